@@ -17,7 +17,7 @@ from ethereum_types.numeric import Uint, U256, U256Struct
 from ethereum_types.bytes import Bytes, BytesStruct
 from ethereum.cancun.vm.memory import buffer_read
 from cairo_ec.curve.alt_bn128 import alt_bn128
-from cairo_ec.ec_ops import ec_add, ec_mul
+from cairo_ec.ec_ops import ec_add
 from cairo_ec.curve.g1_point import G1Point, G1Point__eq__
 from cairo_ec.circuits.ec_ops_compiled import (
     assert_x_is_on_curve,
@@ -177,67 +177,15 @@ func alt_bn128_mul{
         return err;
     }
 
-    tempvar u256_zero = U256(new U256Struct(0, 0));
-    tempvar u256_thirty_two = U256(new U256Struct(32, 0));
-    tempvar u256_sixty_four = U256(new U256Struct(64, 0));
-    let x0_bytes = buffer_read(data, u256_zero, u256_thirty_two);
-    let y0_bytes = buffer_read(data, u256_thirty_two, u256_thirty_two);
-    let k_bytes = buffer_read(data, u256_sixty_four, u256_thirty_two);
-
-    let x0_value = U256_from_be_bytes(x0_bytes);
-    let y0_value = U256_from_be_bytes(y0_bytes);
-    let k_value = U256_from_be_bytes(k_bytes);
-
-    tempvar ALT_BN128_PRIME = U256(new U256Struct(alt_bn128.P_LOW_128, alt_bn128.P_HIGH_128));
-    // Check that x0 is within the interval [0, modulus)
-    let is_x0_out_of_range = U256_le(ALT_BN128_PRIME, x0_value);
-    if (is_x0_out_of_range.value != 0) {
-        tempvar err = new EthereumException(OutOfGasError);
-        return err;
-    }
-    // Check that y0 is within the interval [0, modulus)
-    let is_y0_out_of_range = U256_le(ALT_BN128_PRIME, y0_value);
-    if (is_y0_out_of_range.value != 0) {
-        tempvar err = new EthereumException(OutOfGasError);
-        return err;
-    }
-
-    // Check that p0 is on curve
-    let x0_uint384 = uint256_to_uint384([x0_value.value]);
-    let y0_uint384 = uint256_to_uint384([y0_value.value]);
-    let p0 = G1Point(x=x0_uint384, y=y0_uint384);
-
-    let point_inf = G1Point(x=UInt384(0, 0, 0, 0), y=UInt384(0, 0, 0, 0));
-    let is_p0_zero = G1Point__eq__(p0, point_inf);
-    // If P0 is point at infinity, return point at infinity.
-    if (is_p0_zero.value != 0) {
-        let (buffer: felt*) = alloc();
-        memset(buffer, 0, 64);
-        tempvar output = Bytes(new BytesStruct(data=buffer, len=64));
-        EvmImpl.set_output(output);
-        tempvar ok = cast(0, EthereumException*);
-        return ok;
-    }
+    tempvar data = data;
+    tempvar error: EthereumException*;
+    tempvar output: Bytes;
+    %{ bn256_scalar_mul_precompile %}
 
-    tempvar a = new UInt384(alt_bn128.A0, alt_bn128.A1, alt_bn128.A2, alt_bn128.A3);
-    tempvar b = new UInt384(alt_bn128.B0, alt_bn128.B1, alt_bn128.B2, alt_bn128.B3);
-    tempvar modulus = new UInt384(alt_bn128.P0, alt_bn128.P1, alt_bn128.P2, alt_bn128.P3);
-    tempvar g = new UInt384(alt_bn128.G0, alt_bn128.G1, alt_bn128.G2, alt_bn128.G3);
-    tempvar is_on_curve;
-    tempvar point = p0;
-    %{ is_point_on_curve %}
-    if (is_on_curve == 0) {
-        assert_not_on_curve(new p0.x, new p0.y, a, b, modulus);
-        tempvar err = new EthereumException(OutOfGasError);
-        return err;
+    if (cast(error, felt) != 0) {
+        return error;
     }
 
-    assert_on_curve(new p0.x, new p0.y, a, b, modulus);
-
-    // Operation
-    let k_uint384 = uint256_to_uint384([k_value.value]);
-    let res = ec_mul(p0, k_uint384, [modulus]);
-    let output = alt_bn128_G1Point__to_Bytes_be(res);
     EvmImpl.set_output(output);
     tempvar ok = cast(0, EthereumException*);
     return ok;
@@ -0,0 +1,10 @@
+from ethereum_types.bytes import Bytes
+
+func test__bloom_filter_check(bloom: Bytes, topic: Bytes) -> felt {
+    alloc_locals;
+    local bloom_ptr: felt* = bloom.value.data;
+    local topic_ptr: felt* = topic.value.data;
+    local present: felt;
+    %{ bloom_filter_check %}
+    return present;
+}
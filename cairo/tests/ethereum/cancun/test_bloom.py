@@ -1,8 +1,12 @@
 from typing import Tuple
 
+import pytest
 from ethereum.cancun.blocks import Log
 from ethereum.cancun.bloom import add_to_bloom, logs_bloom
+from ethereum.crypto.hash import keccak256
+from ethereum_types.bytes import Bytes
 from hypothesis import given
+from hypothesis import strategies as st
 
 from tests.utils.args_gen import MutableBloom
 
@@ -19,3 +23,30 @@ class TestBloom:
         cairo_bloom = cairo_run("logs_bloom", logs)
         bloom = logs_bloom(logs)
         assert cairo_bloom == bloom
+
+    @pytest.mark.parametrize("topic", [b"\x00" * 32, keccak256(b"Transfer")])
+    def test_bloom_filter_check_known_topics(self, cairo_run, cairo_run_py, topic):
+        bloom = bytearray(256)
+        add_to_bloom(bloom, keccak256(b"other topic"))
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__bloom_filter_check", Bytes(bloom), Bytes(topic)) == 0
+        add_to_bloom(bloom, topic)
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__bloom_filter_check", Bytes(bloom), Bytes(topic)) == 1
+
+    @given(
+        entries=st.lists(st.binary(min_size=32, max_size=32), max_size=8),
+        topic=st.binary(min_size=32, max_size=32),
+    )
+    def test_bloom_filter_check(self, cairo_run, entries, topic):
+        bloom = bytearray(256)
+        for entry in entries:
+            add_to_bloom(bloom, entry)
+        # The topic may be present iff adding it leaves the filter unchanged.
+        with_topic = bytearray(bloom)
+        add_to_bloom(with_topic, topic)
+        expected = int(with_topic == bloom)
+        assert (
+            cairo_run("test__bloom_filter_check", Bytes(bloom), Bytes(topic))
+            == expected
+        )
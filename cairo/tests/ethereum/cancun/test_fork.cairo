@@ -0,0 +1,18 @@
+from ethereum_types.bytes import Bytes, Bytes32, TupleBytes
+
+func test__compute_next_base_fee(
+    parent_base_fee: felt, parent_gas_used: felt, parent_gas_target: felt
+) -> felt {
+    tempvar next_base_fee;
+    %{ compute_next_base_fee %}
+    return next_base_fee;
+}
+
+func test__compute_receipts_root(encoded_receipts: TupleBytes) -> Bytes32 {
+    alloc_locals;
+    local receipts: Bytes* = encoded_receipts.value.data;
+    local receipts_len = encoded_receipts.value.len;
+    local root: Bytes32;
+    %{ compute_receipts_root %}
+    return root;
+}
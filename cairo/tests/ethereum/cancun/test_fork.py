@@ -10,7 +10,9 @@ from eth_account import Account as EthAccount
 from eth_keys.datatypes import PrivateKey
 from ethereum.cancun.blocks import Block, Header, Log, Withdrawal
 from ethereum.cancun.fork import (
+    ELASTICITY_MULTIPLIER,
     GAS_LIMIT_ADJUSTMENT_FACTOR,
+    GAS_LIMIT_MINIMUM,
     BlockChain,
     apply_body,
     calculate_base_fee_per_gas,
@@ -38,7 +40,7 @@ from ethereum.cancun.transactions import (
     signing_hash_4844,
     signing_hash_pre155,
 )
-from ethereum.cancun.trie import Trie
+from ethereum.cancun.trie import Trie, root, trie_set
 from ethereum.cancun.utils.address import to_address
 from ethereum.cancun.vm import Environment
 from ethereum.cancun.vm.gas import TARGET_BLOB_GAS_PER_BLOCK, calculate_excess_blob_gas
@@ -52,6 +54,7 @@ from ethereum_types.numeric import U64, U256, Uint
 from hypothesis import assume, given, settings
 from hypothesis import strategies as st
 from hypothesis.strategies import composite, integers
+from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
 
 from cairo_addons.testing.errors import strict_raises
 from tests.ef_tests.helpers.load_state_tests import convert_defaultdict
@@ -872,3 +875,72 @@ def _create_erc20_data():
     }
 
     return accounts, storage_tries
+
+
+def next_base_fee(parent_base_fee, parent_gas_used, parent_gas_target):
+    parent_gas_limit = Uint(parent_gas_target) * ELASTICITY_MULTIPLIER
+    return calculate_base_fee_per_gas(
+        parent_gas_limit, parent_gas_limit, Uint(parent_gas_used), Uint(parent_base_fee)
+    )
+
+
+class TestBaseFee:
+    @pytest.mark.parametrize("parent_gas_used_factor", [0, 1, 2])
+    def test_compute_next_base_fee_edge_cases(
+        self, cairo_run, cairo_run_py, parent_gas_used_factor
+    ):
+        parent_base_fee = 10**9
+        parent_gas_target = 15_000_000
+        parent_gas_used = parent_gas_used_factor * parent_gas_target
+        expected = next_base_fee(parent_base_fee, parent_gas_used, parent_gas_target)
+        for run in (cairo_run, cairo_run_py):
+            assert (
+                run(
+                    "test__compute_next_base_fee",
+                    parent_base_fee=parent_base_fee,
+                    parent_gas_used=parent_gas_used,
+                    parent_gas_target=parent_gas_target,
+                )
+                == expected
+            )
+
+    def test_compute_next_base_fee_should_raise_on_overflow(
+        self, cairo_run, cairo_run_py
+    ):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="next_base_fee exceeds the field"):
+                run(
+                    "test__compute_next_base_fee",
+                    parent_base_fee=DEFAULT_PRIME - 1,
+                    parent_gas_used=2,
+                    parent_gas_target=1,
+                )
+
+    @given(
+        parent_base_fee=st.integers(min_value=0, max_value=2**64 - 1),
+        parent_gas_target=st.integers(
+            min_value=GAS_LIMIT_MINIMUM // ELASTICITY_MULTIPLIER, max_value=2**32
+        ),
+        parent_gas_used=st.integers(min_value=0, max_value=2**33),
+    )
+    def test_compute_next_base_fee(
+        self, cairo_run, parent_base_fee, parent_gas_used, parent_gas_target
+    ):
+        assert cairo_run(
+            "test__compute_next_base_fee",
+            parent_base_fee=parent_base_fee,
+            parent_gas_used=parent_gas_used,
+            parent_gas_target=parent_gas_target,
+        ) == next_base_fee(parent_base_fee, parent_gas_used, parent_gas_target)
+
+
+class TestReceipts:
+    @given(encoded_receipts=st.lists(st.binary(min_size=1, max_size=128), max_size=20))
+    def test_compute_receipts_root(self, cairo_run, encoded_receipts):
+        trie = Trie(secured=False, default=b"")
+        for i, receipt in enumerate(encoded_receipts):
+            trie_set(trie, rlp.encode(Uint(i)), Bytes(receipt))
+        encoded_receipts = tuple(Bytes(receipt) for receipt in encoded_receipts)
+        assert cairo_run(
+            "test__compute_receipts_root", encoded_receipts=encoded_receipts
+        ) == root(trie)
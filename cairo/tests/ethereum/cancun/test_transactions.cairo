@@ -0,0 +1,26 @@
+from ethereum.cancun.transactions_types import (
+    AccessListTransaction,
+    FeeMarketTransaction,
+    LegacyTransaction,
+)
+
+func test__compute_legacy_tx_hash(tx: LegacyTransaction, chain_id: felt) -> (felt, felt) {
+    tempvar hash_lo;
+    tempvar hash_hi;
+    %{ compute_legacy_tx_hash %}
+    return (hash_lo, hash_hi);
+}
+
+func test__compute_eip2930_tx_hash(tx: AccessListTransaction) -> (felt, felt) {
+    tempvar hash_lo;
+    tempvar hash_hi;
+    %{ compute_eip2930_tx_hash %}
+    return (hash_lo, hash_hi);
+}
+
+func test__compute_eip1559_tx_hash(tx: FeeMarketTransaction) -> (felt, felt) {
+    tempvar hash_lo;
+    tempvar hash_hi;
+    %{ compute_eip1559_tx_hash %}
+    return (hash_lo, hash_hi);
+}
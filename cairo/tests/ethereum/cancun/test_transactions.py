@@ -92,3 +92,43 @@ class TestTransactions:
         encoded_tx = encode_transaction(tx)
         decoded_tx = cairo_run("decode_transaction", encoded_tx)
         assert decoded_tx == tx
+
+
+def split_hash(value):
+    value = int.from_bytes(value, "big")
+    return (value % 2**128, value >> 128)
+
+
+class TestTxHash:
+    @given(tx=...)
+    def test_compute_legacy_tx_hash_pre155(self, cairo_run, tx: LegacyTransaction):
+        assert cairo_run("test__compute_legacy_tx_hash", tx, 0) == split_hash(
+            signing_hash_pre155(tx)
+        )
+
+    @given(tx=..., chain_id=...)
+    def test_compute_legacy_tx_hash_155(
+        self, cairo_run, tx: LegacyTransaction, chain_id: U64
+    ):
+        assert cairo_run(
+            "test__compute_legacy_tx_hash", tx, int(chain_id)
+        ) == split_hash(signing_hash_155(tx, chain_id))
+
+    @given(tx=...)
+    def test_compute_eip2930_tx_hash(self, cairo_run, tx: AccessListTransaction):
+        assert cairo_run("test__compute_eip2930_tx_hash", tx) == split_hash(
+            signing_hash_2930(tx)
+        )
+
+    @given(tx=...)
+    def test_compute_eip1559_tx_hash(self, cairo_run, tx: FeeMarketTransaction):
+        assert cairo_run("test__compute_eip1559_tx_hash", tx) == split_hash(
+            signing_hash_1559(tx)
+        )
+
+    @given(tx=..., chain_id=...)
+    def test_compute_legacy_tx_hash_py(
+        self, cairo_run, cairo_run_py, tx: LegacyTransaction, chain_id: U64
+    ):
+        args = ("test__compute_legacy_tx_hash", tx, int(chain_id))
+        assert cairo_run_py(*args) == cairo_run(*args)
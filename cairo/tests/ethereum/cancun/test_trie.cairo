@@ -0,0 +1,65 @@
+from ethereum_types.bytes import Bytes
+from ethereum_types.numeric import U256, U256Struct
+
+func test__compute_mpt_node_hash(node: Bytes) -> (felt, felt) {
+    alloc_locals;
+    local node_ptr: felt* = node.value.data;
+    local node_len = node.value.len;
+    tempvar hash_low;
+    tempvar hash_high;
+    %{ compute_mpt_node_hash %}
+    return (hash_low, hash_high);
+}
+
+func test__verify_mpt_inclusion(
+    root_low: felt, root_high: felt, key: Bytes, value: Bytes, proof: Bytes, proof_len: felt
+) -> (felt, felt) {
+    alloc_locals;
+    local key_ptr: felt* = key.value.data;
+    local key_len = key.value.len;
+    local value_ptr: felt* = value.value.data;
+    local value_len = value.value.len;
+    local proof_ptr: felt* = proof.value.data;
+    local valid: felt;
+    local error_code: felt;
+    %{ verify_mpt_inclusion %}
+    return (valid, error_code);
+}
+
+func test__compute_storage_trie_key(
+    is_mapping: felt, key_lo: felt, key_hi: felt, slot_lo: felt, slot_hi: felt
+) -> (felt, felt, felt, felt) {
+    tempvar trie_key_lo;
+    tempvar trie_key_hi;
+    tempvar trie_path: felt*;
+    %{ compute_storage_trie_key %}
+    return (trie_key_lo, trie_key_hi, trie_path[0], trie_path[63]);
+}
+
+func test__compute_mapping_slot(key: U256, base_slot: U256) -> U256 {
+    alloc_locals;
+    local key_lo = key.value.low;
+    local key_hi = key.value.high;
+    local base_slot_lo = base_slot.value.low;
+    local base_slot_hi = base_slot.value.high;
+    local slot_lo: felt;
+    local slot_hi: felt;
+    %{ compute_mapping_slot %}
+    tempvar res = U256(new U256Struct(slot_lo, slot_hi));
+    return res;
+}
+
+func test__compute_nested_mapping_slot(key: U256, inner_key: U256, base_slot: U256) -> U256 {
+    alloc_locals;
+    local key_lo = key.value.low;
+    local key_hi = key.value.high;
+    local inner_key_lo = inner_key.value.low;
+    local inner_key_hi = inner_key.value.high;
+    local base_slot_lo = base_slot.value.low;
+    local base_slot_hi = base_slot.value.high;
+    local slot_lo: felt;
+    local slot_hi: felt;
+    %{ compute_nested_mapping_slot %}
+    tempvar res = U256(new U256Struct(slot_lo, slot_hi));
+    return res;
+}
@@ -25,6 +25,8 @@ from ethereum.cancun.trie import (
     trie_get,
     trie_set,
 )
+from ethereum.crypto.hash import keccak256
+from ethereum_rlp import rlp
 from ethereum_types.bytes import Bytes, Bytes32
 from ethereum_types.numeric import U256, Uint
 from hypothesis import Verbosity, example, given, settings
@@ -449,3 +451,232 @@ class TestTrieOperations:
             copied_trie_py = copy_trie(trie)
             assert original_trie == trie
             assert copied_trie_cairo == copied_trie_py
+
+
+def _mpt_fixture():
+    """
+    Builds the trie mapping 0x1234 and 0x1256 to values of 40 bytes, hashed in their
+    parent, and 0x1278 to a value of 1 byte, embedded in its parent. Returns its root
+    and the proof of each key.
+    """
+    leaf_a = rlp.encode([b"\x34", b"\xaa" * 40])
+    leaf_b = rlp.encode([b"\x36", b"\xbb" * 40])
+    leaf_c = [b"\x38", b"c"]
+    children = [b""] * 16
+    children[3], children[5], children[7] = keccak256(leaf_a), keccak256(leaf_b), leaf_c
+    branch = rlp.encode(children + [b""])
+    extension = rlp.encode([b"\x00\x12", keccak256(branch)])
+    proofs = {
+        b"\x12\x34": (b"\xaa" * 40, [extension, branch, leaf_a]),
+        b"\x12\x56": (b"\xbb" * 40, [extension, branch, leaf_b]),
+        b"\x12\x78": (b"c", [extension, branch]),
+    }
+    return keccak256(extension), proofs
+
+
+def _verify_mpt_inclusion(run, root, key, value, proof):
+    # Nodes are stored as their length followed by their bytes, all shorter than 256
+    # bytes here so that the proof fits in `Bytes`.
+    encoded_proof = b"".join(bytes([len(node)]) + node for node in proof)
+    root = int.from_bytes(root, "big")
+    return run(
+        "test__verify_mpt_inclusion",
+        root_low=root % 2**128,
+        root_high=root >> 128,
+        key=Bytes(key),
+        value=Bytes(value),
+        proof=Bytes(encoded_proof),
+        proof_len=len(proof),
+    )
+
+
+class TestMpt:
+    @pytest.mark.parametrize(
+        "node",
+        [
+            # Leaf node, with an even and an odd path.
+            [b"\x20\x01\x23", b"value"],
+            [b"\x31\x23", b"\xaa" * 40],
+            # Extension node.
+            [b"\x00\x12", keccak256(b"child")],
+            # Branch node, with a value and an embedded child.
+            [b""] * 3 + [[b"\x3a", b"v"]] + [b""] * 12 + [b"value"],
+        ],
+    )
+    def test_compute_mpt_node_hash(self, cairo_run, cairo_run_py, node):
+        encoded = rlp.encode(node)
+        expected = int.from_bytes(keccak256(encoded), "big")
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__compute_mpt_node_hash", node=Bytes(encoded)) == (
+                expected % 2**128,
+                expected >> 128,
+            )
+
+    @pytest.mark.parametrize(
+        "data, error",
+        [
+            (rlp.encode(b"value"), "not a single RLP list"),
+            (rlp.encode([b"\x20", b"value"]) + b"\x00", "not a single RLP list"),
+            (rlp.encode([b"\x40", b"value"]), "invalid path"),
+            (rlp.encode([b"", b"value"]), "invalid path"),
+            (rlp.encode([[b"\x20"], b"value"]), "invalid path"),
+            (rlp.encode([b"\x20", b"value", b""]), "expected 2 or 17 items, got 3"),
+        ],
+    )
+    def test_compute_mpt_node_hash_should_raise_on_invalid_node(
+        self, cairo_run, cairo_run_py, data, error
+    ):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match=error):
+                run("test__compute_mpt_node_hash", node=Bytes(data))
+
+    @pytest.mark.parametrize("key", [b"\x12\x34", b"\x12\x56", b"\x12\x78"])
+    def test_verify_mpt_inclusion(self, cairo_run, cairo_run_py, key):
+        root_hash, proofs = _mpt_fixture()
+        trie = Trie(secured=False, default=b"")
+        for trie_key, (value, _) in proofs.items():
+            trie_set(trie, Bytes(trie_key), Bytes(value))
+        assert root(trie) == root_hash
+
+        value, proof = proofs[key]
+        for run in (cairo_run, cairo_run_py):
+            assert _verify_mpt_inclusion(run, root_hash, key, value, proof) == (1, 0)
+
+    @pytest.mark.parametrize(
+        "key, value, tamper_proof, error_code",
+        [
+            # Node hash mismatch.
+            (b"\x12\x34", b"\xaa" * 40, lambda p: [p[0], p[1], p[2] + b"\x00"], 2),
+            # Key not found: diverging leaf path, empty branch and diverging extension.
+            (b"\x12\x35", b"\xaa" * 40, lambda p: p, 3),
+            (b"\x12\x94", b"\xaa" * 40, lambda p: p[:2], 3),
+            (b"\x13\x34", b"\xaa" * 40, lambda p: p[:1], 3),
+            # Value mismatch, for a hashed and an embedded leaf.
+            (b"\x12\x34", b"\xab" * 40, lambda p: p, 4),
+            (b"\x12\x78", b"d", lambda p: p[:2], 4),
+            # Incomplete proof.
+            (b"\x12\x34", b"\xaa" * 40, lambda p: p[:2], 5),
+            # Unused proof nodes.
+            (b"\x12\x78", b"c", lambda p: p, 6),
+        ],
+    )
+    def test_verify_mpt_inclusion_should_fail_on_invalid_proof(
+        self, cairo_run, cairo_run_py, key, value, tamper_proof, error_code
+    ):
+        # All proofs are derived from the proof of 0x1234: extension, branch and leaf.
+        root_hash, proofs = _mpt_fixture()
+        proof = tamper_proof(proofs[b"\x12\x34"][1])
+        for run in (cairo_run, cairo_run_py):
+            assert _verify_mpt_inclusion(run, root_hash, key, value, proof) == (
+                0,
+                error_code,
+            )
+
+    def test_verify_mpt_inclusion_should_fail_on_invalid_node(
+        self, cairo_run, cairo_run_py
+    ):
+        node = rlp.encode(b"node")
+        for run in (cairo_run, cairo_run_py):
+            assert _verify_mpt_inclusion(
+                run, keccak256(node), b"\x12", b"value", [node]
+            ) == (0, 1)
+
+
+class TestStorageTrieKey:
+    @pytest.mark.parametrize(
+        "is_mapping, key, slot, expected",
+        [
+            # Slot 0 of a state variable.
+            (
+                0,
+                0,
+                0,
+                0x290DECD9548B62A8D60345A988386FC84BA6BC95484008F6362F93160EF3E563,
+            ),
+            # Entry 0 of the mapping stored at slot 0.
+            (
+                1,
+                0,
+                0,
+                0xAD3228B676F7D3CD4284A5443F17F1962B36E491B30A40B2405849E597BA5FB5,
+            ),
+        ],
+    )
+    def test_compute_storage_trie_key_known_layouts(
+        self, cairo_run, cairo_run_py, is_mapping, key, slot, expected
+    ):
+        args = (is_mapping, key % 2**128, key >> 128, slot % 2**128, slot >> 128)
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__compute_storage_trie_key", *args) == (
+                expected % 2**128,
+                expected >> 128,
+                expected >> 252,
+                expected & 0xF,
+            )
+
+    @given(
+        is_mapping=st.integers(min_value=0, max_value=1),
+        key=st.integers(min_value=0, max_value=2**256 - 1),
+        slot=st.integers(min_value=0, max_value=2**256 - 1),
+    )
+    def test_compute_storage_trie_key(self, cairo_run, is_mapping, key, slot):
+        preimage = slot.to_bytes(32, "big")
+        if is_mapping:
+            preimage = key.to_bytes(32, "big") + preimage
+        expected = int.from_bytes(keccak256(preimage), "big")
+        args = (is_mapping, key % 2**128, key >> 128, slot % 2**128, slot >> 128)
+        assert cairo_run("test__compute_storage_trie_key", *args) == (
+            expected % 2**128,
+            expected >> 128,
+            expected >> 252,
+            expected & 0xF,
+        )
+
+    def test_compute_storage_trie_key_invalid_flag(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="is_mapping must be 0 or 1"):
+                run("test__compute_storage_trie_key", 2, 0, 0, 0, 0)
+
+
+def mapping_slot(key: int, base_slot: int) -> bytes:
+    # keccak256(abi.encode(key, base_slot)), as computed by solc and `forge inspect`
+    return keccak256(key.to_bytes(32, "big") + base_slot.to_bytes(32, "big"))
+
+
+class TestSlotHash:
+    def test_compute_mapping_slot_zero(self, cairo_run, cairo_run_py):
+        expected = U256(
+            0xAD3228B676F7D3CD4284A5443F17F1962B36E491B30A40B2405849E597BA5FB5
+        )
+        for run in (cairo_run, cairo_run_py):
+            result = run("test__compute_mapping_slot", key=U256(0), base_slot=U256(0))
+            assert result == expected
+
+    @given(key=st.from_type(U256), base_slot=st.from_type(U256))
+    def test_compute_mapping_slot(self, cairo_run, key, base_slot):
+        # e.g. ERC-20 `balanceOf[account]`, with `balanceOf` at `base_slot`
+        assert cairo_run(
+            "test__compute_mapping_slot", key=key, base_slot=base_slot
+        ) == U256.from_be_bytes(mapping_slot(int(key), int(base_slot)))
+
+    @given(
+        key=st.from_type(U256),
+        inner_key=st.from_type(U256),
+        base_slot=st.from_type(U256),
+    )
+    def test_compute_nested_mapping_slot(
+        self, cairo_run, cairo_run_py, key, inner_key, base_slot
+    ):
+        # e.g. ERC-20 `allowance[owner][spender]`, with `allowance` at `base_slot`
+        outer_slot = int.from_bytes(mapping_slot(int(key), int(base_slot)), "big")
+        expected = U256.from_be_bytes(mapping_slot(int(inner_key), outer_slot))
+        for run in (cairo_run, cairo_run_py):
+            assert (
+                run(
+                    "test__compute_nested_mapping_slot",
+                    key=key,
+                    inner_key=inner_key,
+                    base_slot=base_slot,
+                )
+                == expected
+            )
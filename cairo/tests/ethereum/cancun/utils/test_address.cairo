@@ -0,0 +1,17 @@
+func test__compute_create2_address(
+    sender: felt,
+    salt_low: felt,
+    salt_high: felt,
+    init_code_hash_low: felt,
+    init_code_hash_high: felt,
+) -> felt {
+    tempvar address;
+    %{ compute_create2_address %}
+    return address;
+}
+
+func test__compute_create_address(sender: felt, nonce: felt) -> felt {
+    tempvar address;
+    %{ compute_create_address %}
+    return address;
+}
@@ -1,14 +1,32 @@
 from typing import Union
 
+import pytest
 from ethereum.cancun.fork_types import Address
 from ethereum.cancun.utils.address import (
     compute_contract_address,
     compute_create2_contract_address,
     to_address,
 )
+from ethereum.crypto.hash import keccak256
 from ethereum_types.bytes import Bytes32
 from ethereum_types.numeric import U256, Uint
 from hypothesis import given
+from hypothesis import strategies as st
+
+
+def _compute_create2_address(run, sender: bytes, salt: bytes, init_code: bytes):
+    # Addresses are little-endian felts, and `Bytes32` values are given by their
+    # little-endian halves.
+    init_code_hash = keccak256(init_code)
+    address = run(
+        "test__compute_create2_address",
+        sender=int.from_bytes(sender, "little"),
+        salt_low=int.from_bytes(salt[:16], "little"),
+        salt_high=int.from_bytes(salt[16:], "little"),
+        init_code_hash_low=int.from_bytes(init_code_hash[:16], "little"),
+        init_code_hash_high=int.from_bytes(init_code_hash[16:], "little"),
+    )
+    return address.to_bytes(20, "little")
 
 
 class TestAddress:
@@ -30,3 +48,104 @@ class TestAddress:
         assert compute_create2_contract_address(address, salt, call_data) == cairo_run(
             "compute_create2_contract_address", address, salt, call_data
         )
+
+    @pytest.mark.parametrize(
+        "nonce, expected",
+        [
+            (0, "cd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"),
+            (1, "343c43a37d37dff08ae8c4a11544c718abb4fcf8"),
+            (2, "f778b86fa74e846c4f0a1fbd1335fe81c00a0c91"),
+            (3, "fffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c"),
+        ],
+    )
+    def test_compute_create_address_known_nonces(
+        self, cairo_run, cairo_run_py, nonce, expected
+    ):
+        sender = bytes.fromhex("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0")
+        for run in (cairo_run, cairo_run_py):
+            address = run(
+                "test__compute_create_address",
+                sender=int.from_bytes(sender, "little"),
+                nonce=nonce,
+            )
+            assert address.to_bytes(20, "little") == bytes.fromhex(expected)
+
+    @given(
+        sender=st.binary(min_size=20, max_size=20),
+        nonce=st.one_of(
+            st.integers(min_value=0, max_value=0x80),
+            st.integers(min_value=0, max_value=2**64 - 1),
+        ),
+    )
+    def test_compute_create_address(self, cairo_run, sender, nonce):
+        address = cairo_run(
+            "test__compute_create_address",
+            sender=int.from_bytes(sender, "little"),
+            nonce=nonce,
+        )
+        expected = compute_contract_address(Address(sender), Uint(nonce))
+        assert address.to_bytes(20, "little") == expected
+
+    def test_compute_create_address_should_raise_on_large_nonce(
+        self, cairo_run, cairo_run_py
+    ):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="nonce exceeds 64 bits"):
+                run("test__compute_create_address", sender=0, nonce=2**64)
+
+    # Examples of EIP-1014.
+    @pytest.mark.parametrize(
+        "sender, salt, init_code, expected",
+        [
+            ("00" * 20, "00" * 32, "00", "4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38"),
+            (
+                "deadbeef" + "00" * 16,
+                "00" * 32,
+                "00",
+                "b928f69bb1d91cd65274e3c79d8986362984fda3",
+            ),
+            (
+                "deadbeef" + "00" * 16,
+                "00" * 12 + "feed" + "00" * 18,
+                "00",
+                "d04116cdd17bebe565eb2422f2497e06cc1c9833",
+            ),
+            (
+                "00" * 20,
+                "00" * 32,
+                "deadbeef",
+                "70f2b2914a2a4b783faefb75f459a580616fcb5e",
+            ),
+            (
+                "00" * 16 + "deadbeef",
+                "00" * 28 + "cafebabe",
+                "deadbeef",
+                "60f3f640a8508fc6a86d45df051962668e1e8ac7",
+            ),
+            (
+                "00" * 16 + "deadbeef",
+                "00" * 28 + "cafebabe",
+                "deadbeef" * 11,
+                "1d8bfdc5d46dc4f61d6b6115972536ebe6a8854c",
+            ),
+            ("00" * 20, "00" * 32, "", "e33c0c7f7df4809055c3eba6c09cfe4baf1bd9e0"),
+        ],
+    )
+    def test_compute_create2_address_eip_1014(
+        self, cairo_run, cairo_run_py, sender, salt, init_code, expected
+    ):
+        sender, salt = bytes.fromhex(sender), bytes.fromhex(salt)
+        init_code, expected = bytes.fromhex(init_code), bytes.fromhex(expected)
+        for run in (cairo_run, cairo_run_py):
+            assert _compute_create2_address(run, sender, salt, init_code) == expected
+
+    @given(
+        sender=st.binary(min_size=20, max_size=20),
+        salt=st.binary(min_size=32, max_size=32),
+        init_code=st.binary(max_size=256),
+    )
+    def test_compute_create2_address(self, cairo_run, sender, salt, init_code):
+        expected = compute_create2_contract_address(
+            Address(sender), Bytes32(salt), bytearray(init_code)
+        )
+        assert _compute_create2_address(cairo_run, sender, salt, init_code) == expected
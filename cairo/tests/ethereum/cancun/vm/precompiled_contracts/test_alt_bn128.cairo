@@ -0,0 +1,59 @@
+from starkware.cairo.common.alloc import alloc
+from starkware.cairo.common.uint256 import Uint256
+from ethereum_types.bytes import Bytes, BytesStruct
+
+func test__bn256_scalar_mul_precompile(data: Bytes) -> Bytes {
+    alloc_locals;
+    local error: felt;
+    local output: Bytes;
+    %{ bn256_scalar_mul_precompile %}
+    with_attr error_message("OutOfGasError") {
+        assert error = 0;
+    }
+    return output;
+}
+
+func test__bn256_scalar_mul_precompile_single_value(value: felt) -> Bytes {
+    alloc_locals;
+    let (buffer: felt*) = alloc();
+    assert [buffer] = value;
+    tempvar data = Bytes(new BytesStruct(data=buffer, len=1));
+    local error: felt;
+    local output: Bytes;
+    %{ bn256_scalar_mul_precompile %}
+    return output;
+}
+
+func test__bn254_ec_add(
+    x1_low: felt,
+    x1_high: felt,
+    y1_low: felt,
+    y1_high: felt,
+    x2_low: felt,
+    x2_high: felt,
+    y2_low: felt,
+    y2_high: felt,
+) -> (felt, felt, felt, felt) {
+    alloc_locals;
+    local x1: Uint256 = Uint256(x1_low, x1_high);
+    local y1: Uint256 = Uint256(y1_low, y1_high);
+    local x2: Uint256 = Uint256(x2_low, x2_high);
+    local y2: Uint256 = Uint256(y2_low, y2_high);
+    local rx: Uint256;
+    local ry: Uint256;
+    %{ bn254_ec_add %}
+    return (rx.low, rx.high, ry.low, ry.high);
+}
+
+func test__bn254_ec_mul(
+    x_low: felt, x_high: felt, y_low: felt, y_high: felt, s_low: felt, s_high: felt
+) -> (felt, felt, felt, felt) {
+    alloc_locals;
+    local x: Uint256 = Uint256(x_low, x_high);
+    local y: Uint256 = Uint256(y_low, y_high);
+    local s: Uint256 = Uint256(s_low, s_high);
+    local rx: Uint256;
+    local ry: Uint256;
+    %{ bn254_ec_mul %}
+    return (rx.low, rx.high, ry.low, ry.high);
+}
@@ -1,3 +1,4 @@
+import pytest
 from ethereum.cancun.vm import Evm
 from ethereum.cancun.vm.exceptions import OutOfGasError
 from ethereum.cancun.vm.precompiled_contracts.alt_bn128 import (
@@ -5,7 +6,7 @@ from ethereum.cancun.vm.precompiled_contracts.alt_bn128 import (
     alt_bn128_mul,
     alt_bn128_pairing_check,
 )
-from ethereum.crypto.alt_bn128 import ALT_BN128_PRIME, BNP
+from ethereum.crypto.alt_bn128 import ALT_BN128_CURVE_ORDER, ALT_BN128_PRIME, BNF, BNP
 from ethereum_types.bytes import Bytes
 from hypothesis import given
 from hypothesis import strategies as st
@@ -13,6 +14,7 @@ from hypothesis import strategies as st
 from cairo_addons.testing.errors import strict_raises
 from cairo_ec.curve import AltBn128
 from tests.utils.evm_builder import EvmBuilder
+from tests.utils.helpers import split_uint256s
 
 
 @st.composite
@@ -193,3 +195,165 @@ class TestAltbn128:
 
         alt_bn128_mul(evm)
         assert evm == evm_cairo
+
+
+def bn256_scalar_mul(data: bytes) -> bytes:
+    # Reference implementation following ethereum.cancun.vm.precompiled_contracts.alt_bn128
+    data = data[:96].ljust(96, b"\x00")
+    x, y, n = (int.from_bytes(data[i : i + 32], "big") for i in range(0, 96, 32))
+    if x >= ALT_BN128_PRIME or y >= ALT_BN128_PRIME:
+        raise ValueError("Invalid point")
+    result = BNP(BNF(x), BNF(y)).mul_by(n)
+    return result.x.to_be_bytes32() + result.y.to_be_bytes32()
+
+
+def encode_mul_input(x: int, y: int, n: int) -> Bytes:
+    return Bytes(b"".join(value.to_bytes(32, "big") for value in (x, y, n)))
+
+
+G1_MUL_VECTORS = [
+    # Generator
+    *[
+        encode_mul_input(1, 2, n)
+        for n in (
+            0,
+            1,
+            2,
+            9,
+            ALT_BN128_CURVE_ORDER - 1,
+            ALT_BN128_CURVE_ORDER,
+            ALT_BN128_CURVE_ORDER + 1,
+            2**256 - 1,
+        )
+    ],
+    # Point at infinity
+    *[encode_mul_input(0, 0, n) for n in (0, 1, 5, 2**256 - 1)],
+    # Point not on the curve
+    encode_mul_input(1, 3, 1),
+    # Coordinate out of the field
+    encode_mul_input(ALT_BN128_PRIME, 2, 1),
+    encode_mul_input(1, ALT_BN128_PRIME + 2, 1),
+    # Short input is right-padded with zeros
+    Bytes(encode_mul_input(1, 2, 2**255)[:65]),
+    Bytes(encode_mul_input(1, 2, 0)[:64]),
+    Bytes(b""),
+    # Long input is truncated
+    Bytes(encode_mul_input(1, 2, 3) + b"\x01" * 32),
+]
+
+
+class TestBn256ScalarMul:
+    @pytest.mark.parametrize("data", G1_MUL_VECTORS)
+    def test_bn256_scalar_mul_precompile(self, cairo_run, cairo_run_py, data: Bytes):
+        try:
+            expected = bn256_scalar_mul(data)
+        except ValueError:
+            for run in (cairo_run, cairo_run_py):
+                with pytest.raises(Exception, match="OutOfGasError"):
+                    run("test__bn256_scalar_mul_precompile", data=data)
+            return
+
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__bn256_scalar_mul_precompile", data=data) == expected
+
+    def test_bn256_scalar_mul_precompile_should_raise_on_non_byte(
+        self, cairo_run, cairo_run_py
+    ):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="byte"):
+                run("test__bn256_scalar_mul_precompile_single_value", value=256)
+
+
+BN254_G1 = BNP(BNF(1), BNF(2))
+
+
+class TestBn254EcAdd:
+    @pytest.mark.parametrize(
+        "p1, p2",
+        [
+            (BN254_G1, BN254_G1),
+            (BN254_G1, BN254_G1.mul_by(9)),
+            (BN254_G1, BNP.point_at_infinity()),
+            (BNP.point_at_infinity(), BNP.point_at_infinity()),
+            # P + (-P) is the point at infinity.
+            (BN254_G1, BN254_G1.mul_by(ALT_BN128_CURVE_ORDER - 1)),
+        ],
+    )
+    def test_bn254_ec_add(self, cairo_run, cairo_run_py, p1, p2):
+        args = split_uint256s(int(p1.x), int(p1.y), int(p2.x), int(p2.y))
+        expected = p1 + p2
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__bn254_ec_add", *args) == tuple(
+                split_uint256s(int(expected.x), int(expected.y))
+            )
+
+    @pytest.mark.parametrize(
+        "p1, p2, error",
+        [
+            ((1, 3), (1, 2), r"\(x1, y1\) is not on the curve"),
+            ((1, 2), (2, 2), r"\(x2, y2\) is not on the curve"),
+            ((ALT_BN128_PRIME, 2), (1, 2), "x1 is not a valid field element"),
+            ((1, 2), (1, ALT_BN128_PRIME + 2), "y2 is not a valid field element"),
+        ],
+    )
+    def test_bn254_ec_add_should_raise_on_invalid_point(
+        self, cairo_run, cairo_run_py, p1, p2, error
+    ):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match=error):
+                run("test__bn254_ec_add", *split_uint256s(*p1, *p2))
+
+
+class TestBn254EcMul:
+    @pytest.mark.parametrize(
+        "point",
+        [BN254_G1, BN254_G1.mul_by(9), BNP.point_at_infinity()],
+    )
+    @pytest.mark.parametrize(
+        "scalar",
+        [
+            0,
+            1,
+            2,
+            9,
+            ALT_BN128_CURVE_ORDER - 1,
+            ALT_BN128_CURVE_ORDER,
+            ALT_BN128_CURVE_ORDER + 1,
+            2**256 - 1,
+        ],
+    )
+    def test_bn254_ec_mul(self, cairo_run, cairo_run_py, point, scalar):
+        args = split_uint256s(int(point.x), int(point.y), scalar)
+        expected = point.mul_by(scalar)
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__bn254_ec_mul", *args) == tuple(
+                split_uint256s(int(expected.x), int(expected.y))
+            )
+
+    def test_bn254_ec_mul_by_zero_and_one(self, cairo_run, cairo_run_py):
+        args = split_uint256s(int(BN254_G1.x), int(BN254_G1.y))
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__bn254_ec_mul", *args, 0, 0) == (0, 0, 0, 0)
+            assert run("test__bn254_ec_mul", *args, 1, 0) == (1, 0, 2, 0)
+
+    @given(scalar=st.integers(min_value=0, max_value=2**256 - 1))
+    def test_bn254_ec_mul_matches_precompile(self, cairo_run, scalar):
+        output = bn256_scalar_mul(encode_mul_input(1, 2, scalar))
+        x, y = int.from_bytes(output[:32], "big"), int.from_bytes(output[32:], "big")
+        assert cairo_run(
+            "test__bn254_ec_mul", *split_uint256s(1, 2, scalar)
+        ) == tuple(split_uint256s(x, y))
+
+    @pytest.mark.parametrize(
+        "point, error",
+        [
+            ((1, 3), r"\(x, y\) is not on the curve"),
+            ((ALT_BN128_PRIME, 2), "x is not a valid field element"),
+        ],
+    )
+    def test_bn254_ec_mul_should_raise_on_invalid_point(
+        self, cairo_run, cairo_run_py, point, error
+    ):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match=error):
+                run("test__bn254_ec_mul", *split_uint256s(*point, 1))
@@ -0,0 +1,9 @@
+from ethereum_types.numeric import U256
+
+func test__ecrecover(hash: U256, v: U256, r: U256, s: U256) -> (felt, felt) {
+    alloc_locals;
+    local address: felt;
+    local success: felt;
+    %{ ecrecover %}
+    return (address, success);
+}
@@ -1,7 +1,9 @@
+import pytest
+from eth_keys.datatypes import PrivateKey
 from ethereum.cancun.vm import Evm
 from ethereum.cancun.vm.precompiled_contracts.ecrecover import ecrecover
 from ethereum.crypto.elliptic_curve import SECP256K1N
-from ethereum.crypto.hash import Hash32
+from ethereum.crypto.hash import Hash32, keccak256
 from ethereum.exceptions import EthereumException
 from ethereum_types.numeric import U256
 from hypothesis import given
@@ -74,3 +76,33 @@ def test_ecrecover(cairo_run, evm: Evm):
 
     ecrecover(evm)
     assert cairo_evm == evm
+
+
+class TestEcrecover:
+    @given(private_key=..., message_hash=st.binary(min_size=32, max_size=32))
+    def test_ecrecover(self, cairo_run, private_key: PrivateKey, message_hash):
+        signature = private_key.sign_msg_hash(message_hash)
+        address = private_key.public_key.to_canonical_address()
+        assert cairo_run(
+            "test__ecrecover",
+            hash=U256.from_be_bytes(message_hash),
+            v=U256(signature.v + 27),
+            r=U256(signature.r),
+            s=U256(signature.s),
+        ) == (int.from_bytes(address, "little"), 1)
+
+    @pytest.mark.parametrize("v", [0, 26, 29])
+    def test_ecrecover_should_not_raise_on_invalid_signature(
+        self, cairo_run, cairo_run_py, v
+    ):
+        private_key = PrivateKey(b"\x01" * 32)
+        message_hash = keccak256(b"hello world")
+        signature = private_key.sign_msg_hash(message_hash)
+        for run in (cairo_run, cairo_run_py):
+            assert run(
+                "test__ecrecover",
+                hash=U256.from_be_bytes(message_hash),
+                v=U256(v),
+                r=U256(signature.r),
+                s=U256(signature.s),
+            ) == (0, 0)
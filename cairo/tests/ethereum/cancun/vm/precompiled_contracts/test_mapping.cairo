@@ -0,0 +1,53 @@
+from starkware.cairo.common.cairo_builtins import (
+    BitwiseBuiltin,
+    KeccakBuiltin,
+    PoseidonBuiltin,
+    ModBuiltin,
+)
+
+from ethereum.exceptions import EthereumException
+from ethereum.cancun.vm.evm_impl import Evm, EvmStruct
+from ethereum.cancun.vm.precompiled_contracts.mapping import precompile_table_lookup
+
+// Runs the precompile at `address` the way `process_message` dispatches it.
+func test__dispatch_precompile{
+    range_check_ptr,
+    bitwise_ptr: BitwiseBuiltin*,
+    keccak_ptr: KeccakBuiltin*,
+    poseidon_ptr: PoseidonBuiltin*,
+    range_check96_ptr: felt*,
+    add_mod_ptr: ModBuiltin*,
+    mul_mod_ptr: ModBuiltin*,
+    evm: Evm,
+}(address: felt) -> EthereumException* {
+    alloc_locals;
+    let (_, precompile_fn) = precompile_table_lookup(address);
+
+    [ap] = range_check_ptr, ap++;
+    [ap] = bitwise_ptr, ap++;
+    [ap] = keccak_ptr, ap++;
+    [ap] = poseidon_ptr, ap++;
+    [ap] = range_check96_ptr, ap++;
+    [ap] = add_mod_ptr, ap++;
+    [ap] = mul_mod_ptr, ap++;
+    [ap] = evm.value, ap++;
+    call abs precompile_fn;
+
+    let range_check_ptr = [ap - 9];
+    let bitwise_ptr = cast([ap - 8], BitwiseBuiltin*);
+    let keccak_ptr = cast([ap - 7], KeccakBuiltin*);
+    let poseidon_ptr = cast([ap - 6], PoseidonBuiltin*);
+    let range_check96_ptr = cast([ap - 5], felt*);
+    let add_mod_ptr = cast([ap - 4], ModBuiltin*);
+    let mul_mod_ptr = cast([ap - 3], ModBuiltin*);
+    let evm = Evm(cast([ap - 2], EvmStruct*));
+    let err = cast([ap - 1], EthereumException*);
+    return err;
+}
+
+func test__get_precompile_addresses(hardfork: felt) -> (felt, felt, felt) {
+    tempvar precompiles: felt*;
+    tempvar precompiles_len;
+    %{ get_precompile_addresses %}
+    return (precompiles_len, precompiles[0], precompiles[precompiles_len - 1]);
+}
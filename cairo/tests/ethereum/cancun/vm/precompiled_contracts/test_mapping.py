@@ -1,12 +1,26 @@
+import pytest
+from ethereum.cancun.utils.hexadecimal import hex_to_address
+from ethereum.cancun.vm import Evm
+from ethereum.cancun.vm.precompiled_contracts import RIPEMD160_ADDRESS
 from ethereum.cancun.vm.precompiled_contracts.mapping import (
     ECRECOVER_ADDRESS,
     PRE_COMPILED_CONTRACTS,
 )
 from hypothesis import Verbosity, example, given, settings
 from hypothesis import strategies as st
+from hypothesis.strategies import composite
 
-from cairo_addons.testing.errors import cairo_error
+from cairo_addons.testing.errors import cairo_error, strict_raises
 from cairo_addons.testing.hints import patch_hint
+from tests.utils.evm_builder import EvmBuilder
+from tests.utils.message_builder import MessageBuilder
+
+
+@composite
+def evm_test_strategy(draw):
+    input_bytes = draw(st.binary(min_size=0, max_size=256))
+    message = MessageBuilder().with_data(st.just(input_bytes)).build()
+    return draw(EvmBuilder().with_gas_left().with_message(message).build())
 
 
 class TestPrecompileMapping:
@@ -58,3 +72,64 @@ class TestPrecompileMapping:
             cairo_error(message="precompile_table_lookup: address mismatch"),
         ):
             cairo_run_py("precompile_table_lookup", address_int)
+
+    @given(evm=evm_test_strategy())
+    def test_dispatch_ripemd160(self, cairo_run, evm: Evm):
+        address_int = int.from_bytes(RIPEMD160_ADDRESS, "little")
+        try:
+            cairo_evm = cairo_run("test__dispatch_precompile", evm, address_int)
+        except Exception as cairo_error:
+            with strict_raises(type(cairo_error)):
+                PRE_COMPILED_CONTRACTS[RIPEMD160_ADDRESS](evm)
+            return
+
+        PRE_COMPILED_CONTRACTS[RIPEMD160_ADDRESS](evm)
+        assert cairo_evm == evm
+
+    @given(evm=evm_test_strategy())
+    @settings(verbosity=Verbosity.quiet)
+    def test_dispatch_ripemd160_runs_hint(self, cairo_programs, cairo_run_py, evm: Evm):
+        address_int = int.from_bytes(RIPEMD160_ADDRESS, "little")
+        with patch_hint(
+            cairo_programs,
+            "ripemd160_precompile",
+            "ids.output_ptr = segments.gen_arg([0xAB] * 32)",
+        ):
+            try:
+                cairo_evm = cairo_run_py("test__dispatch_precompile", evm, address_int)
+            except Exception as cairo_error:
+                with strict_raises(type(cairo_error)):
+                    PRE_COMPILED_CONTRACTS[RIPEMD160_ADDRESS](evm)
+                return
+
+        assert cairo_evm.output == b"\xab" * 32
+
+    @pytest.mark.parametrize("hardfork, n_precompiles", [(0, 9), (1, 10)])
+    def test_get_precompile_addresses(
+        self, cairo_run, cairo_run_py, hardfork, n_precompiles
+    ):
+        first_address = int.from_bytes(hex_to_address("0x01"), "little")
+        last_address = int.from_bytes(hex_to_address(hex(n_precompiles)), "little")
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__get_precompile_addresses", hardfork) == (
+                n_precompiles,
+                first_address,
+                last_address,
+            )
+
+    def test_get_precompile_addresses_prague(self, cairo_run):
+        n_precompiles, first_address, last_address = cairo_run(
+            "test__get_precompile_addresses", 2
+        )
+        assert n_precompiles > 10
+        assert first_address == int.from_bytes(hex_to_address("0x01"), "little")
+        assert last_address == int.from_bytes(
+            hex_to_address(hex(n_precompiles)), "little"
+        )
+
+    def test_get_precompile_addresses_should_raise_on_unknown_hardfork(
+        self, cairo_run, cairo_run_py
+    ):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="Unknown hardfork: 3"):
+                run("test__get_precompile_addresses", 3)
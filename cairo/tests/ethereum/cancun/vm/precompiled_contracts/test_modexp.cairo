@@ -0,0 +1,16 @@
+from ethereum_types.bytes import Bytes, BytesStruct
+
+func test__modexp(base: Bytes, exp: Bytes, modulus: Bytes) -> Bytes {
+    alloc_locals;
+    local base_ptr: felt* = base.value.data;
+    local base_len = base.value.len;
+    local exp_ptr: felt* = exp.value.data;
+    local exp_len = exp.value.len;
+    local mod_ptr: felt* = modulus.value.data;
+    local mod_len = modulus.value.len;
+    local result_ptr: felt*;
+    local result_len: felt;
+    %{ modexp %}
+    tempvar res = Bytes(new BytesStruct(result_ptr, result_len));
+    return res;
+}
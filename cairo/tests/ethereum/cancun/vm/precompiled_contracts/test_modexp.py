@@ -1,4 +1,5 @@
 import hypothesis.strategies as st
+import pytest
 from ethereum.cancun.vm import Evm
 from ethereum.cancun.vm.precompiled_contracts.modexp import (
     complexity,
@@ -120,3 +121,45 @@ class TestModexp:
         expected_bytes = int_value.to_bytes(length, "big")
         assert len(cairo_result) == length
         assert bytes(cairo_result) == expected_bytes
+
+    @pytest.mark.parametrize(
+        "base, exp, modulus, expected",
+        [
+            (b"\x03", b"\x05", b"\x07", b"\x05"),
+            # The result is left-padded to the length of the modulus.
+            (b"\x02", b"\x03", b"\x00\x00\x0b", b"\x00\x00\x08"),
+            # Zero exponent.
+            (b"\x05", b"", b"\x07", b"\x01"),
+            (b"\x05", b"\x00", b"\x01", b"\x00"),
+            # Zero modulus.
+            (b"\x05", b"\x02", b"\x00\x00", b"\x00\x00"),
+            (b"\x05", b"\x02", b"", b""),
+        ],
+    )
+    def test_modexp_edge_cases(
+        self, cairo_run, cairo_run_py, base, exp, modulus, expected
+    ):
+        for run in (cairo_run, cairo_run_py):
+            result = run(
+                "test__modexp",
+                base=Bytes(base),
+                exp=Bytes(exp),
+                modulus=Bytes(modulus),
+            )
+            assert result == expected
+
+    @given(
+        base=st.binary(max_size=64),
+        exp=st.binary(max_size=32),
+        modulus=st.binary(max_size=64),
+    )
+    def test_modexp_hint(self, cairo_run, base, exp, modulus):
+        modulus_int = int.from_bytes(modulus, "big")
+        expected = (
+            pow(int.from_bytes(base, "big"), int.from_bytes(exp, "big"), modulus_int)
+            if modulus_int != 0
+            else 0
+        )
+        assert cairo_run(
+            "test__modexp", base=Bytes(base), exp=Bytes(exp), modulus=Bytes(modulus)
+        ) == expected.to_bytes(len(modulus), "big")
@@ -0,0 +1,22 @@
+from ethereum_types.bytes import Bytes, BytesStruct
+
+func test__ripemd160_precompile(data: Bytes) -> Bytes {
+    alloc_locals;
+    local input_ptr: felt* = data.value.data;
+    local input_len = data.value.len;
+    local output_ptr: felt*;
+    local gas_cost: felt;
+    %{ ripemd160_precompile %}
+    tempvar res = Bytes(new BytesStruct(output_ptr, 32));
+    return res;
+}
+
+func test__ripemd160_precompile_gas_cost(data: Bytes) -> felt {
+    alloc_locals;
+    local input_ptr: felt* = data.value.data;
+    local input_len = data.value.len;
+    local output_ptr: felt*;
+    local gas_cost: felt;
+    %{ ripemd160_precompile %}
+    return gas_cost;
+}
@@ -1,8 +1,10 @@
 import hashlib
 
+import pytest
 from ethereum.cancun.vm import Evm
 from ethereum.cancun.vm.precompiled_contracts.ripemd160 import ripemd160
 from ethereum.utils.byte import left_pad_zero_bytes
+from ethereum_types.bytes import Bytes
 from hypothesis import given
 from hypothesis import strategies as st
 from hypothesis.strategies import composite
@@ -19,6 +21,16 @@ def evm_test_strategy(draw):
     return draw(EvmBuilder().with_gas_left().with_message(message).build())
 
 
+RIPEMD160_VECTORS = [
+    (b"", "9c1185a5c5e9fc54612808977ee8f548b2258d31"),
+    (b"a", "0bdc9d2d256b3ee9daae347be6f4dc835a467ffe"),
+    (b"abc", "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"),
+    (b"message digest", "5d0689ef49d2fae572b881b123a85ffa21595f36"),
+    (b"abcdefghijklmnopqrstuvwxyz", "f71c27109c692c1b56bbdceb5b9d2865b3708dbc"),
+    (b"1234567890" * 8, "9b752e45573d4b39f4dbd3323cab82bf63326bfb"),
+]
+
+
 class TestRIPEMD160:
     @given(evm=evm_test_strategy())
     def test_ripemd160(self, cairo_run, evm: Evm):
@@ -38,3 +50,15 @@ class TestRIPEMD160:
 
         assert evm == cairo_result
         assert cairo_result.output == expected_output
+
+    @pytest.mark.parametrize("data, digest", RIPEMD160_VECTORS)
+    def test_ripemd160_precompile(self, cairo_run, cairo_run_py, data, digest):
+        expected = bytes.fromhex(digest).rjust(32, b"\x00")
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__ripemd160_precompile", data=Bytes(data)) == expected
+
+    @given(data=st.binary(max_size=256))
+    def test_ripemd160_precompile_gas_cost(self, cairo_run, data):
+        assert cairo_run(
+            "test__ripemd160_precompile_gas_cost", data=Bytes(data)
+        ) == 600 + 120 * ((len(data) + 31) // 32)
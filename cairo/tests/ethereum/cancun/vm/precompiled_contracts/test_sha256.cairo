@@ -0,0 +1,11 @@
+from ethereum_types.bytes import Bytes, BytesStruct
+
+func test__sha256_precompile(data: Bytes) -> Bytes {
+    alloc_locals;
+    local data_ptr: felt* = data.value.data;
+    local data_len = data.value.len;
+    local output_ptr: felt*;
+    %{ sha256_precompile %}
+    tempvar res = Bytes(new BytesStruct(output_ptr, 32));
+    return res;
+}
@@ -1,7 +1,12 @@
+import hashlib
+
+import pytest
 from ethereum.cancun.vm import Evm
 from ethereum.cancun.vm.precompiled_contracts.sha256 import sha256
 from ethereum.exceptions import EthereumException
+from ethereum_types.bytes import Bytes
 from hypothesis import given
+from hypothesis import strategies as st
 
 from cairo_addons.testing.errors import strict_raises
 from tests.utils.evm_builder import EvmBuilder
@@ -24,3 +29,35 @@ def test_sha256(cairo_run, evm: Evm):
 
     sha256(evm)
     assert cairo_evm == evm
+
+
+SHA256_VECTORS = [
+    # NIST FIPS 180-2 examples
+    (b"", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+    (b"abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+    (
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+    ),
+    (
+        b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmn"
+        b"hijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+        "cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1",
+    ),
+]
+
+
+class TestSha256Precompile:
+    @pytest.mark.parametrize("data, digest", SHA256_VECTORS)
+    def test_sha256_precompile(self, cairo_run, cairo_run_py, data, digest):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__sha256_precompile", data=Bytes(data)) == bytes.fromhex(
+                digest
+            )
+
+    @given(data=st.binary(max_size=256))
+    def test_sha256_precompile_matches_hashlib(self, cairo_run, data):
+        assert (
+            cairo_run("test__sha256_precompile", data=Bytes(data))
+            == hashlib.sha256(data).digest()
+        )
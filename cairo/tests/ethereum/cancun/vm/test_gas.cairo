@@ -0,0 +1,11 @@
+func test__evm_opcode_gas_cost(opcode: felt) -> felt {
+    tempvar base_gas;
+    %{ evm_opcode_gas_cost %}
+    return base_gas;
+}
+
+func test__evm_opcode_is_static_gas(opcode: felt) -> felt {
+    tempvar is_static_gas;
+    %{ evm_opcode_is_static_gas %}
+    return is_static_gas;
+}
@@ -1,5 +1,6 @@
 from typing import List, Tuple
 
+import pytest
 from ethereum.cancun.blocks import Header
 from ethereum.cancun.transactions import BlobTransaction, Transaction
 from ethereum.cancun.vm import Evm
@@ -140,3 +141,52 @@ class TestGas:
             calculate_data_fee(excess_blob_gas, tx), Uint((2**64 - 1) ** 2)
         )
         assert data_fee_py == cairo_run("calculate_data_fee", excess_blob_gas, tx)
+
+
+class TestGasTable:
+    @pytest.mark.parametrize(
+        "opcode, base_gas",
+        [
+            (0x00, 0),  # STOP
+            (0x01, 3),  # ADD
+            (0x0A, 10),  # EXP
+            (0x0C, 0),  # undefined
+            (0x20, 30),  # KECCAK256
+            (0x40, 20),  # BLOCKHASH
+            (0x54, 100),  # SLOAD
+            (0x5B, 1),  # JUMPDEST
+            (0x60, 3),  # PUSH1
+            (0xA2, 1125),  # LOG2
+            (0xF0, 32000),  # CREATE
+            (0xFF, 5000),  # SELFDESTRUCT
+        ],
+    )
+    def test_evm_opcode_gas_cost(self, cairo_run, cairo_run_py, opcode, base_gas):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__evm_opcode_gas_cost", opcode) == base_gas
+
+    @pytest.mark.parametrize(
+        "opcode, is_static_gas",
+        [
+            (0x01, 1),  # ADD
+            (0x0A, 0),  # EXP
+            (0x0C, 0),  # undefined
+            (0x51, 0),  # MLOAD
+            (0x5C, 1),  # TLOAD
+            (0x9F, 1),  # SWAP16
+            (0xA0, 0),  # LOG0
+            (0xFE, 0),  # INVALID
+        ],
+    )
+    def test_evm_opcode_is_static_gas(
+        self, cairo_run, cairo_run_py, opcode, is_static_gas
+    ):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__evm_opcode_is_static_gas", opcode) == is_static_gas
+
+    def test_evm_opcode_gas_cost_should_raise_on_invalid_opcode(
+        self, cairo_run, cairo_run_py
+    ):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="Invalid opcode: 256"):
+                run("test__evm_opcode_gas_cost", 256)
@@ -0,0 +1,106 @@
+from starkware.cairo.common.cairo_builtins import UInt384
+from starkware.cairo.common.uint256 import Uint256
+from cairo_ec.curve.g2_point import G2Point
+from ethereum_types.bytes import Bytes
+from ethereum_types.numeric import U256
+
+func test__is_point_on_stark_curve(x: felt, y: felt) -> felt {
+    tempvar on_curve;
+    %{ is_point_on_stark_curve %}
+    return on_curve;
+}
+
+func test__assert_point_on_stark_curve(x: felt, y: felt) -> felt {
+    tempvar on_curve;
+    %{ assert_point_on_stark_curve %}
+    return on_curve;
+}
+
+func test__is_point_on_bn254(x_low: felt, x_high: felt, y_low: felt, y_high: felt) -> felt {
+    alloc_locals;
+    local x: Uint256 = Uint256(x_low, x_high);
+    local y: Uint256 = Uint256(y_low, y_high);
+    tempvar on_curve;
+    %{ is_point_on_bn254 %}
+    return on_curve;
+}
+
+func test__assert_point_on_bn254(x_low: felt, x_high: felt, y_low: felt, y_high: felt) -> felt {
+    alloc_locals;
+    local x: Uint256 = Uint256(x_low, x_high);
+    local y: Uint256 = Uint256(y_low, y_high);
+    tempvar on_curve;
+    %{ assert_point_on_bn254 %}
+    return on_curve;
+}
+
+func test__is_point_on_bls12_381_g1(
+    x_d0: felt,
+    x_d1: felt,
+    x_d2: felt,
+    x_d3: felt,
+    y_d0: felt,
+    y_d1: felt,
+    y_d2: felt,
+    y_d3: felt,
+) -> felt {
+    alloc_locals;
+    local x: UInt384 = UInt384(x_d0, x_d1, x_d2, x_d3);
+    local y: UInt384 = UInt384(y_d0, y_d1, y_d2, y_d3);
+    tempvar on_curve;
+    %{ is_point_on_bls12_381_g1 %}
+    return on_curve;
+}
+
+func test__bls12_381_hash_to_g2(message: Bytes, dst: Bytes) -> (
+    felt, felt, felt, felt, felt, felt, felt, felt, felt, felt, felt, felt, felt, felt, felt, felt
+) {
+    alloc_locals;
+    local message_ptr: felt* = message.value.data;
+    local message_len = message.value.len;
+    local dst_ptr: felt* = dst.value.data;
+    local dst_len = dst.value.len;
+    local result: G2Point;
+    %{ bls12_381_hash_to_g2 %}
+    return (
+        result.a0.d0,
+        result.a0.d1,
+        result.a0.d2,
+        result.a0.d3,
+        result.b0.d0,
+        result.b0.d1,
+        result.b0.d2,
+        result.b0.d3,
+        result.a1.d0,
+        result.a1.d1,
+        result.a1.d2,
+        result.a1.d3,
+        result.b1.d0,
+        result.b1.d1,
+        result.b1.d2,
+        result.b1.d3,
+    );
+}
+
+func test__secp256k1_add(a_x: U256, a_y: U256, b_x: U256, b_y: U256) -> (felt, felt, felt, felt) {
+    alloc_locals;
+    local x1: Uint256 = Uint256(a_x.value.low, a_x.value.high);
+    local y1: Uint256 = Uint256(a_y.value.low, a_y.value.high);
+    local x2: Uint256 = Uint256(b_x.value.low, b_x.value.high);
+    local y2: Uint256 = Uint256(b_y.value.low, b_y.value.high);
+    local x3: Uint256;
+    local y3: Uint256;
+    %{ secp256k1_add %}
+    return (x3.low, x3.high, y3.low, y3.high);
+}
+
+func test__secp256k1_mul(p_x: U256, p_y: U256, k: U256) -> (felt, felt, felt, felt) {
+    alloc_locals;
+    local x: Uint256 = Uint256(p_x.value.low, p_x.value.high);
+    local y: Uint256 = Uint256(p_y.value.low, p_y.value.high);
+    local scalar: Uint256 = Uint256(k.value.low, k.value.high);
+    local x_res: Uint256;
+    local y_res: Uint256;
+    %{ secp256k1_mul %}
+    return (x_res.low, x_res.high, y_res.low, y_res.high);
+}
@@ -1,10 +1,16 @@
+import pytest
 from eth_keys.datatypes import PrivateKey
 from ethereum.cancun.fork_types import Address
+from ethereum.crypto.alt_bn128 import ALT_BN128_PRIME
 from ethereum.crypto.elliptic_curve import secp256k1_recover
 from ethereum.crypto.hash import Hash32, keccak256
 from ethereum_types.bytes import Bytes
 from ethereum_types.numeric import U256
 from hypothesis import given
+from hypothesis import strategies as st
+from py_ecc.bls12_381 import G1 as BLS12_381_G1
+from py_ecc.secp256k1 import secp256k1
+from starkware.crypto.signature.signature import EC_GEN
 
 from cairo_addons.testing.errors import strict_raises
 from tests.utils.strategies import bytes64
@@ -60,3 +66,180 @@ class TestEllipticCurve:
         )
 
         assert cairo_result == Address(keccak256(public_key)[12:32])
+
+
+class TestPointOnCurve:
+    @pytest.mark.parametrize(
+        "point, expected",
+        [(EC_GEN, 1), ((EC_GEN[0], EC_GEN[1] + 1), 0), ((0, 0), 0)],
+    )
+    def test_is_point_on_stark_curve(self, cairo_run, cairo_run_py, point, expected):
+        x, y = point
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__is_point_on_stark_curve", x=x, y=y) == expected
+
+    @pytest.mark.parametrize(
+        "point, expected",
+        [
+            ((1, 2), 1),
+            ((1, ALT_BN128_PRIME - 2), 1),
+            ((1, 3), 0),
+            ((1, ALT_BN128_PRIME + 2), 0),
+        ],
+    )
+    def test_is_point_on_bn254(self, cairo_run, cairo_run_py, point, expected):
+        x, y = point
+        kwargs = {
+            "x_low": x % 2**128,
+            "x_high": x >> 128,
+            "y_low": y % 2**128,
+            "y_high": y >> 128,
+        }
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__is_point_on_bn254", **kwargs) == expected
+
+    def test_assert_point_on_curve(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__assert_point_on_stark_curve", *EC_GEN) == 1
+            assert run("test__assert_point_on_bn254", 1, 0, 2, 0) == 1
+            with pytest.raises(Exception, match="Point not on curve"):
+                run("test__assert_point_on_stark_curve", x=0, y=0)
+            with pytest.raises(Exception, match="Point not on curve"):
+                run("test__assert_point_on_bn254", 1, 0, 3, 0)
+
+    @pytest.mark.parametrize("y_offset, expected", [(0, 1), (1, 0)])
+    def test_is_point_on_bls12_381_g1(self, cairo_run_py, y_offset, expected):
+        # The Rust hint is only available with the `bls` feature.
+        x, y = (int(coordinate) for coordinate in BLS12_381_G1)
+        limbs = [
+            (value >> (96 * i)) % 2**96 for value in (x, y + y_offset) for i in range(4)
+        ]
+        assert cairo_run_py("test__is_point_on_bls12_381_g1", *limbs) == expected
+
+
+HASH_TO_G2_DST = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_"
+
+
+class TestHashToCurve:
+    # Test vectors from RFC 9380, appendix J.10.1.
+    @pytest.mark.parametrize(
+        "message, x, y",
+        [
+            (
+                b"",
+                (
+                    0x0141EBFBDCA40EB85B87142E130AB689C673CF60F1A3E98D69335266F30D9B8D4AC44C1038E9DCDD5393FAF5C41FB78A,
+                    0x05CB8437535E20ECFFAEF7752BADDF98034139C38452458BAEEFAB379BA13DFF5BF5DD71B72418717047F5B0F37DA03D,
+                ),
+                (
+                    0x0503921D7F6A12805E72940B963C0CF3471C7B2A524950CA195D11062EE75EC076DAF2D4BC358C4B190C0C98064FDD92,
+                    0x12424AC32561493F3FE3C260708A12B7C620E7BE00099A974E259DDC7D1F6395C3C811CDD19F1E8DBF3E9ECFDCBAB8D6,
+                ),
+            ),
+            (
+                b"abc",
+                (
+                    0x02C2D18E033B960562AAE3CAB37A27CE00D80CCD5BA4B7FE0E7A210245129DBEC7780CCC7954725F4168AFF2787776E6,
+                    0x139CDDBCCDC5E91B9623EFD38C49F81A6F83F175E80B06FC374DE9EB4B41DFE4CA3A230ED250FBE3A2ACF73A41177FD8,
+                ),
+                (
+                    0x1787327B68159716A37440985269CF584BCB1E621D3A7202BE6EA05C4CFE244AEB197642555A0645FB87BF7466B2BA48,
+                    0x00AA65DAE3C8D732D10ECD2C50F8A1BAF3001578F71C694E03866E9F3D49AC1E1CE70DD94A733534F106D4CEC0EDDD16,
+                ),
+            ),
+        ],
+    )
+    def test_bls12_381_hash_to_g2(self, cairo_run, cairo_run_py, message, x, y):
+        # The G2Point fields (a0, b0, a1, b1) hold (x.c1, x.c0, y.c1, y.c0).
+        expected = [x[1], x[0], y[1], y[0]]
+        for run in (cairo_run, cairo_run_py):
+            limbs = run(
+                "test__bls12_381_hash_to_g2", Bytes(message), Bytes(HASH_TO_G2_DST)
+            )
+            coefficients = [
+                sum(limb << (96 * i) for i, limb in enumerate(limbs[j : j + 4]))
+                for j in range(0, 16, 4)
+            ]
+            assert coefficients == expected
+
+
+# Multiples of the secp256k1 generator, as published with SEC 2.
+SECP256K1_G = (
+    0x79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798,
+    0x483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8,
+)
+
+
+SECP256K1_2G = (
+    0xC6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5,
+    0x1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A,
+)
+
+
+SECP256K1_3G = (
+    0xF9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9,
+    0x388F7B0F632DE8140FE337E62A37F3566500A99934C2231B6CB9FD7584B8E672,
+)
+
+
+SECP256K1_NEG_G = (SECP256K1_G[0], secp256k1.P - SECP256K1_G[1])
+
+
+def _secp256k1_point(result):
+    x_low, x_high, y_low, y_high = result
+    return (x_low + (x_high << 128), y_low + (y_high << 128))
+
+
+def _secp256k1_add(run, p, q):
+    args = (U256(coordinate) for coordinate in (*p, *q))
+    return _secp256k1_point(run("test__secp256k1_add", *args))
+
+
+def _secp256k1_mul(run, point, scalar):
+    args = (U256(point[0]), U256(point[1]), U256(scalar))
+    return _secp256k1_point(run("test__secp256k1_mul", *args))
+
+
+class TestSecp256k1:
+    @pytest.mark.parametrize(
+        "p, q, expected",
+        [
+            (SECP256K1_G, SECP256K1_G, SECP256K1_2G),
+            (SECP256K1_G, SECP256K1_2G, SECP256K1_3G),
+            (SECP256K1_G, SECP256K1_NEG_G, (0, 0)),
+            (SECP256K1_G, (0, 0), SECP256K1_G),
+            ((0, 0), (0, 0), (0, 0)),
+        ],
+    )
+    def test_secp256k1_add(self, cairo_run, cairo_run_py, p, q, expected):
+        for run in (cairo_run, cairo_run_py):
+            assert _secp256k1_add(run, p, q) == expected
+
+    @pytest.mark.parametrize(
+        "scalar, expected",
+        [
+            (0, (0, 0)),
+            (1, SECP256K1_G),
+            (2, SECP256K1_2G),
+            (3, SECP256K1_3G),
+            (secp256k1.N - 1, SECP256K1_NEG_G),
+            (secp256k1.N, (0, 0)),
+            (secp256k1.N + 2, SECP256K1_2G),
+        ],
+    )
+    def test_secp256k1_mul(self, cairo_run, cairo_run_py, scalar, expected):
+        for run in (cairo_run, cairo_run_py):
+            assert _secp256k1_mul(run, SECP256K1_G, scalar) == expected
+
+    @given(scalar=st.integers(min_value=0, max_value=2**256 - 1))
+    def test_secp256k1_mul_matches_py_ecc(self, cairo_run, scalar):
+        expected = secp256k1.multiply(SECP256K1_G, scalar)
+        assert _secp256k1_mul(cairo_run, SECP256K1_G, scalar) == expected
+
+    def test_secp256k1_should_reject_points_not_on_curve(self, cairo_run, cairo_run_py):
+        point = (SECP256K1_G[0], SECP256K1_G[1] + 1)
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match=r"\(x2, y2\) is not on the curve"):
+                _secp256k1_add(run, SECP256K1_G, point)
+            with pytest.raises(Exception, match=r"\(x, y\) is not on the curve"):
+                _secp256k1_mul(run, point, 1)
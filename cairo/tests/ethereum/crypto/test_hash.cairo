@@ -0,0 +1,11 @@
+from ethereum_types.bytes import Bytes
+
+func test__compute_keccak256(data: Bytes) -> (felt, felt) {
+    alloc_locals;
+    local data_ptr: felt* = data.value.data;
+    local data_len = data.value.len;
+    tempvar low;
+    tempvar high;
+    %{ compute_keccak256 %}
+    return (low, high);
+}
@@ -1,3 +1,4 @@
+import pytest
 from ethereum.crypto.hash import keccak256
 from ethereum_types.bytes import Bytes
 from hypothesis import given
@@ -10,3 +11,22 @@ class TestHash:
         @given(buffer=st.binary(max_size=1000).map(Bytes))
         def test_keccak256(self, cairo_run, buffer: Bytes):
             assert keccak256(buffer) == cairo_run("keccak256", buffer)
+
+
+class TestKeccak:
+    @pytest.mark.parametrize("data", [b"", b"\x01", b"hello world", bytes(range(136))])
+    def test_compute_keccak256_known_inputs(self, cairo_run, cairo_run_py, data):
+        expected = int.from_bytes(keccak256(data), "big")
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__compute_keccak256", data=Bytes(data)) == (
+                expected % 2**128,
+                expected >> 128,
+            )
+
+    @given(data=st.binary(max_size=256))
+    def test_compute_keccak256(self, cairo_run, data):
+        expected = int.from_bytes(keccak256(data), "big")
+        assert cairo_run("test__compute_keccak256", data=Bytes(data)) == (
+            expected % 2**128,
+            expected >> 128,
+        )
@@ -0,0 +1,24 @@
+from ethereum_types.bytes import Bytes, BytesStruct
+from ethereum_types.numeric import U256, U256Struct
+
+func test__abi_decode_uint256(calldata: Bytes, word_offset: felt) -> U256 {
+    alloc_locals;
+    local calldata_ptr: felt* = calldata.value.data;
+    local calldata_len = calldata.value.len;
+    local low: felt;
+    local high: felt;
+    %{ abi_decode_uint256 %}
+    tempvar res = U256(new U256Struct(low, high));
+    return res;
+}
+
+func test__abi_decode_bytes_dynamic(calldata: Bytes, word_offset: felt) -> Bytes {
+    alloc_locals;
+    local calldata_ptr: felt* = calldata.value.data;
+    local calldata_len = calldata.value.len;
+    local data_ptr: felt*;
+    local data_len: felt;
+    %{ abi_decode_bytes_dynamic %}
+    tempvar res = Bytes(new BytesStruct(data_ptr, data_len));
+    return res;
+}
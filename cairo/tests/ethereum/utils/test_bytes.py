@@ -1,6 +1,8 @@
 from typing import List
 
+import pytest
 from ethereum_types.bytes import Bytes, Bytes4, Bytes8, Bytes20, Bytes32, Bytes256
+from ethereum_types.numeric import U256
 from hypothesis import given
 from hypothesis import strategies as st
 
@@ -51,3 +53,35 @@ class TestBytes:
     )
     def test_Bytes256__eq__(self, cairo_run, a: Bytes256, b: Bytes256):
         assert (a == b) == cairo_run("Bytes256__eq__", a, b)
+
+
+class TestCalldata:
+    @given(
+        values=st.lists(st.integers(min_value=0, max_value=2**256 - 1), min_size=1),
+        data=st.data(),
+    )
+    def test_abi_decode_uint256(self, cairo_run, values, data):
+        calldata = Bytes(b"".join(value.to_bytes(32, "big") for value in values))
+        word_offset = data.draw(st.integers(min_value=0, max_value=len(values) - 1))
+        assert cairo_run(
+            "test__abi_decode_uint256", calldata=calldata, word_offset=word_offset
+        ) == U256(values[word_offset])
+
+    def test_abi_decode_uint256_should_fail_out_of_bounds(self, cairo_run):
+        with pytest.raises(Exception, match="exceeds calldata length"):
+            cairo_run(
+                "test__abi_decode_uint256", calldata=Bytes(b"\x00" * 63), word_offset=1
+            )
+
+    @given(selector=st.integers(min_value=0, max_value=2**256 - 1), value=st.binary())
+    def test_abi_decode_bytes_dynamic(self, cairo_run, selector, value):
+        # abi.encode(uint256, bytes)
+        calldata = Bytes(
+            selector.to_bytes(32, "big")
+            + (64).to_bytes(32, "big")
+            + len(value).to_bytes(32, "big")
+            + value.ljust((len(value) + 31) // 32 * 32, b"\x00")
+        )
+        assert cairo_run(
+            "test__abi_decode_bytes_dynamic", calldata=calldata, word_offset=1
+        ) == Bytes(value)
@@ -0,0 +1,150 @@
+from starkware.cairo.common.alloc import alloc
+from starkware.cairo.common.default_dict import default_dict_new
+from starkware.cairo.common.dict import dict_read, dict_write
+from starkware.cairo.common.dict_access import DictAccess
+from tests.utils.array import fill_range
+
+func _hashdict_write(dict_ptr: DictAccess*, key_len: felt, key: felt*, new_value: felt) {
+    %{ hashdict_write %}
+    return ();
+}
+
+func _hashdict_read(dict_ptr: DictAccess*, key_len: felt, key: felt*) -> felt {
+    tempvar value;
+    %{ hashdict_read %}
+    return value;
+}
+
+func _hashdict_delete(dict_ptr: DictAccess*, key_len: felt, key: felt*) {
+    %{ hashdict_delete %}
+    return ();
+}
+
+func test__hashdict_delete(key_len: felt) -> (felt, felt) {
+    alloc_locals;
+    let (local dict_ptr: DictAccess*) = default_dict_new(default_value=0);
+    let (local key: felt*) = alloc();
+    fill_range(key, 0, key_len);
+    _hashdict_write(dict_ptr, key_len, key, 42);
+    let value_before = _hashdict_read(dict_ptr + DictAccess.SIZE, key_len, key);
+    _hashdict_delete(dict_ptr + 2 * DictAccess.SIZE, key_len, key);
+    let value_after = _hashdict_read(dict_ptr + 3 * DictAccess.SIZE, key_len, key);
+    return (value_before, value_after);
+}
+
+func test__hashdict_delete_missing_key(key_len: felt) {
+    alloc_locals;
+    let (local dict_ptr: DictAccess*) = default_dict_new(default_value=0);
+    let (local key: felt*) = alloc();
+    fill_range(key, 0, key_len);
+    _hashdict_delete(dict_ptr, key_len, key);
+    return ();
+}
+
+func _hashdict_contains(dict_ptr: DictAccess*, key_len: felt, key: felt*) -> felt {
+    tempvar result;
+    %{ hashdict_contains %}
+    return result;
+}
+
+func test__hashdict_contains(key_len: felt) -> (felt, felt, felt) {
+    alloc_locals;
+    let (local dict_ptr: DictAccess*) = default_dict_new(default_value=0);
+    let (local key: felt*) = alloc();
+    fill_range(key, 0, key_len);
+    let contains_before = _hashdict_contains(dict_ptr, key_len, key);
+    _hashdict_write(dict_ptr, key_len, key, 42);
+    let contains_after = _hashdict_contains(dict_ptr + DictAccess.SIZE, key_len, key);
+    // The membership tests did not advance the dict pointer.
+    let value = _hashdict_read(dict_ptr + DictAccess.SIZE, key_len, key);
+    return (contains_before, contains_after, value);
+}
+
+func _hashdict_merge(src_dict_ptr: DictAccess*, dst_dict_ptr: DictAccess*) {
+    %{ hashdict_merge %}
+    return ();
+}
+
+func test__hashdict_merge(key_len: felt) -> (felt, felt) {
+    alloc_locals;
+    let (local src: DictAccess*) = default_dict_new(default_value=0);
+    let (local dst: DictAccess*) = default_dict_new(default_value=0);
+    let (local keys: felt*) = alloc();
+    fill_range(keys, 0, key_len + 1);
+    let key_a = keys;
+    let key_b = keys + 1;
+    _hashdict_write(src, key_len, key_a, 42);
+    _hashdict_write(dst, key_len, key_a, 7);
+    _hashdict_write(dst + DictAccess.SIZE, key_len, key_b, 8);
+    // The source has a single entry, so the destination pointer is advanced by one access.
+    _hashdict_merge(src + DictAccess.SIZE, dst + 2 * DictAccess.SIZE);
+    let value_a = _hashdict_read(dst + 3 * DictAccess.SIZE, key_len, key_a);
+    let value_b = _hashdict_read(dst + 4 * DictAccess.SIZE, key_len, key_b);
+    return (value_a, value_b);
+}
+
+func _hashdict_len(dict_ptr: DictAccess*) -> felt {
+    tempvar len;
+    %{ hashdict_len %}
+    return len;
+}
+
+func test__hashdict_len(key_len: felt) -> (felt, felt, felt) {
+    alloc_locals;
+    let (local dict_ptr: DictAccess*) = default_dict_new(default_value=0);
+    let (local keys: felt*) = alloc();
+    fill_range(keys, 0, key_len + 1);
+    let len_empty = _hashdict_len(dict_ptr);
+    _hashdict_write(dict_ptr, key_len, keys, 1);
+    _hashdict_write(dict_ptr + DictAccess.SIZE, key_len, keys + 1, 2);
+    // Overwriting an existing key does not add an entry.
+    _hashdict_write(dict_ptr + 2 * DictAccess.SIZE, key_len, keys, 3);
+    let len_filled = _hashdict_len(dict_ptr + 3 * DictAccess.SIZE);
+    // Computing the length did not advance the dict pointer.
+    let value = _hashdict_read(dict_ptr + 3 * DictAccess.SIZE, key_len, keys);
+    return (len_empty, len_filled, value);
+}
+
+func test__copy_hashdict_tracker_entry_simple_key(key: felt, value: felt) -> felt {
+    alloc_locals;
+    let (dict_ptr) = default_dict_new(0);
+    dict_write{dict_ptr=dict_ptr}(key=key, new_value=value);
+    local source_ptr_stop: DictAccess* = dict_ptr;
+    let (local dest_ptr: DictAccess*) = default_dict_new(0);
+    local source_key = key;
+    %{ copy_hashdict_tracker_entry %}
+    let dest_ptr_stop = dest_ptr + DictAccess.SIZE;
+    let (copied_value) = dict_read{dict_ptr=dest_ptr_stop}(key=key);
+    return copied_value;
+}
+
+func test__copy_hashdict_tracker_range(prefix_len: felt) -> (felt, felt, felt, felt) {
+    alloc_locals;
+    let (local source: DictAccess*) = default_dict_new(default_value=0);
+    let (local key_a: felt*) = alloc();
+    assert key_a[0] = 1;
+    assert key_a[1] = 2;
+    let (local key_b: felt*) = alloc();
+    assert key_b[0] = 1;
+    assert key_b[1] = 3;
+    let (local key_c: felt*) = alloc();
+    assert key_c[0] = 2;
+    assert key_c[1] = 2;
+    _hashdict_write(source, 2, key_a, 10);
+    _hashdict_write(source + DictAccess.SIZE, 2, key_b, 20);
+    _hashdict_write(source + 2 * DictAccess.SIZE, 2, key_c, 30);
+
+    local source_ptr_stop: DictAccess* = source + 3 * DictAccess.SIZE;
+    let (local dest_ptr: DictAccess*) = default_dict_new(default_value=0);
+    let (local prefix_ptr: felt*) = alloc();
+    assert prefix_ptr[0] = 1;
+    assert prefix_ptr[1] = 3;
+    local count: felt;
+    %{ copy_hashdict_tracker_range %}
+
+    let dest_ptr_stop = dest_ptr + count * DictAccess.SIZE;
+    let value_a = _hashdict_read(dest_ptr_stop, 2, key_a);
+    let value_b = _hashdict_read(dest_ptr_stop + DictAccess.SIZE, 2, key_b);
+    let value_c = _hashdict_read(dest_ptr_stop + 2 * DictAccess.SIZE, 2, key_c);
+    return (count, value_a, value_b, value_c);
+}
@@ -0,0 +1,65 @@
+import pytest
+from hypothesis import given
+from hypothesis import strategies as st
+from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+
+
+class TestHashdictDelete:
+    @pytest.mark.parametrize("key_len", [1, 3])
+    def test_hashdict_delete(self, cairo_run, cairo_run_py, key_len):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__hashdict_delete", key_len) == (42, 0)
+
+    @pytest.mark.parametrize("key_len", [1, 3])
+    def test_hashdict_delete_should_raise_on_missing_key(
+        self, cairo_run, cairo_run_py, key_len
+    ):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="No value found for preimage"):
+                run("test__hashdict_delete_missing_key", key_len)
+
+
+class TestHashdictContains:
+    @pytest.mark.parametrize("key_len", [1, 3])
+    def test_hashdict_contains(self, cairo_run, cairo_run_py, key_len):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__hashdict_contains", key_len) == (0, 1, 42)
+
+
+class TestHashdictMerge:
+    @pytest.mark.parametrize("key_len", [1, 3])
+    def test_hashdict_merge(self, cairo_run, cairo_run_py, key_len):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__hashdict_merge", key_len) == (42, 8)
+
+
+class TestHashdictLen:
+    @pytest.mark.parametrize("key_len", [1, 3])
+    def test_hashdict_len(self, cairo_run, cairo_run_py, key_len):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__hashdict_len", key_len) == (0, 2, 3)
+
+
+class TestCopyHashdictTrackerEntry:
+    @given(
+        key=st.integers(min_value=0, max_value=DEFAULT_PRIME - 1),
+        value=st.integers(min_value=0, max_value=DEFAULT_PRIME - 1),
+    )
+    def test_copy_simple_key(self, cairo_run, key, value):
+        # Keys written with `dict_write` are simple keys without a registered preimage.
+        assert (
+            cairo_run("test__copy_hashdict_tracker_entry_simple_key", key, value)
+            == value
+        )
+
+
+class TestCopyHashdictTrackerRange:
+    @pytest.mark.parametrize(
+        "prefix_len, expected",
+        [(0, (3, 10, 20, 30)), (1, (2, 10, 20, 0)), (2, (1, 0, 20, 0))],
+    )
+    def test_copy_hashdict_tracker_range(
+        self, cairo_run, cairo_run_py, prefix_len, expected
+    ):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__copy_hashdict_tracker_range", prefix_len) == expected
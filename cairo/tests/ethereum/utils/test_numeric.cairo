@@ -0,0 +1,60 @@
+func test__uint256_add(a_low: felt, a_high: felt, b_low: felt, b_high: felt) -> (felt, felt, felt) {
+    tempvar res_low;
+    tempvar res_high;
+    tempvar carry;
+    %{ uint256_add %}
+    return (res_low, res_high, carry);
+}
+
+func test__uint256_mul(a_low: felt, a_high: felt, b_low: felt, b_high: felt) -> (
+    felt, felt, felt, felt
+) {
+    tempvar res_0;
+    tempvar res_1;
+    tempvar res_2;
+    tempvar res_3;
+    %{ uint256_mul %}
+    return (res_0, res_1, res_2, res_3);
+}
+
+func test__uint256_divmod(
+    dividend_low: felt, dividend_high: felt, divisor_low: felt, divisor_high: felt
+) -> (felt, felt, felt, felt) {
+    tempvar quotient_low;
+    tempvar quotient_high;
+    tempvar remainder_low;
+    tempvar remainder_high;
+    %{ uint256_divmod %}
+    return (quotient_low, quotient_high, remainder_low, remainder_high);
+}
+
+func test__is_felt_negative(value: felt) -> felt {
+    tempvar is_neg;
+    %{ is_felt_negative %}
+    return is_neg;
+}
+
+func test__felt_abs(value: felt) -> (felt, felt) {
+    tempvar abs;
+    tempvar sign;
+    %{ felt_abs %}
+    return (abs, sign);
+}
+
+func test__felt_min(a: felt, b: felt) -> felt {
+    tempvar min;
+    %{ felt_min %}
+    return min;
+}
+
+func test__is_felt_le(a: felt, b: felt) -> felt {
+    tempvar is_le;
+    %{ is_felt_le %}
+    return is_le;
+}
+
+func test__is_felt_ge(a: felt, b: felt) -> felt {
+    tempvar is_ge;
+    %{ is_felt_ge %}
+    return is_ge;
+}
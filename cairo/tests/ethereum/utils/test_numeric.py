@@ -10,10 +10,12 @@ from ethereum_types.bytes import Bytes, Bytes32
 from ethereum_types.numeric import U64, U256, Uint
 from hypothesis import example, given
 from hypothesis import strategies as st
+from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
 from starkware.cairo.lang.instances import PRIME
 
 from cairo_addons.testing.errors import strict_raises
 from tests.utils.args_gen import U384
+from tests.utils.helpers import split_uint256s
 from tests.utils.strategies import small_bytes, uint128, uint256
 
 
@@ -277,3 +279,157 @@ class TestNumeric:
         result = cairo_run("U256_max", a, b)
         expected = max(a, b)
         assert result == expected
+
+
+class TestUint256:
+    @pytest.mark.parametrize(
+        "a, b",
+        [
+            (0, 0),
+            (1, 2),
+            # Carry from the low limb to the high limb.
+            (2**128 - 1, 1),
+            # Carry out of the high limb.
+            (2**256 - 1, 1),
+            (2**256 - 1, 2**256 - 1),
+            (2**255, 2**255),
+        ],
+    )
+    def test_uint256_add(self, cairo_run, cairo_run_py, a, b):
+        res = a + b
+        expected = (res % 2**128, (res >> 128) % 2**128, res >> 256)
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__uint256_add", *split_uint256s(a, b)) == expected
+
+    @given(a=st.from_type(U256), b=st.from_type(U256))
+    def test_uint256_add_matches_int(self, cairo_run, a, b):
+        res = int(a) + int(b)
+        assert cairo_run("test__uint256_add", *split_uint256s(int(a), int(b))) == (
+            res % 2**128,
+            (res >> 128) % 2**128,
+            res >> 256,
+        )
+
+    def test_uint256_add_should_raise_on_invalid_limb(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="b_high exceeds 128 bits"):
+                run("test__uint256_add", 0, 0, 0, 2**128)
+
+    @pytest.mark.parametrize(
+        "a, b",
+        [
+            (0, 0),
+            (0, 2**256 - 1),
+            (1, 2**256 - 1),
+            (2**128 - 1, 2**128 - 1),
+            (2**128, 2**128),
+            (2**256 - 1, 2),
+            (2**256 - 1, 2**256 - 1),
+        ],
+    )
+    def test_uint256_mul(self, cairo_run, cairo_run_py, a, b):
+        res = a * b
+        expected = tuple((res >> (128 * i)) % 2**128 for i in range(4))
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__uint256_mul", *split_uint256s(a, b)) == expected
+
+    @given(a=st.from_type(U256), b=st.from_type(U256))
+    def test_uint256_mul_matches_int(self, cairo_run, a, b):
+        res = int(a) * int(b)
+        assert cairo_run("test__uint256_mul", *split_uint256s(int(a), int(b))) == tuple(
+            (res >> (128 * i)) % 2**128 for i in range(4)
+        )
+
+    @given(dividend=st.from_type(U256), divisor=st.from_type(U256).filter(bool))
+    def test_uint256_divmod_matches_int(self, cairo_run, dividend, divisor):
+        quotient, remainder = divmod(int(dividend), int(divisor))
+        assert cairo_run(
+            "test__uint256_divmod", *split_uint256s(int(dividend), int(divisor))
+        ) == tuple(split_uint256s(quotient, remainder))
+
+    @given(dividend=st.from_type(U256), divisor=st.from_type(U256).filter(bool))
+    def test_uint256_divmod_recomposes_dividend(self, cairo_run, dividend, divisor):
+        limbs = cairo_run(
+            "test__uint256_divmod", *split_uint256s(int(dividend), int(divisor))
+        )
+        quotient = limbs[0] + (limbs[1] << 128)
+        remainder = limbs[2] + (limbs[3] << 128)
+        assert quotient * int(divisor) + remainder == int(dividend)
+        assert remainder < int(divisor)
+
+    @given(dividend=st.from_type(U256))
+    def test_uint256_divmod_by_one(self, cairo_run, dividend):
+        assert cairo_run(
+            "test__uint256_divmod", *split_uint256s(int(dividend), 1)
+        ) == tuple(split_uint256s(int(dividend), 0))
+
+    @given(divisor=st.from_type(U256).filter(bool))
+    def test_uint256_divmod_by_itself(self, cairo_run, divisor):
+        assert cairo_run(
+            "test__uint256_divmod", *split_uint256s(int(divisor), int(divisor))
+        ) == (1, 0, 0, 0)
+
+    @given(
+        values=st.lists(
+            st.from_type(U256).filter(bool), min_size=2, max_size=2, unique=True
+        )
+    )
+    def test_uint256_divmod_smaller_dividend(self, cairo_run, values):
+        dividend, divisor = sorted(int(value) for value in values)
+        assert cairo_run(
+            "test__uint256_divmod", *split_uint256s(dividend, divisor)
+        ) == tuple(split_uint256s(0, dividend))
+
+    @pytest.mark.parametrize("dividend", [0, 1, 2**256 - 1])
+    def test_uint256_divmod_should_raise_on_division_by_zero(
+        self, cairo_run, cairo_run_py, dividend
+    ):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="Division by zero"):
+                run("test__uint256_divmod", *split_uint256s(dividend, 0))
+
+
+HALF_PRIME = DEFAULT_PRIME // 2
+
+
+class TestFeltComparison:
+    @pytest.mark.parametrize(
+        "value, is_neg",
+        [(0, 0), (1, 0), (HALF_PRIME, 0), (HALF_PRIME + 1, 1), (DEFAULT_PRIME - 1, 1)],
+    )
+    def test_is_felt_negative(self, cairo_run, cairo_run_py, value, is_neg):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__is_felt_negative", value) == is_neg
+
+    @pytest.mark.parametrize(
+        "value, expected",
+        [
+            (5, (5, 0)),
+            (HALF_PRIME, (HALF_PRIME, 0)),
+            (HALF_PRIME + 1, (HALF_PRIME, 1)),
+            (DEFAULT_PRIME - 5, (5, 1)),
+        ],
+    )
+    def test_felt_abs(self, cairo_run, cairo_run_py, value, expected):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__felt_abs", value) == expected
+
+    @pytest.mark.parametrize(
+        "a, b, expected_min",
+        [
+            (1, 2, 1),
+            (DEFAULT_PRIME - 1, 1, DEFAULT_PRIME - 1),
+            (HALF_PRIME, HALF_PRIME + 1, HALF_PRIME + 1),
+            (HALF_PRIME + 1, HALF_PRIME + 2, HALF_PRIME + 1),
+        ],
+    )
+    def test_felt_min_and_comparisons(
+        self, cairo_run, cairo_run_py, a, b, expected_min
+    ):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__felt_min", a, b) == expected_min
+            assert run("test__felt_min", b, a) == expected_min
+            assert run("test__is_felt_le", a, b) == int(expected_min == a)
+            assert run("test__is_felt_ge", a, b) == int(expected_min == b)
+            assert run("test__is_felt_le", a, a) == 1
+            assert run("test__is_felt_ge", a, a) == 1
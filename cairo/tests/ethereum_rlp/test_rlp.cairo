@@ -0,0 +1,24 @@
+from ethereum_types.bytes import Bytes, BytesStruct
+
+func test__rlp_decode_item(data: Bytes) -> Bytes {
+    alloc_locals;
+    local rlp_ptr: felt* = data.value.data;
+    local rlp_len = data.value.len;
+    local output_ptr: felt*;
+    local output_len: felt;
+    local is_list: felt;
+    %{ rlp_decode_item %}
+    tempvar res = Bytes(new BytesStruct(output_ptr, output_len));
+    return res;
+}
+
+func test__rlp_decode_item_is_list(data: Bytes) -> felt {
+    alloc_locals;
+    local rlp_ptr: felt* = data.value.data;
+    local rlp_len = data.value.len;
+    local output_ptr: felt*;
+    local output_len: felt;
+    local is_list: felt;
+    %{ rlp_decode_item %}
+    return is_list;
+}
@@ -370,3 +370,46 @@ class TestRlp:
             )
 
             assert decoded_tx == tx
+
+        @pytest.mark.parametrize(
+            "item",
+            [
+                b"",
+                b"\x7f",
+                b"\x80",
+                b"dog",
+                b"\xaa" * 56,
+                [],
+                [b"cat", b"dog"],
+                [b"\xaa" * 32, [b"", [b"\x01"]], Uint(1024)],
+            ],
+        )
+        def test_rlp_decode_item(self, cairo_run, cairo_run_py, item):
+            encoded = encode(item)
+            is_list = isinstance(item, list)
+            expected = b"".join(encode(x) for x in item) if is_list else bytes(item)
+            # Bytes following the first item are ignored.
+            data = Bytes(encoded + encode(b"trailing"))
+            for run in (cairo_run, cairo_run_py):
+                assert run("test__rlp_decode_item", data=data) == expected
+                assert run("test__rlp_decode_item_is_list", data=data) == int(is_list)
+
+        @pytest.mark.parametrize(
+            "data",
+            [
+                b"",
+                # Non-canonical encoding of a single byte.
+                b"\x81\x01",
+                # Payload longer than the input.
+                b"\x83do",
+                b"\xc2\x01",
+                # Long form for a short payload.
+                b"\xb8\x01\x80",
+            ],
+        )
+        def test_rlp_decode_item_should_raise_on_invalid_rlp(
+            self, cairo_run, cairo_run_py, data
+        ):
+            for run in (cairo_run, cairo_run_py):
+                with pytest.raises(Exception, match="Invalid RLP"):
+                    run("test__rlp_decode_item", data=Bytes(data))
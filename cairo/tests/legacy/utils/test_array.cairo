@@ -18,3 +18,82 @@ func test__count_not_zero(data: Bytes) -> felt {
     let count = count_not_zero(data.value.len, data.value.data);
     return count;
 }
+
+func _felt_arrays_with_prime_minus_one() -> (felt*, felt*) {
+    let (a: felt*) = alloc();
+    let (b: felt*) = alloc();
+    assert a[0] = -1;
+    assert a[1] = 2;
+    assert a[2] = -1;
+    assert b[0] = -1;
+    assert b[1] = 3;
+    assert b[2] = 1;
+    return (a, b);
+}
+
+func test__felt_array_add() {
+    alloc_locals;
+    let (local a_ptr: felt*, local b_ptr: felt*) = _felt_arrays_with_prime_minus_one();
+    local len = 3;
+    local result_ptr: felt*;
+    %{ felt_array_add %}
+    assert result_ptr[0] = -2;
+    assert result_ptr[1] = 5;
+    assert result_ptr[2] = 0;
+    return ();
+}
+
+func test__felt_array_mul() {
+    alloc_locals;
+    let (local a_ptr: felt*, local b_ptr: felt*) = _felt_arrays_with_prime_minus_one();
+    local len = 3;
+    local result_ptr: felt*;
+    %{ felt_array_mul %}
+    assert result_ptr[0] = 1;
+    assert result_ptr[1] = 6;
+    assert result_ptr[2] = -1;
+    return ();
+}
+
+func test__felt_array_dot() {
+    alloc_locals;
+    let (local a_ptr: felt*, local b_ptr: felt*) = _felt_arrays_with_prime_minus_one();
+    local len = 3;
+    local result: felt;
+    %{ felt_array_dot %}
+    assert result = 6;
+    return ();
+}
+
+func test__compare_segments() {
+    alloc_locals;
+    let (local seg_a_base: felt*) = alloc();
+    let (local seg_b_base: felt*) = alloc();
+    // Equal values
+    assert seg_a_base[0] = 1;
+    assert seg_b_base[0] = 1;
+    // Hole in a only
+    assert seg_b_base[1] = 5;
+    // Different values
+    assert seg_a_base[2] = 3;
+    assert seg_b_base[2] = 4;
+    // seg_a_base[3] and seg_b_base[3] are holes in both segments
+    // Hole in b only
+    assert seg_a_base[4] = 7;
+    local seg_size = 5;
+
+    local diff_ptr: felt*;
+    local diff_len: felt;
+    %{ compare_segments %}
+    assert diff_len = 3;
+    assert diff_ptr[0] = 1;
+    assert diff_ptr[1] = 0;
+    assert diff_ptr[2] = 5;
+    assert diff_ptr[3] = 2;
+    assert diff_ptr[4] = 3;
+    assert diff_ptr[5] = 4;
+    assert diff_ptr[6] = 4;
+    assert diff_ptr[7] = 7;
+    assert diff_ptr[8] = 0;
+    return ();
+}
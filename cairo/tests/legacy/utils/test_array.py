@@ -33,3 +33,20 @@ class TestArray:
         def test_should_return_count_of_non_zero_elements(self, cairo_run, arr):
             output = cairo_run("test__count_not_zero", data=bytes(arr))
             assert len(arr) - arr.count(0) == output
+
+
+class TestFeltArrayOps:
+    @pytest.mark.parametrize(
+        "function", ["felt_array_add", "felt_array_mul", "felt_array_dot"]
+    )
+    def test_felt_array_ops_with_prime_minus_one(
+        self, cairo_run, cairo_run_py, function
+    ):
+        cairo_run(f"test__{function}")
+        cairo_run_py(f"test__{function}")
+
+
+class TestMemoryDiff:
+    def test_compare_segments(self, cairo_run, cairo_run_py):
+        cairo_run("test__compare_segments")
+        cairo_run_py("test__compare_segments")
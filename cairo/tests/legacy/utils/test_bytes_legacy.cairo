@@ -4,7 +4,7 @@ from starkware.cairo.common.alloc import alloc
 from starkware.cairo.common.uint256 import Uint256
 
 from cairo_core.numeric import U256
-from cairo_core.bytes import Bytes
+from cairo_core.bytes import Bytes, BytesStruct
 
 from legacy.utils.bytes import (
     felt_to_bytes_little,
@@ -17,6 +17,8 @@ from legacy.utils.bytes import (
     bytes_to_felt_le,
 )
 
+from tests.utils.array import fill_range
+
 func test__felt_to_bytes_little{range_check_ptr}(n: felt) -> felt* {
     alloc_locals;
 
@@ -72,3 +74,113 @@ func test__bytes_to_felt_le(bytes: Bytes) -> felt {
     let res = bytes_to_felt_le(bytes.value.len, bytes.value.data);
     return res;
 }
+
+func test__felt_to_bits_le(value: felt, n_bits: felt) -> Bytes {
+    alloc_locals;
+    local bits_ptr: felt*;
+    %{ felt_to_bits_le %}
+    tempvar res = Bytes(new BytesStruct(bits_ptr, n_bits));
+    return res;
+}
+
+func test__felt_to_bits_be(value: felt, n_bits: felt) -> Bytes {
+    alloc_locals;
+    local bits_ptr: felt*;
+    %{ felt_to_bits_be %}
+    tempvar res = Bytes(new BytesStruct(bits_ptr, n_bits));
+    return res;
+}
+
+func test__reverse_bytes32(input_lo: felt, input_hi: felt) -> (felt, felt) {
+    tempvar output_lo;
+    tempvar output_hi;
+    %{ reverse_bytes32 %}
+    return (output_lo, output_hi);
+}
+
+func test__reverse_bytes16(input: felt) -> felt {
+    tempvar output;
+    %{ reverse_bytes16 %}
+    return output;
+}
+
+func test__reverse_bytes8(input: felt) -> felt {
+    tempvar output;
+    %{ reverse_bytes8 %}
+    return output;
+}
+
+func test__swap_bytes32_ptr(data: Bytes) -> Bytes {
+    alloc_locals;
+    assert data.value.len = 32;
+    local input_ptr: felt* = data.value.data;
+    local output_ptr: felt*;
+    %{ swap_bytes32_ptr %}
+    tempvar res = Bytes(new BytesStruct(output_ptr, 32));
+    return res;
+}
+
+func _bytes31_from_byte_array(bytes: felt*) -> felt {
+    tempvar value;
+    %{ bytes31_from_byte_array %}
+    return value;
+}
+
+func test__bytes31_from_byte_array(first_byte: felt) -> felt {
+    alloc_locals;
+    let (local bytes: felt*) = alloc();
+    assert bytes[0] = first_byte;
+    fill_range(bytes + 1, 0, 30);
+    return _bytes31_from_byte_array(bytes);
+}
+
+func test__bytes31_to_byte_array(value: felt) -> (felt, felt) {
+    alloc_locals;
+    let (local bytes: felt*) = alloc();
+    %{ bytes31_to_byte_array %}
+    return (bytes[0], bytes[30]);
+}
+
+func test__bytes31_round_trip(value: felt) -> felt {
+    alloc_locals;
+    let (local bytes: felt*) = alloc();
+    %{ bytes31_to_byte_array %}
+    return _bytes31_from_byte_array(bytes);
+}
+
+func test__bytes31_at_index(value: felt, index: felt) -> felt {
+    tempvar byte;
+    %{ bytes31_at_index %}
+    return byte;
+}
+
+func test__u8_add_with_carry(a: felt, b: felt) -> (felt, felt) {
+    tempvar result;
+    tempvar carry;
+    %{ u8_add_with_carry %}
+    return (result, carry);
+}
+
+func test__u8_xor(a: felt, b: felt) -> felt {
+    tempvar result;
+    %{ u8_xor %}
+    return result;
+}
+
+func test__u8_and(a: felt, b: felt) -> felt {
+    tempvar result;
+    %{ u8_and %}
+    return result;
+}
+
+func test__u8_or(a: felt, b: felt) -> felt {
+    tempvar result;
+    %{ u8_or %}
+    return result;
+}
+
+func test__u8_rotate_right(a: felt, b: felt) -> felt {
+    tempvar result;
+    %{ u8_rotate_right %}
+    return result;
+}
@@ -1,6 +1,8 @@
+import pytest
 from ethereum_types.bytes import Bytes
 from ethereum_types.numeric import U256
 from hypothesis import Verbosity, given, settings
+from hypothesis import strategies as st
 from hypothesis.strategies import binary, integers
 from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
 
@@ -135,3 +137,179 @@ class TestBytes:
         def test_should_convert_bytes_to_felt_le(self, cairo_run, data: Bytes):
             output = cairo_run("test__bytes_to_felt_le", bytes=data)
             assert output == int.from_bytes(data, byteorder="little") % DEFAULT_PRIME
+
+
+def bits_le(value: int, n_bits: int) -> Bytes:
+    return Bytes(bytes((value >> i) & 1 for i in range(n_bits)))
+
+
+BITS_CORNER_CASES = [
+    (value, n_bits)
+    for n_bits in (128, 64, 8)
+    for value in (0, 1, 2 ** (n_bits - 1), 2**n_bits - 1)
+]
+
+
+class TestBitwiseDecompose:
+    @pytest.mark.parametrize("value, n_bits", BITS_CORNER_CASES)
+    def test_felt_to_bits(self, cairo_run, cairo_run_py, value, n_bits):
+        expected = bits_le(value, n_bits)
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__felt_to_bits_le", value=value, n_bits=n_bits) == expected
+            assert run("test__felt_to_bits_be", value=value, n_bits=n_bits) == Bytes(
+                expected[::-1]
+            )
+
+    @pytest.mark.parametrize("n_bits", [128, 64, 8])
+    def test_felt_to_bits_should_fail_on_overflow(
+        self, cairo_run, cairo_run_py, n_bits
+    ):
+        for run in (cairo_run, cairo_run_py):
+            for function in ("test__felt_to_bits_le", "test__felt_to_bits_be"):
+                with pytest.raises(Exception, match="Value exceeds n_bits"):
+                    run(function, value=2**n_bits, n_bits=n_bits)
+
+    @given(value=st.integers(min_value=0, max_value=2**128 - 1))
+    def test_felt_to_bits_le(self, cairo_run, value):
+        assert cairo_run("test__felt_to_bits_le", value=value, n_bits=128) == bits_le(
+            value, 128
+        )
+
+
+DEADBEEF_WORD = (0xDEADBEEF).to_bytes(32, "big")
+
+
+class TestWordReverse:
+    @pytest.mark.parametrize(
+        "word", [DEADBEEF_WORD, bytes(range(32)), b"\xff" * 32, b"\x00" * 32]
+    )
+    def test_reverse_bytes32(self, cairo_run, cairo_run_py, word):
+        value = int.from_bytes(word, "big")
+        expected = int.from_bytes(word, "little")
+        for run in (cairo_run, cairo_run_py):
+            assert run(
+                "test__reverse_bytes32", input_lo=value % 2**128, input_hi=value >> 128
+            ) == (expected % 2**128, expected >> 128)
+
+    def test_reverse_bytes32_deadbeef(self, cairo_run):
+        output_lo, output_hi = cairo_run(
+            "test__reverse_bytes32", input_lo=0xDEADBEEF, input_hi=0
+        )
+        assert output_lo == 0
+        assert output_hi == 0xEFBEADDE << 96
+
+    @given(value=st.integers(min_value=0, max_value=2**128 - 1))
+    def test_reverse_bytes16(self, cairo_run, value):
+        assert cairo_run("test__reverse_bytes16", input=value) == int.from_bytes(
+            value.to_bytes(16, "big"), "little"
+        )
+
+    @given(value=st.integers(min_value=0, max_value=2**64 - 1))
+    def test_reverse_bytes8(self, cairo_run, value):
+        assert cairo_run("test__reverse_bytes8", input=value) == int.from_bytes(
+            value.to_bytes(8, "big"), "little"
+        )
+
+    def test_reverse_bytes8_overflow(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="exceeds 64 bits"):
+                run("test__reverse_bytes8", input=2**64)
+
+    def test_swap_bytes32_ptr(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__swap_bytes32_ptr", data=Bytes(DEADBEEF_WORD)) == (
+                DEADBEEF_WORD[::-1]
+            )
+
+
+class TestBytes31:
+    def test_bytes31_from_byte_array(self, cairo_run, cairo_run_py):
+        expected = int.from_bytes(bytes([0xAB, *range(30)]), "big")
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__bytes31_from_byte_array", 0xAB) == expected
+
+    def test_bytes31_from_byte_array_invalid_byte(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="Byte at index 0 out of range: 256"):
+                run("test__bytes31_from_byte_array", 256)
+
+    @given(value=st.integers(min_value=0, max_value=2**248 - 1))
+    def test_bytes31_to_byte_array(self, cairo_run, value):
+        array = value.to_bytes(31, "big")
+        assert cairo_run("test__bytes31_to_byte_array", value) == (array[0], array[30])
+
+    @given(value=st.integers(min_value=0, max_value=2**248 - 1))
+    def test_bytes31_round_trip(self, cairo_run, value):
+        assert cairo_run("test__bytes31_round_trip", value) == value
+
+    def test_bytes31_round_trip_py(self, cairo_run_py):
+        value = int.from_bytes(bytes(range(1, 32)), "big")
+        assert cairo_run_py("test__bytes31_round_trip", value) == value
+
+    def test_bytes31_to_byte_array_value_too_large(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="Value does not fit in bytes31"):
+                run("test__bytes31_to_byte_array", 2**248)
+
+    @given(
+        value=st.integers(min_value=0, max_value=2**248 - 1),
+        index=st.integers(min_value=0, max_value=30),
+    )
+    def test_bytes31_at_index(self, cairo_run, value, index):
+        expected = value.to_bytes(31, "big")[index]
+        assert cairo_run("test__bytes31_at_index", value, index) == expected
+
+    def test_bytes31_at_index_out_of_range(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="Index out of range: 31"):
+                run("test__bytes31_at_index", 1, 31)
+
+
+def rotate_right_u8(a, b):
+    b %= 8
+    return ((a >> b) | (a << (8 - b))) & 0xFF
+
+
+class TestU8Arithmetic:
+    @given(a=st.integers(0, 255), b=st.integers(0, 255))
+    def test_u8_add_with_carry(self, cairo_run, a, b):
+        assert cairo_run("test__u8_add_with_carry", a, b) == (
+            (a + b) % 256,
+            (a + b) // 256,
+        )
+
+    @pytest.mark.parametrize(
+        "func, op",
+        [
+            ("test__u8_xor", lambda a, b: a ^ b),
+            ("test__u8_and", lambda a, b: a & b),
+            ("test__u8_or", lambda a, b: a | b),
+            ("test__u8_rotate_right", rotate_right_u8),
+        ],
+    )
+    @given(a=st.integers(0, 255), b=st.integers(0, 255))
+    def test_u8_bitwise(self, cairo_run, func, op, a, b):
+        assert cairo_run(func, a, b) == op(a, b)
+
+    @pytest.mark.parametrize(
+        "func, expected",
+        [
+            ("test__u8_xor", 0x0B),
+            ("test__u8_and", 0xA4),
+            ("test__u8_or", 0xAF),
+            ("test__u8_rotate_right", 0xFA),
+        ],
+    )
+    def test_u8_bitwise_py(self, cairo_run, cairo_run_py, func, expected):
+        for run in (cairo_run, cairo_run_py):
+            assert run(func, 0xAF, 0xA4) == expected
+
+    def test_u8_add_with_carry_py(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__u8_add_with_carry", 0xFF, 0x02) == (1, 1)
+
+    @pytest.mark.parametrize("a, b, name", [(256, 0, "a"), (0, 256, "b")])
+    def test_u8_operand_out_of_range(self, cairo_run, cairo_run_py, a, b, name):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match=f"{name} out of range: 256"):
+                run("test__u8_xor", a, b)
@@ -91,6 +91,22 @@ func test_get_keys_for_address_prefix{range_check_ptr}(
     return res;
 }
 
+func test_get_sorted_keys_for_address_prefix{range_check_ptr}(
+    prefix_: Address, dict_entries: MappingTupleAddressBytes32U256
+) -> ListTupleAddressBytes32 {
+    alloc_locals;
+    let prefix_len = 1;
+    let (prefix: felt*) = alloc();
+    assert [prefix] = prefix_.value;
+    let dict_ptr = cast(dict_entries.value.dict_ptr, DictAccess*);
+    local keys_len: felt;
+    local keys: TupleAddressBytes32*;
+    local sorted = 1;
+    %{ get_keys_for_address_prefix %}
+    tempvar res = ListTupleAddressBytes32(new ListTupleAddressBytes32Struct(keys, keys_len));
+    return res;
+}
+
 func test_squash_and_update{range_check_ptr}(
     src_dict: MappingTupleAddressBytes32U256, dst_dict: MappingTupleAddressBytes32U256
 ) -> MappingTupleAddressBytes32U256 {
@@ -117,3 +133,25 @@ func test_squash_and_update{range_check_ptr}(
     );
     return new_dst_dict;
 }
+
+func test__felt252_dict_entry(key: felt, new_value: felt) -> (felt, felt) {
+    alloc_locals;
+    let (local dict_ptr: DictAccess*) = default_dict_new(default_value=7);
+    local current_value: felt;
+    %{ felt252_dict_entry_init %}
+    assert dict_ptr.key = key;
+    assert dict_ptr.prev_value = current_value;
+    assert dict_ptr.new_value = new_value;
+    %{ felt252_dict_entry_finalize %}
+
+    let dict_ptr_end = dict_ptr + DictAccess.SIZE;
+    let (value) = dict_read{dict_ptr=dict_ptr_end}(key);
+    return (current_value, value);
+}
+
+func test__felt252_dict_entry_finalize_without_init(key: felt, new_value: felt) {
+    alloc_locals;
+    let (local dict_ptr: DictAccess*) = default_dict_new(default_value=0);
+    %{ felt252_dict_entry_finalize %}
+    return ();
+}
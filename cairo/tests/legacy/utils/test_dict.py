@@ -1,12 +1,14 @@
 from collections import defaultdict
 from typing import List, Mapping, Tuple
 
+import pytest
 from ethereum.cancun.fork_types import Address
 from ethereum_types.bytes import Bytes32
 from ethereum_types.numeric import U256, Uint
 from hypothesis import given
 from hypothesis import strategies as st
 from starkware.cairo.common.dict import DictManager
+from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
 from starkware.cairo.lang.vm.memory_dict import MemoryDict
 from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
 from starkware.cairo.lang.vm.relocatable import RelocatableValue
@@ -116,6 +118,23 @@ def test_get_keys_for_address_prefix(cairo_run, dict_with_prefix):
     }
 
 
+@given(dict_with_prefix=dict_with_prefix())
+def test_get_sorted_keys_for_address_prefix(cairo_run, dict_with_prefix):
+    dict_entries: Mapping[Tuple[Address, Bytes32], U256] = dict_with_prefix[0]
+    prefix: Address = dict_with_prefix[1]
+    keys = cairo_run("test_get_sorted_keys_for_address_prefix", prefix, dict_entries)
+    keys = [keys] if not isinstance(keys, list) else keys
+    expected = sorted(
+        (key for key in dict_entries.keys() if key[0] == prefix),
+        key=lambda key: (
+            int.from_bytes(key[0], "little"),
+            int.from_bytes(key[1][:16], "little"),
+            int.from_bytes(key[1][16:], "little"),
+        ),
+    )
+    assert [tuple(k) for k in keys] == expected
+
+
 @given(src_dict=..., dst_dict=...)
 def test_squash_and_update(
     cairo_run,
@@ -129,3 +148,25 @@ def test_squash_and_update(
     )
     dst_dict.update(src_dict)
     assert new_dst_dict == dst_dict
+
+
+class TestFelt252DictEntry:
+    @given(key=st.integers(min_value=0, max_value=2**128), new_value=st.integers())
+    def test_felt252_dict_entry(self, cairo_run, key, new_value):
+        new_value = new_value % DEFAULT_PRIME
+        assert cairo_run("test__felt252_dict_entry", key=key, new_value=new_value) == (
+            7,
+            new_value,
+        )
+
+    def test_felt252_dict_entry_py(self, cairo_run_py):
+        assert cairo_run_py("test__felt252_dict_entry", key=1, new_value=2) == (7, 2)
+
+    def test_felt252_dict_entry_finalize_without_init(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="finalized without being initialized"):
+                run(
+                    "test__felt252_dict_entry_finalize_without_init",
+                    key=1,
+                    new_value=2,
+                )
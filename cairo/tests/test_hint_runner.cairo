@@ -130,3 +130,30 @@ func test__access_let_relocatable() {
     %}
     ret;
 }
+
+func test__iterate_ids() {
+    tempvar x = 3;
+    tempvar y = 4;
+    %{
+        assert len(ids) == 2
+        assert "x" in ids and "z" not in ids
+        assert sorted(ids) == ["x", "y"]
+        assert sorted(ids.items()) == [("x", 3), ("y", 4)]
+    %}
+    ret;
+}
+
+func test__vm_const_arithmetic() {
+    alloc_locals;
+    let (local x: felt*) = alloc();
+    assert x[0] = 5;
+    %{
+        value = ids.x.deref()
+        assert int(value) == 5
+        assert value * 3 + 1 == 16
+        assert 2 * value == 10
+        assert 2 + value == 7
+        assert value - 7 == -2
+    %}
+    ret;
+}
@@ -70,3 +70,11 @@ class TestRunner:
     def test__access_let_relocatable(self, cairo_run, cairo_run_py):
         cairo_run("test__access_let_relocatable")
         cairo_run_py("test__access_let_relocatable")
+
+    def test__iterate_ids(self, cairo_run):
+        cairo_run("test__iterate_ids")
+        # The ids of the Python runner can't be enumerated.
+
+    def test__vm_const_arithmetic(self, cairo_run):
+        cairo_run("test__vm_const_arithmetic")
+        # The ids of the Python runner have no `deref`.
@@ -0,0 +1,82 @@
+from starkware.cairo.common.alloc import alloc
+from tests.utils.array import fill_range
+
+func test__pedersen_hash_pair(x: felt, y: felt) -> felt {
+    tempvar hash;
+    %{ pedersen_hash_pair %}
+    return hash;
+}
+
+func test__poseidon_hash_range(data_len: felt) -> felt {
+    alloc_locals;
+    let (local data_ptr: felt*) = alloc();
+    fill_range(data_ptr, 0, data_len);
+    tempvar result;
+    %{ poseidon_hash_range %}
+    return result;
+}
+
+func _poseidon_hasher_new() -> felt {
+    tempvar handle;
+    %{ poseidon_hasher_new %}
+    return handle;
+}
+
+func _poseidon_hasher_update(handle: felt, element: felt) {
+    %{ poseidon_hasher_update %}
+    return ();
+}
+
+func _poseidon_hasher_finalize(handle: felt) -> felt {
+    tempvar hash;
+    %{ poseidon_hasher_finalize %}
+    return hash;
+}
+
+func test__poseidon_hasher(a: felt, b: felt, c: felt) -> felt {
+    alloc_locals;
+    let new_handle = _poseidon_hasher_new();
+    local handle = new_handle;
+    // Interleave with another hasher to check the buffers are independent
+    let new_handle = _poseidon_hasher_new();
+    local other_handle = new_handle;
+    _poseidon_hasher_update(handle, a);
+    _poseidon_hasher_update(other_handle, c);
+    _poseidon_hasher_update(handle, b);
+    _poseidon_hasher_update(handle, c);
+    let hash = _poseidon_hasher_finalize(handle);
+    local result = hash;
+    _poseidon_hasher_finalize(other_handle);
+    return result;
+}
+
+func test__poseidon_hasher_empty() -> felt {
+    let handle = _poseidon_hasher_new();
+    let hash = _poseidon_hasher_finalize(handle);
+    return hash;
+}
+
+func test__poseidon_chunk_hash(n: felt) -> felt {
+    alloc_locals;
+    let (local data_ptr: felt*) = alloc();
+    fill_range(data_ptr, 0, n);
+    local data_len = n;
+    tempvar root_hash;
+    %{ poseidon_chunk_hash %}
+    return root_hash;
+}
+
+func test__merkle_tree(leaf_index: felt) -> (felt, felt, felt, felt) {
+    alloc_locals;
+    let (local leaves: felt*) = alloc();
+    fill_range(leaves, 0, 4);
+    tempvar leaves_ptr = leaves;
+    tempvar n_leaves = 4;
+    tempvar tree_ptr: felt*;
+    tempvar tree_len;
+    tempvar root;
+    %{ merkle_tree_build %}
+    tempvar proof: felt*;
+    %{ merkle_tree_get_proof %}
+    return (tree_len, root, proof[0], proof[1]);
+}
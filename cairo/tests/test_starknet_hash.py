@@ -0,0 +1,115 @@
+import pytest
+from hypothesis import given
+from hypothesis import strategies as st
+from starkware.cairo.common.poseidon_hash import poseidon_hash, poseidon_hash_many
+from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+from starkware.crypto.signature.fast_pedersen_hash import pedersen_hash
+
+
+class TestPedersen:
+    @pytest.mark.parametrize(
+        "x, y, expected",
+        [
+            (
+                0x3D937C035C878245CAF64531A5756109C53068DA139362728FEB561405371CB,
+                0x208A0A10250E382E1E4BBE2880906C2791BF6275695E02FBBC6AEFF9CD8B31A,
+                0x30E480BED5FE53FA909CC0F8C4D99B8F9F2C016BE4C41E13A4848797979C662,
+            ),
+            (
+                0x58F580910A6CA59B28927C08FE6C43E2E303CA384BADC365795FC645D479D45,
+                0x78734F65A067BE9BDB39DE18434D71E79F7B6466A4B66BBD979AB9E7515FE0B,
+                0x68CC0B76CDDD1DD4ED2301ADA9B7C872B23875D5FF837B3A87993E0D9996B87,
+            ),
+        ],
+    )
+    def test_pedersen_hash_pair_known_vectors(
+        self, cairo_run, cairo_run_py, x, y, expected
+    ):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__pedersen_hash_pair", x=x, y=y) == expected
+
+    @given(
+        x=st.integers(min_value=0, max_value=DEFAULT_PRIME - 1),
+        y=st.integers(min_value=0, max_value=DEFAULT_PRIME - 1),
+    )
+    def test_pedersen_hash_pair(self, cairo_run, x, y):
+        assert cairo_run("test__pedersen_hash_pair", x=x, y=y) == pedersen_hash(x, y)
+
+
+class TestPoseidon:
+    @pytest.mark.parametrize("data_len", [0, 1, 2, 3])
+    def test_poseidon_hash_range_short_inputs(self, cairo_run, cairo_run_py, data_len):
+        expected = poseidon_hash_many(list(range(data_len)))
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__poseidon_hash_range", data_len) == expected
+
+    @given(data_len=st.integers(min_value=0, max_value=100))
+    def test_poseidon_hash_range(self, cairo_run, data_len):
+        expected = poseidon_hash_many(list(range(data_len)))
+        assert cairo_run("test__poseidon_hash_range", data_len) == expected
+
+
+class TestPoseidonHasher:
+    @given(
+        a=st.integers(min_value=0, max_value=DEFAULT_PRIME - 1),
+        b=st.integers(min_value=0, max_value=DEFAULT_PRIME - 1),
+        c=st.integers(min_value=0, max_value=DEFAULT_PRIME - 1),
+    )
+    def test_poseidon_hasher(self, cairo_run, a, b, c):
+        assert cairo_run("test__poseidon_hasher", a=a, b=b, c=c) == poseidon_hash_many(
+            [a, b, c]
+        )
+
+    def test_poseidon_hasher_py(self, cairo_run_py):
+        assert cairo_run_py("test__poseidon_hasher", a=1, b=2, c=3) == (
+            poseidon_hash_many([1, 2, 3])
+        )
+
+    def test_poseidon_hasher_empty(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__poseidon_hasher_empty") == poseidon_hash_many([])
+
+
+def chunk_hash(data):
+    while len(data) > 1024:
+        data = [
+            poseidon_hash_many(data[i : i + 1024]) for i in range(0, len(data), 1024)
+        ]
+    return poseidon_hash_many(data)
+
+
+class TestPoseidonChunkHash:
+    @pytest.mark.parametrize("n", [0, 1, 1024, 1025, 3000])
+    def test_poseidon_chunk_hash(self, cairo_run, cairo_run_py, n):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__poseidon_chunk_hash", n=n) == chunk_hash(list(range(n)))
+
+    def test_poseidon_chunk_hash_single_chunk(self, cairo_run):
+        assert cairo_run("test__poseidon_chunk_hash", n=10) == poseidon_hash_many(
+            list(range(10))
+        )
+
+    def test_poseidon_chunk_hash_two_chunks(self, cairo_run):
+        data = list(range(1025))
+        assert cairo_run("test__poseidon_chunk_hash", n=1025) == poseidon_hash_many(
+            [poseidon_hash_many(data[:1024]), poseidon_hash_many(data[1024:])]
+        )
+
+
+class TestCommitmentTree:
+    @pytest.mark.parametrize("leaf_index", [0, 1, 2, 3])
+    def test_merkle_tree(self, cairo_run, cairo_run_py, leaf_index):
+        leaves = [0, 1, 2, 3]
+        nodes = [poseidon_hash(0, 1), poseidon_hash(2, 3)]
+        root = poseidon_hash(*nodes)
+        proof = [leaves[leaf_index ^ 1], nodes[(leaf_index // 2) ^ 1]]
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__merkle_tree", leaf_index) == (7, root, *proof)
+
+        node = leaves[leaf_index]
+        for level, sibling in enumerate(proof):
+            if (leaf_index >> level) & 1:
+                node = poseidon_hash(sibling, node)
+            else:
+                node = poseidon_hash(node, sibling)
+        assert node == root
@@ -0,0 +1,190 @@
+from starkware.cairo.common.alloc import alloc
+from tests.utils.array import fill_range
+
+func _log_storage_write(address: felt, key: felt, value: felt) {
+    %{ log_storage_write %}
+    return ();
+}
+
+func _get_storage_write_log_len() -> felt {
+    tempvar len;
+    %{ get_storage_write_log_len %}
+    return len;
+}
+
+func test__storage_write_log_round_trip() {
+    alloc_locals;
+    _log_storage_write(1, 2, 3);
+    _log_storage_write(1, 4, -1);
+    _log_storage_write(5, 2, 0);
+    let len = _get_storage_write_log_len();
+    assert len = 3;
+
+    local log_ptr: felt*;
+    local log_len: felt;
+    %{ flush_storage_write_log %}
+    assert log_len = 3;
+    assert log_ptr[0] = 1;
+    assert log_ptr[1] = 2;
+    assert log_ptr[2] = 3;
+    assert log_ptr[3] = 1;
+    assert log_ptr[4] = 4;
+    assert log_ptr[5] = -1;
+    assert log_ptr[6] = 5;
+    assert log_ptr[7] = 2;
+    assert log_ptr[8] = 0;
+
+    let len = _get_storage_write_log_len();
+    assert len = 0;
+    return ();
+}
+
+func _charge_syscall_gas(syscall_type: felt, topic_count: felt, n_calls: felt) -> felt {
+    alloc_locals;
+    if (n_calls == 0) {
+        return 0;
+    }
+    local gas_charged: felt;
+    %{ charge_syscall_gas %}
+    let total = _charge_syscall_gas(syscall_type, topic_count, n_calls - 1);
+    return gas_charged + total;
+}
+
+func test__charge_syscall_gas(
+    initial_gas: felt, syscall_type: felt, topic_count: felt, n_calls: felt
+) -> felt {
+    %{ init_syscall_gas %}
+    let total = _charge_syscall_gas(syscall_type, topic_count, n_calls);
+    return total;
+}
+
+func test__compute_stark_tx_hash(
+    contract_address: felt,
+    selector: felt,
+    calldata_len: felt,
+    nonce: felt,
+    max_fee: felt,
+    chain_id: felt,
+) -> felt {
+    alloc_locals;
+    let (local calldata_ptr: felt*) = alloc();
+    fill_range(calldata_ptr, 0, calldata_len);
+    tempvar tx_hash;
+    %{ compute_stark_tx_hash %}
+    return tx_hash;
+}
+
+func test__hash_event_content(keys_len: felt, data_len: felt) -> (felt, felt) {
+    alloc_locals;
+    let (local keys_ptr: felt*) = alloc();
+    let (local data_ptr: felt*) = alloc();
+    fill_range(keys_ptr, 0, keys_len);
+    fill_range(data_ptr, 0, data_len);
+    tempvar keys_hash;
+    tempvar data_hash;
+    %{ hash_event_content %}
+    return (keys_hash, data_hash);
+}
+
+func test__emit_event_and_track(keys_len: felt, data_len: felt) -> (felt, felt) {
+    alloc_locals;
+    let (local keys_ptr: felt*) = alloc();
+    let (local data_ptr: felt*) = alloc();
+    fill_range(keys_ptr, 0, keys_len);
+    fill_range(data_ptr, 0, data_len);
+    local keys_hash: felt;
+    local data_hash: felt;
+    %{ emit_event_and_track %}
+    %{ emit_event_and_track %}
+    return (keys_hash, data_hash);
+}
+
+func test__compute_l1_handler_hash(
+    from_address: felt,
+    to_address: felt,
+    selector: felt,
+    payload_len: felt,
+    nonce: felt,
+    chain_id: felt,
+) -> felt {
+    alloc_locals;
+    let (local payload_ptr: felt*) = alloc();
+    fill_range(payload_ptr, 0, payload_len);
+    tempvar message_hash;
+    %{ compute_l1_handler_hash %}
+    return message_hash;
+}
+
+func test__verify_l1_handler_nonce(nonce: felt, expected_nonce: felt) {
+    %{ verify_l1_handler_nonce %}
+    return ();
+}
+
+func test__batch_verify_stark_ecdsa(
+    public_key: felt, msg_hash: felt, sig_r: felt, sig_s: felt
+) -> felt {
+    alloc_locals;
+    // A valid signature, the same signature over another message, and the valid one again.
+    let (local signatures: felt*) = alloc();
+    assert signatures[0] = public_key;
+    assert signatures[1] = msg_hash;
+    assert signatures[2] = sig_r;
+    assert signatures[3] = sig_s;
+    assert signatures[4] = public_key;
+    assert signatures[5] = msg_hash + 1;
+    assert signatures[6] = sig_r;
+    assert signatures[7] = sig_s;
+    assert signatures[8] = public_key;
+    assert signatures[9] = msg_hash;
+    assert signatures[10] = sig_r;
+    assert signatures[11] = sig_s;
+    tempvar n_signatures = 3;
+    tempvar bitmap: felt*;
+    %{ batch_verify_stark_ecdsa %}
+    return bitmap[0];
+}
+
+func _kv_cache_put(key: felt, value_ptr: felt*, value_len: felt) {
+    %{ kv_cache_put %}
+    return ();
+}
+
+func _kv_cache_get(key: felt) -> (felt*, felt, felt) {
+    tempvar value_ptr: felt*;
+    tempvar value_len;
+    tempvar found;
+    %{ kv_cache_get %}
+    return (value_ptr, value_len, found);
+}
+
+func test__kv_cache(key: felt, value_len: felt) -> (felt, felt, felt, felt, felt, felt) {
+    alloc_locals;
+    let (local value: felt*) = alloc();
+    fill_range(value, 0, value_len);
+    _kv_cache_put(key, value, value_len);
+    let (cached_value, cached_value_len, hit) = _kv_cache_get(key);
+    let (_, _, miss) = _kv_cache_get(key + 1);
+    tempvar hits;
+    tempvar misses;
+    %{ kv_cache_stats %}
+    return (hit, cached_value_len, cached_value[value_len - 1], miss, hits, misses);
+}
+
+func _kv_cache_put_range(key: felt, n: felt, value: felt*) {
+    if (n == 0) {
+        return ();
+    }
+    _kv_cache_put(key, value, 1);
+    return _kv_cache_put_range(key + 1, n - 1, value);
+}
+
+// Caches `n_keys` values, then returns whether the first and last keys are still cached.
+func test__kv_cache_eviction(n_keys: felt) -> (felt, felt) {
+    alloc_locals;
+    let (local value: felt*) = alloc();
+    assert value[0] = 0xff;
+    _kv_cache_put_range(0, n_keys, value);
+    let (_, _, first_found) = _kv_cache_get(0);
+    let (_, _, last_found) = _kv_cache_get(n_keys - 1);
+    return (first_found, last_found);
+}
@@ -0,0 +1,245 @@
+import pytest
+from hypothesis import given
+from hypothesis import strategies as st
+from starkware.cairo.common.hash_state import compute_hash_on_elements
+from starkware.cairo.common.poseidon_hash import poseidon_hash_many
+from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+from starkware.crypto.signature.signature import private_to_stark_key, sign
+
+
+def short_string(value: str) -> int:
+    return int.from_bytes(value.encode(), "big")
+
+
+class TestStorageWriteLog:
+    def test_storage_write_log_round_trip(self, cairo_run, cairo_run_py):
+        cairo_run("test__storage_write_log_round_trip")
+        cairo_run_py("test__storage_write_log_round_trip")
+
+
+class TestSyscallGas:
+    @pytest.mark.parametrize(
+        "syscall_type, topic_count, cost",
+        [
+            ("StorageRead", 0, 50),
+            ("StorageWrite", 0, 50),
+            ("EmitEvent", 0, 0),
+            ("EmitEvent", 3, 300),
+            ("GetBlockHash", 0, 50),
+            ("SendMessageToL1", 0, 50),
+        ],
+    )
+    def test_charge_syscall_gas(
+        self, cairo_run, cairo_run_py, syscall_type, topic_count, cost
+    ):
+        for run in (cairo_run, cairo_run_py):
+            assert (
+                run(
+                    "test__charge_syscall_gas",
+                    initial_gas=1000,
+                    syscall_type=short_string(syscall_type),
+                    topic_count=topic_count,
+                    n_calls=2,
+                )
+                == 2 * cost
+            )
+
+    def test_charge_syscall_gas_out_of_gas(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(
+                Exception, match="Out of gas: required 50, available 20"
+            ):
+                run(
+                    "test__charge_syscall_gas",
+                    initial_gas=120,
+                    syscall_type=short_string("StorageRead"),
+                    topic_count=0,
+                    n_calls=3,
+                )
+
+    def test_charge_syscall_gas_unknown_syscall(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            with pytest.raises(Exception, match="Unknown syscall: Deploy"):
+                run(
+                    "test__charge_syscall_gas",
+                    initial_gas=1000,
+                    syscall_type=short_string("Deploy"),
+                    topic_count=0,
+                    n_calls=1,
+                )
+
+
+class TestStarkTxHash:
+    @given(
+        contract_address=st.integers(min_value=0, max_value=2**251 - 1),
+        selector=st.integers(min_value=0, max_value=2**251 - 1),
+        calldata_len=st.integers(min_value=0, max_value=10),
+        nonce=st.integers(min_value=0, max_value=2**64 - 1),
+        max_fee=st.integers(min_value=0, max_value=2**128 - 1),
+    )
+    def test_compute_stark_tx_hash(
+        self, cairo_run, contract_address, selector, calldata_len, nonce, max_fee
+    ):
+        chain_id = short_string("SN_MAIN")
+        expected = compute_hash_on_elements(
+            [
+                short_string("invoke"),
+                1,
+                contract_address,
+                selector,
+                compute_hash_on_elements(list(range(calldata_len))),
+                max_fee,
+                chain_id,
+                nonce,
+            ]
+        )
+        assert (
+            cairo_run(
+                "test__compute_stark_tx_hash",
+                contract_address=contract_address,
+                selector=selector,
+                calldata_len=calldata_len,
+                nonce=nonce,
+                max_fee=max_fee,
+                chain_id=chain_id,
+            )
+            == expected
+        )
+
+    def test_compute_stark_tx_hash_py(self, cairo_run, cairo_run_py):
+        kwargs = {
+            "contract_address": 0x1234,
+            "selector": 0,
+            "calldata_len": 3,
+            "nonce": 1,
+            "max_fee": 10**15,
+            "chain_id": short_string("SN_SEPOLIA"),
+        }
+        assert cairo_run_py("test__compute_stark_tx_hash", **kwargs) == cairo_run(
+            "test__compute_stark_tx_hash", **kwargs
+        )
+
+
+class TestEvent:
+    @given(
+        keys_len=st.integers(min_value=0, max_value=10),
+        data_len=st.integers(min_value=0, max_value=50),
+    )
+    def test_hash_event_content(self, cairo_run, keys_len, data_len):
+        expected = (
+            poseidon_hash_many(list(range(keys_len))),
+            poseidon_hash_many(list(range(data_len))),
+        )
+        assert cairo_run("test__hash_event_content", keys_len, data_len) == expected
+        assert cairo_run("test__emit_event_and_track", keys_len, data_len) == expected
+
+    def test_hash_event_content_py(self, cairo_run, cairo_run_py):
+        for function in ("test__hash_event_content", "test__emit_event_and_track"):
+            assert cairo_run_py(function, 3, 7) == cairo_run(function, 3, 7)
+
+
+class TestL1Handler:
+    @given(
+        from_address=st.integers(min_value=0, max_value=2**160 - 1),
+        to_address=st.integers(min_value=0, max_value=DEFAULT_PRIME - 1),
+        selector=st.integers(min_value=0, max_value=DEFAULT_PRIME - 1),
+        payload_len=st.integers(min_value=0, max_value=10),
+        nonce=st.integers(min_value=0, max_value=2**64 - 1),
+    )
+    def test_compute_l1_handler_hash(
+        self, cairo_run, from_address, to_address, selector, payload_len, nonce
+    ):
+        chain_id = short_string("SN_MAIN")
+        expected = compute_hash_on_elements(
+            [
+                short_string("l1_handler"),
+                0,
+                to_address,
+                selector,
+                compute_hash_on_elements([from_address, *range(payload_len)]),
+                0,
+                chain_id,
+                nonce,
+            ]
+        )
+        assert (
+            cairo_run(
+                "test__compute_l1_handler_hash",
+                from_address=from_address,
+                to_address=to_address,
+                selector=selector,
+                payload_len=payload_len,
+                nonce=nonce,
+                chain_id=chain_id,
+            )
+            == expected
+        )
+
+    def test_compute_l1_handler_hash_py(self, cairo_run, cairo_run_py):
+        kwargs = {
+            "from_address": 0xAE0EE0A63A2CE6BAEEFFE56E7714FB4EFE48D419,
+            "to_address": 0x1234,
+            "selector": 0x5678,
+            "payload_len": 3,
+            "nonce": 42,
+            "chain_id": short_string("SN_SEPOLIA"),
+        }
+        assert cairo_run_py("test__compute_l1_handler_hash", **kwargs) == cairo_run(
+            "test__compute_l1_handler_hash", **kwargs
+        )
+
+    def test_verify_l1_handler_nonce(self, cairo_run, cairo_run_py):
+        for run in (cairo_run, cairo_run_py):
+            run("test__verify_l1_handler_nonce", nonce=3, expected_nonce=3)
+            with pytest.raises(
+                Exception, match="Invalid L1 handler nonce: expected 4, got 3"
+            ):
+                run("test__verify_l1_handler_nonce", nonce=3, expected_nonce=4)
+
+
+class TestBatchVerify:
+    @given(
+        private_key=st.integers(min_value=1, max_value=2**200),
+        msg_hash=st.integers(min_value=0, max_value=2**250),
+    )
+    def test_batch_verify_stark_ecdsa(self, cairo_run, private_key, msg_hash):
+        public_key = private_to_stark_key(private_key)
+        r, s = sign(msg_hash, private_key)
+        assert (
+            cairo_run("test__batch_verify_stark_ecdsa", public_key, msg_hash, r, s)
+            == 0b101
+        )
+
+    def test_batch_verify_stark_ecdsa_py(self, cairo_run, cairo_run_py):
+        private_key = 0x1234
+        msg_hash = 0x5678
+        r, s = sign(msg_hash, private_key)
+        args = (private_to_stark_key(private_key), msg_hash, r, s)
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__batch_verify_stark_ecdsa", *args) == 0b101
+
+
+# Matches KV_CACHE_SIZE of the kv_cache hints.
+KV_CACHE_SIZE = 1024
+
+
+class TestKvCache:
+    @pytest.mark.parametrize("value_len", [1, 32])
+    def test_kv_cache(self, cairo_run, cairo_run_py, value_len):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__kv_cache", 0x1234, value_len) == (
+                1,
+                value_len,
+                value_len - 1,
+                0,
+                1,
+                1,
+            )
+
+    @pytest.mark.parametrize(
+        "n_keys, expected",
+        [(KV_CACHE_SIZE, (1, 1)), (KV_CACHE_SIZE + 1, (0, 1))],
+    )
+    def test_kv_cache_eviction(self, cairo_run, cairo_run_py, n_keys, expected):
+        for run in (cairo_run, cairo_run_py):
+            assert run("test__kv_cache_eviction", n_keys) == expected
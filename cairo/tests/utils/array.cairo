@@ -0,0 +1,8 @@
+// Writes `i, ..., n - 1` to `ptr[i], ..., ptr[n - 1]`.
+func fill_range(ptr: felt*, i: felt, n: felt) {
+    if (i == n) {
+        return ();
+    }
+    assert ptr[i] = i;
+    return fill_range(ptr, i + 1, n);
+}
@@ -101,3 +101,7 @@ def flatten_tx_access_list(access_list):
 def get_internal_storage_key(key: U256) -> int:
     low, high = int_to_uint256(int(key))
     return pedersen_hash(low, high)
+
+
+def split_uint256s(*values: int) -> list:
+    return [limb for value in values for limb in (value % 2**128, value >> 128)]
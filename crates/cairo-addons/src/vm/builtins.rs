@@ -4,6 +4,12 @@ use pyo3::{prelude::*, FromPyObject};
 #[derive(FromPyObject)]
 pub struct PyBuiltinList(Vec<String>);
 
+impl From<Vec<String>> for PyBuiltinList {
+    fn from(names: Vec<String>) -> Self {
+        Self(names)
+    }
+}
+
 impl PyBuiltinList {
     pub fn into_builtin_names(self) -> PyResult<Vec<BuiltinName>> {
         self.0
@@ -1,8 +1,9 @@
 use cairo_vm::{
-    hint_processor::builtin_hint_processor::dict_manager::{
-        DictKey as RustDictKey, DictManager as RustDictManager, DictTracker,
+    hint_processor::builtin_hint_processor::{
+        dict_hint_utils::DICT_ACCESS_SIZE,
+        dict_manager::{DictKey as RustDictKey, DictManager as RustDictManager, DictTracker},
     },
-    types::relocatable::MaybeRelocatable,
+    types::relocatable::{MaybeRelocatable, Relocatable},
 };
 use pyo3::{
     prelude::*,
@@ -11,7 +12,10 @@ use pyo3::{
 };
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use super::{maybe_relocatable::PyMaybeRelocatable, relocatable::PyRelocatable};
+use super::{
+    hint_definitions::compound_key_from_values, maybe_relocatable::PyMaybeRelocatable,
+    memory_segments::PyMemorySegmentManager, relocatable::PyRelocatable,
+};
 
 #[derive(FromPyObject, Eq, PartialEq, Hash, Debug)]
 pub enum PyDictKey {
@@ -194,6 +198,48 @@ impl PyDictManager {
         Ok(())
     }
 
+    /// Returns a snapshot of the tracker of the dict segment of `ptr`, see `DictTracker.snapshot`.
+    fn snapshot(&self, ptr: PyRelocatable) -> PyResult<PyDictTrackerSnapshot> {
+        Ok(self.get_tracker(ptr)?.snapshot())
+    }
+
+    /// Restores the tracker of the dict segment of `ptr` to `snapshot` in the manager, see
+    /// `DictTracker.restore`.
+    fn restore(&mut self, ptr: PyRelocatable, snapshot: &PyDictTrackerSnapshot) -> PyResult<()> {
+        let segment_index = ptr.inner.segment_index;
+        let mut tracker = self.get_tracker(ptr)?;
+        tracker.restore(snapshot)?;
+        self.inner.borrow_mut().trackers.insert(segment_index, tracker.inner);
+        Ok(())
+    }
+
+    /// Creates a dict in a new segment of `segments`, holding the entries of the Python `dict`
+    /// `initial_data`, and returns its base. Keys are ints or tuples of ints (compound keys),
+    /// values are ints or relocatables.
+    ///
+    /// Like `DictManager.new_dict` of the Python VM, this takes the segments to allocate the dict
+    /// segment in, as the manager is not bound to a VM.
+    fn new_dict_from_python(
+        &mut self,
+        segments: &PyMemorySegmentManager,
+        initial_data: Bound<'_, PyDict>,
+    ) -> PyResult<PyRelocatable> {
+        let initial_data = initial_data
+            .extract::<HashMap<PyDictKey, PyMaybeRelocatable>>()?
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+        let vm = unsafe { &mut *segments.vm };
+        let base = self
+            .inner
+            .borrow_mut()
+            .new_dict(vm, initial_data)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+        base.get_relocatable()
+            .map(|inner| PyRelocatable { inner })
+            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Invalid dict base"))
+    }
+
     fn get_value(&self, segment_index: isize, key: PyDictKey) -> PyResult<PyMaybeRelocatable> {
         let value = self
             .inner
@@ -209,6 +255,82 @@ impl PyDictManager {
         Ok(value.into())
     }
 
+    /// Builds the compound key made of the ints `values`, as the hashdict hints do, and returns it
+    /// as a tuple.
+    ///
+    /// This mutates the manager: the key is registered in `preimages` as the preimage of its hash,
+    /// so that keys built from Python can be read back from their hash like the keys of the
+    /// hashdict hints. Nothing is registered if `values` is invalid.
+    fn build_compound_key(&mut self, py: Python<'_>, values: Vec<PyObject>) -> PyResult<PyObject> {
+        let values = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                value.extract::<PyMaybeRelocatable>(py).map(MaybeRelocatable::from).map_err(|_| {
+                    PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                        "Invalid value at index {}",
+                        index
+                    ))
+                })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let (dict_key, hashed_key) = compound_key_from_values(values)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        self.inner.borrow_mut().preimages.insert(hashed_key.into(), dict_key.clone());
+        PyDictKey::from(dict_key).into_py_any(py)
+    }
+
+    /// Squashes the accesses of every dict, read from the memory of `segments` between the base of
+    /// the dict segment and the current pointer of its tracker.
+    ///
+    /// Returns a `dict` mapping the base of each dict segment to its squashed accesses, as a list
+    /// of `(key, initial_value, final_value)` tuples sorted by key. Raises a `ValueError` if the
+    /// previous value of an access doesn't match the new value of the previous access to its key.
+    fn squash_all(&self, py: Python<'_>, segments: &PyMemorySegmentManager) -> PyResult<PyObject> {
+        let vm = unsafe { &*segments.vm };
+        let squashed_dicts = PyDict::new(py);
+        for (segment_index, tracker) in self.inner.borrow().trackers.iter() {
+            let base = Relocatable::from((*segment_index, 0));
+            let accesses = vm
+                .get_continuous_range(base, tracker.current_ptr.offset)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+            let mut squashed: Vec<(MaybeRelocatable, MaybeRelocatable, MaybeRelocatable)> = vec![];
+            let mut indices: HashMap<MaybeRelocatable, usize> = HashMap::new();
+            for access in accesses.chunks_exact(DICT_ACCESS_SIZE) {
+                let [key, prev_value, new_value] = access else { unreachable!() };
+                let Some(&index) = indices.get(key) else {
+                    indices.insert(key.clone(), squashed.len());
+                    squashed.push((key.clone(), prev_value.clone(), new_value.clone()));
+                    continue;
+                };
+                if squashed[index].2 != *prev_value {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Inconsistent accesses to key {} of dict {}: expected previous value {}, \
+                         got {}",
+                        key, base, squashed[index].2, prev_value
+                    )));
+                }
+                squashed[index].2 = new_value.clone();
+            }
+            squashed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+            let squashed = squashed
+                .into_iter()
+                .map(|(key, initial_value, final_value)| {
+                    (
+                        PyMaybeRelocatable::from(key),
+                        PyMaybeRelocatable::from(initial_value),
+                        PyMaybeRelocatable::from(final_value),
+                    )
+                })
+                .collect::<Vec<_>>();
+            squashed_dicts.set_item(PyRelocatable { inner: base }, squashed)?;
+        }
+        squashed_dicts.into_py_any(py)
+    }
+
     fn get_default_value(&self, segment_index: isize) -> PyResult<PyMaybeRelocatable> {
         let dict_manager = self.inner.borrow();
         let tracker = dict_manager.trackers.get(&segment_index).unwrap();
@@ -250,11 +372,60 @@ impl PyDictTracker {
         }
     }
 
+    /// Builds a tracker from a Python `dict` mapping keys (ints or tuples of ints) to values (ints
+    /// or relocatables), the inverse of `to_python_dict`.
+    #[staticmethod]
+    #[pyo3(signature = (data, current_ptr, default_value=None))]
+    fn from_python_dict(
+        data: Bound<'_, PyDict>,
+        current_ptr: PyRelocatable,
+        default_value: Option<PyMaybeRelocatable>,
+    ) -> PyResult<Self> {
+        let data = data.extract::<HashMap<PyDictKey, PyMaybeRelocatable>>()?;
+        Self::new(data, current_ptr, default_value)
+    }
+
     #[getter]
     fn current_ptr(&self) -> PyRelocatable {
         PyRelocatable { inner: self.inner.current_ptr }
     }
 
+    /// Inserts all the `(key, value)` pairs of `updates` and returns their count.
+    ///
+    /// Keys are ints or lists of ints (compound keys), values are ints or relocatables. All pairs
+    /// are validated before any is inserted.
+    ///
+    /// Trackers returned by `DictManager.get_tracker` and `DictManager.trackers[i]` are copies:
+    /// the updated tracker must be written back with `manager.trackers[i] = tracker`.
+    fn apply_updates(&mut self, updates: Vec<(PyObject, PyObject)>) -> PyResult<usize> {
+        let updates = Python::with_gil(|py| {
+            updates
+                .iter()
+                .enumerate()
+                .map(|(index, (key, value))| {
+                    let key = key.extract::<PyDictKey>(py).map_err(|_| {
+                        PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                            "Invalid key at index {}",
+                            index
+                        ))
+                    })?;
+                    let value = value.extract::<PyMaybeRelocatable>(py).map_err(|_| {
+                        PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                            "Invalid value at index {}",
+                            index
+                        ))
+                    })?;
+                    Ok((RustDictKey::from(key), MaybeRelocatable::from(value)))
+                })
+                .collect::<PyResult<Vec<_>>>()
+        })?;
+
+        for (key, value) in updates.iter() {
+            self.inner.insert_value(key, value);
+        }
+        Ok(updates.len())
+    }
+
     #[getter]
     fn data(&self) -> HashMap<PyDictKey, PyMaybeRelocatable> {
         self.inner
@@ -264,6 +435,39 @@ impl PyDictTracker {
             .collect()
     }
 
+    /// Returns the content of the tracker as a Python `dict`. Simple keys are converted to ints,
+    /// compound keys to tuples, and values to ints or relocatables.
+    fn to_python_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        for (key, value) in self.inner.get_dictionary_ref().iter() {
+            dict.set_item(PyDictKey::from(key.clone()), PyMaybeRelocatable::from(value.clone()))?;
+        }
+        dict.into_py_any(py)
+    }
+
+    /// Returns a snapshot of the content and current pointer of the tracker, to be restored with
+    /// `restore` if the execution that follows reverts.
+    ///
+    /// Trackers returned by `DictManager.get_tracker` are copies: use `DictManager.snapshot` and
+    /// `DictManager.restore` to roll back the tracker held by a manager.
+    fn snapshot(&self) -> PyDictTrackerSnapshot {
+        PyDictTrackerSnapshot { inner: self.inner.clone() }
+    }
+
+    /// Restores the content and current pointer of the tracker to those of `snapshot`, which must
+    /// have been taken from a tracker of the same dict segment.
+    fn restore(&mut self, snapshot: &PyDictTrackerSnapshot) -> PyResult<()> {
+        let segment_index = self.inner.current_ptr.segment_index;
+        if snapshot.inner.current_ptr.segment_index != segment_index {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Snapshot of segment {} cannot be restored to segment {}",
+                snapshot.inner.current_ptr.segment_index, segment_index
+            )));
+        }
+        self.inner = snapshot.inner.clone();
+        Ok(())
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         let mut pairs: Vec<_> = self.inner.get_dictionary_ref().iter().collect();
 
@@ -279,3 +483,10 @@ impl PyDictTracker {
         ))
     }
 }
+
+/// An opaque snapshot of a `DictTracker`, as returned by `DictTracker.snapshot`.
+#[pyclass(name = "DictTrackerSnapshot")]
+#[derive(Clone, Debug)]
+pub struct PyDictTrackerSnapshot {
+    inner: DictTracker,
+}
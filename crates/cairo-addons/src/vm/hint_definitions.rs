@@ -1,18 +1,118 @@
+mod basefee;
+mod batch_verify;
+mod bitwise_decompose;
+mod bloom;
+mod bytes31;
 mod bytes_hints;
+#[cfg(feature = "nested-runner")]
+mod cairo_run_inner;
+mod calldata;
+mod chunk_hash;
 mod circuits;
+mod commitment_tree;
 mod curve;
 mod dict;
+mod ecadd_projective;
+mod ecdsa_hash;
 mod ethereum;
+mod event;
+mod evm;
+mod felt252_array_ops;
+mod felt252_dict_entry;
+mod felt252_dict_init;
+mod felt_comparison;
+mod gas_table;
+mod hardfork;
+#[cfg(feature = "bls")]
+mod hash_to_curve;
 mod hashdict;
+#[cfg(feature = "memoize-hints")]
+mod hint_cache;
+mod keccak;
+#[cfg(feature = "kv-cache")]
+mod kv_cache;
+mod l1_handler;
 mod maths;
+mod memory_diff;
+mod mpt;
+mod output_ops;
+mod pedersen;
+mod point_on_curve;
+mod poseidon;
+mod precompile_bn256;
+mod precompile_ripemd;
 mod precompiles;
+mod preimage_registry;
+mod receipt;
+mod recursive_hasher;
+mod rlp;
+mod secp256k1;
+mod slot_hash;
+mod storage_write_log;
+mod syscall_gas;
+mod trie_key;
+mod tx_hash;
+mod u8_arithmetic;
+mod uint256;
 mod utils;
+mod word_reverse;
+pub use basefee::HINTS as BASEFEE_HINTS;
+pub use batch_verify::HINTS as BATCH_VERIFY_HINTS;
+pub use bitwise_decompose::HINTS as BITWISE_DECOMPOSE_HINTS;
+pub use bloom::HINTS as BLOOM_HINTS;
+pub use bytes31::HINTS as BYTES31_HINTS;
 pub use bytes_hints::HINTS as BYTES_HINTS;
+#[cfg(feature = "nested-runner")]
+pub use cairo_run_inner::HINTS as CAIRO_RUN_INNER_HINTS;
+pub use calldata::HINTS as CALLDATA_HINTS;
+pub use chunk_hash::HINTS as CHUNK_HASH_HINTS;
 pub use circuits::HINTS as CIRCUITS_HINTS;
+pub use commitment_tree::HINTS as COMMITMENT_TREE_HINTS;
 pub use curve::HINTS as CURVE_HINTS;
 pub use dict::HINTS as DICT_HINTS;
+pub use ecadd_projective::HINTS as ECADD_PROJECTIVE_HINTS;
+pub use ecdsa_hash::HINTS as ECDSA_HASH_HINTS;
 pub use ethereum::HINTS as ETHEREUM_HINTS;
-pub use hashdict::HINTS as HASHDICT_HINTS;
+pub use event::HINTS as EVENT_HINTS;
+pub use evm::HINTS as EVM_HINTS;
+pub use felt252_array_ops::HINTS as FELT252_ARRAY_OPS_HINTS;
+pub use felt252_dict_entry::HINTS as FELT252_DICT_ENTRY_HINTS;
+pub use felt252_dict_init::HINTS as FELT252_DICT_INIT_HINTS;
+pub use felt_comparison::HINTS as FELT_COMPARISON_HINTS;
+pub use gas_table::HINTS as GAS_TABLE_HINTS;
+pub use hardfork::{PyHardfork, HINTS as HARDFORK_HINTS};
+#[cfg(feature = "bls")]
+pub use hash_to_curve::HINTS as HASH_TO_CURVE_HINTS;
+pub use hashdict::{compound_key_from_values, HINTS as HASHDICT_HINTS};
+#[cfg(feature = "memoize-hints")]
+pub use hint_cache::HintCache;
+pub use keccak::HINTS as KECCAK_HINTS;
+#[cfg(feature = "kv-cache")]
+pub use kv_cache::HINTS as KV_CACHE_HINTS;
+pub use l1_handler::HINTS as L1_HANDLER_HINTS;
 pub use maths::HINTS as MATHS_HINTS;
+pub use memory_diff::HINTS as MEMORY_DIFF_HINTS;
+pub use mpt::HINTS as MPT_HINTS;
+pub use output_ops::HINTS as OUTPUT_OPS_HINTS;
+pub use pedersen::HINTS as PEDERSEN_HINTS;
+#[cfg(feature = "bls")]
+pub use point_on_curve::BLS_HINTS as POINT_ON_CURVE_BLS_HINTS;
+pub use point_on_curve::HINTS as POINT_ON_CURVE_HINTS;
+pub use poseidon::HINTS as POSEIDON_HINTS;
+pub use precompile_bn256::HINTS as PRECOMPILE_BN256_HINTS;
+pub use precompile_ripemd::HINTS as PRECOMPILE_RIPEMD_HINTS;
 pub use precompiles::HINTS as PRECOMPILES_HINTS;
+pub use preimage_registry::PreimageRegistry;
+pub use receipt::HINTS as RECEIPT_HINTS;
+pub use recursive_hasher::HINTS as RECURSIVE_HASHER_HINTS;
+pub use rlp::HINTS as RLP_HINTS;
+pub use secp256k1::HINTS as SECP256K1_HINTS;
+pub use slot_hash::HINTS as SLOT_HASH_HINTS;
+pub use storage_write_log::HINTS as STORAGE_WRITE_LOG_HINTS;
+pub use syscall_gas::HINTS as SYSCALL_GAS_HINTS;
+pub use trie_key::HINTS as TRIE_KEY_HINTS;
+pub use tx_hash::HINTS as TX_HASH_HINTS;
+pub use u8_arithmetic::HINTS as U8_ARITHMETIC_HINTS;
+pub use uint256::HINTS as UINT256_HINTS;
 pub use utils::HINTS as UTILS_HINTS;
+pub use word_reverse::HINTS as WORD_REVERSE_HINTS;
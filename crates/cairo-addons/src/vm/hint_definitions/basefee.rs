@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+use crate::vm::hints::Hint;
+
+pub const HINTS: &[fn() -> Hint] = &[compute_next_base_fee];
+
+const BASE_FEE_MAX_CHANGE_DENOMINATOR: u32 = 8;
+
+/// Computes the EIP-1559 base fee of the next block from the parent block's base fee, gas used
+/// and gas target, and writes it to `ids.next_base_fee`. Fails if the base fee doesn't fit in a
+/// felt.
+pub fn compute_next_base_fee() -> Hint {
+    Hint::new(
+        String::from("compute_next_base_fee"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let parent_base_fee =
+                get_integer_from_var_name("parent_base_fee", vm, ids_data, ap_tracking)?
+                    .to_biguint();
+            let parent_gas_used =
+                get_integer_from_var_name("parent_gas_used", vm, ids_data, ap_tracking)?
+                    .to_biguint();
+            let parent_gas_target =
+                get_integer_from_var_name("parent_gas_target", vm, ids_data, ap_tracking)?
+                    .to_biguint();
+
+            if parent_gas_target.is_zero() {
+                return Err(HintError::CustomHint(Box::from("parent_gas_target is zero")));
+            }
+
+            let next_base_fee = next_base_fee(parent_base_fee, parent_gas_used, parent_gas_target);
+            if next_base_fee > Felt252::MAX.to_biguint() {
+                return Err(HintError::CustomHint(Box::from("next_base_fee exceeds the field")));
+            }
+            insert_value_from_var_name(
+                "next_base_fee",
+                Felt252::from(&next_base_fee),
+                vm,
+                ids_data,
+                ap_tracking,
+            )
+        },
+    )
+}
+
+/// Same as `calculate_base_fee_per_gas` in EELS. The base fee isn't clamped from below: a
+/// decrease is at most 1/8 of a non-zero base fee, so it only stays at 0 if it already was.
+fn next_base_fee(
+    parent_base_fee: BigUint,
+    parent_gas_used: BigUint,
+    parent_gas_target: BigUint,
+) -> BigUint {
+    let denominator = &parent_gas_target * BASE_FEE_MAX_CHANGE_DENOMINATOR;
+    match parent_gas_used.cmp(&parent_gas_target) {
+        std::cmp::Ordering::Equal => parent_base_fee,
+        std::cmp::Ordering::Greater => {
+            let gas_used_delta = parent_gas_used - parent_gas_target;
+            let base_fee_delta =
+                (&parent_base_fee * gas_used_delta / denominator).max(BigUint::one());
+            parent_base_fee + base_fee_delta
+        }
+        std::cmp::Ordering::Less => {
+            let gas_used_delta = parent_gas_target - parent_gas_used;
+            let base_fee_delta = &parent_base_fee * gas_used_delta / denominator;
+            parent_base_fee - base_fee_delta
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+#[cfg(feature = "parallel-hints")]
+use rayon::prelude::*;
+use starknet_crypto::verify;
+
+use crate::vm::{
+    hint_utils::{read_usize_from_var_name, write_collection_to_addr},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[batch_verify_stark_ecdsa];
+
+/// Number of felts of a signature entry: `(public_key, msg_hash, sig_r, sig_s)`.
+const SIGNATURE_SIZE: usize = 4;
+
+/// Number of signature results packed in each felt of the bitmap.
+const BITMAP_WORD_SIZE: usize = 128;
+
+/// Verifies the `ids.n_signatures` STARK ECDSA signatures at `ids.signatures`, each stored as
+/// `(public_key, msg_hash, sig_r, sig_s)` where `public_key` is the x coordinate of the key, as
+/// with the signature builtin.
+///
+/// The results are written as a bitmap to a new segment whose base is written to `ids.bitmap`:
+/// bit `i % 128` of the felt `i / 128` is set if signature `i` is valid. Signatures with
+/// out-of-range values are invalid. With the `parallel-hints` feature, signatures are verified in
+/// parallel.
+pub fn batch_verify_stark_ecdsa() -> Hint {
+    Hint::new(
+        String::from("batch_verify_stark_ecdsa"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let n_signatures = read_usize_from_var_name("n_signatures", vm, ids_data, ap_tracking)?;
+            let signatures = if n_signatures == 0 {
+                vec![]
+            } else {
+                let signatures_ptr =
+                    get_ptr_from_var_name("signatures", vm, ids_data, ap_tracking)?;
+                vm.get_integer_range(signatures_ptr, n_signatures * SIGNATURE_SIZE)?
+                    .into_iter()
+                    .map(|felt| felt.into_owned())
+                    .collect::<Vec<_>>()
+            };
+
+            #[cfg(feature = "parallel-hints")]
+            let results =
+                signatures.par_chunks(SIGNATURE_SIZE).map(is_valid_signature).collect::<Vec<_>>();
+            #[cfg(not(feature = "parallel-hints"))]
+            let results =
+                signatures.chunks(SIGNATURE_SIZE).map(is_valid_signature).collect::<Vec<_>>();
+
+            let bitmap = results
+                .chunks(BITMAP_WORD_SIZE)
+                .map(|word| {
+                    let bits = word
+                        .iter()
+                        .enumerate()
+                        .fold(0u128, |bits, (i, is_valid)| bits | (u128::from(*is_valid) << i));
+                    Felt252::from(bits)
+                })
+                .collect::<Vec<_>>();
+            let bitmap_ptr = vm.add_memory_segment();
+            write_collection_to_addr(bitmap_ptr, &bitmap, vm)?;
+            insert_value_from_var_name("bitmap", bitmap_ptr, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Returns whether `signature`, as `(public_key, msg_hash, sig_r, sig_s)`, is valid.
+fn is_valid_signature(signature: &[Felt252]) -> bool {
+    let [public_key, msg_hash, r, s] = signature else {
+        return false;
+    };
+    verify(public_key, msg_hash, r, s).unwrap_or(false)
+}
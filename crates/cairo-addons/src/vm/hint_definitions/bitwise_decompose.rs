@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::vm::{
+    hint_utils::{read_usize_from_var_name, write_collection_to_addr},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[felt_to_bits_le, felt_to_bits_be];
+
+/// Writes the `ids.n_bits` bits of `ids.value`, least significant first, to a new segment whose
+/// base is written to `ids.bits_ptr`.
+pub fn felt_to_bits_le() -> Hint {
+    Hint::new(
+        String::from("felt_to_bits_le"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let bits = read_bits_le(vm, ids_data, ap_tracking)?;
+            write_bits(&bits, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes the `ids.n_bits` bits of `ids.value`, most significant first, to a new segment whose
+/// base is written to `ids.bits_ptr`.
+pub fn felt_to_bits_be() -> Hint {
+    Hint::new(
+        String::from("felt_to_bits_be"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let mut bits = read_bits_le(vm, ids_data, ap_tracking)?;
+            bits.reverse();
+            write_bits(&bits, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Returns the `ids.n_bits` bits of `ids.value` in little-endian order.
+fn read_bits_le(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<Vec<bool>, HintError> {
+    let value = get_integer_from_var_name("value", vm, ids_data, ap_tracking)?;
+    let n_bits = read_usize_from_var_name("n_bits", vm, ids_data, ap_tracking)?;
+    if value.bits() > n_bits {
+        return Err(HintError::CustomHint("Value exceeds n_bits".into()));
+    }
+
+    let value = value.to_biguint();
+    Ok((0..n_bits as u64).map(|i| value.bit(i)).collect())
+}
+
+fn write_bits(
+    bits: &[bool],
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let bits =
+        bits.iter().map(|bit| if *bit { Felt252::ONE } else { Felt252::ZERO }).collect::<Vec<_>>();
+    let bits_ptr = vm.add_memory_segment();
+    write_collection_to_addr(bits_ptr, &bits, vm)?;
+    insert_value_from_var_name("bits_ptr", bits_ptr, vm, ids_data, ap_tracking)
+}
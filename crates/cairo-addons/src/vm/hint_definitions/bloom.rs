@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{exec_scope::ExecutionScopes, relocatable::Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+use revm::primitives::keccak256;
+
+use crate::vm::hints::Hint;
+
+pub const HINTS: &[fn() -> Hint] = &[bloom_filter_check];
+
+/// Number of bytes of a logs Bloom filter, i.e. 2048 bits.
+const BLOOM_SIZE: usize = 256;
+
+/// Number of bytes of the entries checked against a Bloom filter.
+const BLOOM_ENTRY_SIZE: usize = 32;
+
+/// Checks whether the 32 bytes at `ids.topic_ptr` may have been added to the 256-byte logs Bloom
+/// filter at `ids.bloom_ptr`, both one byte per felt, and writes 1 to `ids.present` if the three
+/// bits of the entry are set, 0 otherwise.
+///
+/// As in `add_to_bloom` of the execution specs, the bits are given by the first three pairs of
+/// bytes of the keccak256 hash of the entry, each taken modulo 2048 and counted from the last bit
+/// of the filter.
+pub fn bloom_filter_check() -> Hint {
+    Hint::new(
+        String::from("bloom_filter_check"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let bloom_ptr = get_ptr_from_var_name("bloom_ptr", vm, ids_data, ap_tracking)?;
+            let bloom = read_bytes(vm, bloom_ptr, BLOOM_SIZE)?;
+            let topic_ptr = get_ptr_from_var_name("topic_ptr", vm, ids_data, ap_tracking)?;
+            let topic = read_bytes(vm, topic_ptr, BLOOM_ENTRY_SIZE)?;
+
+            let present = if bloom_bits(&topic).iter().all(|&bit| is_bit_set(&bloom, bit)) {
+                Felt252::ONE
+            } else {
+                Felt252::ZERO
+            };
+            insert_value_from_var_name("present", present, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Returns the indexes of the three bits set by `entry` in a Bloom filter, counted from its last
+/// bit.
+fn bloom_bits(entry: &[u8]) -> [usize; 3] {
+    let hash = keccak256(entry);
+    [0, 2, 4].map(|i| usize::from(u16::from_be_bytes([hash[i], hash[i + 1]]) & 0x07ff))
+}
+
+/// Returns whether the bit `bit`, counted from the last bit of `bloom`, is set.
+fn is_bit_set(bloom: &[u8], bit: usize) -> bool {
+    bloom[BLOOM_SIZE - 1 - bit / 8] & (1 << (bit % 8)) != 0
+}
+
+fn read_bytes(vm: &VirtualMachine, ptr: Relocatable, len: usize) -> Result<Vec<u8>, HintError> {
+    vm.get_integer_range(ptr, len)?
+        .into_iter()
+        .map(|byte| {
+            byte.to_u8().ok_or_else(|| HintError::CustomHint(Box::from("Value is not a byte")))
+        })
+        .collect()
+}
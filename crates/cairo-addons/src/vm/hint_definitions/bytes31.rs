@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+
+use crate::vm::{
+    hint_utils::{read_usize_from_var_name, write_collection_to_addr},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] =
+    &[bytes31_from_byte_array, bytes31_to_byte_array, bytes31_at_index];
+
+/// Number of bytes packed in a `bytes31`.
+const BYTES31_SIZE: usize = 31;
+
+/// Packs the 31 bytes at `ids.bytes`, most significant first, into a single felt written to
+/// `ids.value`.
+pub fn bytes31_from_byte_array() -> Hint {
+    Hint::new(
+        String::from("bytes31_from_byte_array"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let bytes_ptr = get_ptr_from_var_name("bytes", vm, ids_data, ap_tracking)?;
+            let bytes = vm
+                .get_integer_range(bytes_ptr, BYTES31_SIZE)?
+                .into_iter()
+                .enumerate()
+                .map(|(i, byte)| {
+                    byte.to_u8().ok_or_else(|| {
+                        HintError::CustomHint(
+                            format!("Byte at index {} out of range: {}", i, byte).into(),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let value = Felt252::from_bytes_be_slice(&bytes);
+            insert_value_from_var_name("value", value, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Unpacks the `bytes31` `ids.value` into 31 bytes, most significant first, written to
+/// `ids.bytes`.
+pub fn bytes31_to_byte_array() -> Hint {
+    Hint::new(
+        String::from("bytes31_to_byte_array"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let bytes = read_bytes31("value", vm, ids_data, ap_tracking)?
+                .into_iter()
+                .map(Felt252::from)
+                .collect::<Vec<_>>();
+            let bytes_ptr = get_ptr_from_var_name("bytes", vm, ids_data, ap_tracking)?;
+            write_collection_to_addr(bytes_ptr, &bytes, vm)?;
+            Ok(())
+        },
+    )
+}
+
+/// Writes the byte `ids.index` of the `bytes31` `ids.value` to `ids.byte`, where byte 0 is the
+/// most significant one, i.e. the first byte of the array `ids.value` was packed from.
+pub fn bytes31_at_index() -> Hint {
+    Hint::new(
+        String::from("bytes31_at_index"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let index = read_usize_from_var_name("index", vm, ids_data, ap_tracking)?;
+            if index >= BYTES31_SIZE {
+                return Err(HintError::CustomHint(format!("Index out of range: {}", index).into()));
+            }
+            let bytes = read_bytes31("value", vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name(
+                "byte",
+                Felt252::from(bytes[index]),
+                vm,
+                ids_data,
+                ap_tracking,
+            )
+        },
+    )
+}
+
+/// Reads the felt `ids.{name}` as the 31 big-endian bytes of a `bytes31`.
+fn read_bytes31(
+    name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<[u8; BYTES31_SIZE], HintError> {
+    let value = get_integer_from_var_name(name, vm, ids_data, ap_tracking)?;
+    if value.bits() > 8 * BYTES31_SIZE {
+        return Err(HintError::CustomHint(
+            format!("Value does not fit in bytes31: {}", value).into(),
+        ));
+    }
+
+    let mut bytes = [0u8; BYTES31_SIZE];
+    bytes.copy_from_slice(&value.to_bytes_be()[32 - BYTES31_SIZE..]);
+    Ok(bytes)
+}
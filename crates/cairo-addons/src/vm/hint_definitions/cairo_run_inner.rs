@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::{
+            builtin_hint_processor_definition::BuiltinHintProcessor,
+            hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{exec_scope::ExecutionScopes, layout_name::LayoutName, relocatable::MaybeRelocatable},
+    vm::{
+        errors::hint_errors::HintError,
+        runners::{
+            builtin_runner::BuiltinRunner, cairo_pie::StrippedProgram, cairo_runner::CairoRunner,
+        },
+        vm_core::VirtualMachine,
+    },
+    Felt252,
+};
+
+use crate::vm::{
+    hint_utils::{
+        read_short_string_from_var_name, read_usize_from_var_name, write_collection_to_addr,
+    },
+    hints::Hint,
+    stripped_program::stripped_program_to_program,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[cairo_run_inner];
+
+/// Runs a nested Cairo program and copies its output to the outer VM.
+///
+/// `ids.program_id` is a short string naming the execution scope variable that holds the
+/// `StrippedProgram` to run. Its `main` is called with the program builtins followed by the
+/// `ids.args_len` felts at `ids.args_ptr`. The content of the inner output segment is written to
+/// a new segment, whose base is written to `ids.output_ptr`.
+pub fn cairo_run_inner() -> Hint {
+    Hint::new(
+        String::from("cairo_run_inner"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let program_id =
+                read_short_string_from_var_name("program_id", vm, ids_data, ap_tracking)?;
+            let program = exec_scopes.get::<StrippedProgram>(&program_id)?;
+
+            let args_ptr = get_ptr_from_var_name("args_ptr", vm, ids_data, ap_tracking)?;
+            let args_len = read_usize_from_var_name("args_len", vm, ids_data, ap_tracking)?;
+            let args = vm
+                .get_integer_range(args_ptr, args_len)?
+                .into_iter()
+                .map(|arg| MaybeRelocatable::Int(arg.into_owned()))
+                .collect::<Vec<_>>();
+
+            let output = run_stripped_program(&program, args).map_err(|e| {
+                HintError::CustomHint(format!("Inner program {} failed: {}", program_id, e).into())
+            })?;
+
+            let output_ptr = vm.add_memory_segment();
+            write_collection_to_addr(output_ptr, &output, vm)?;
+            insert_value_from_var_name("output_ptr", output_ptr, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Runs `program` from its `main` entrypoint and returns the content of its output segment.
+fn run_stripped_program(
+    stripped_program: &StrippedProgram,
+    args: Vec<MaybeRelocatable>,
+) -> Result<Vec<Felt252>, String> {
+    let program = stripped_program_to_program(stripped_program).map_err(|e| e.to_string())?;
+
+    let mut runner = CairoRunner::new(&program, LayoutName::all_cairo, None, false, false, false)
+        .map_err(|e| e.to_string())?;
+    runner.initialize_builtins(false).map_err(|e| e.to_string())?;
+    runner.initialize_segments(None);
+
+    let mut stack = runner
+        .vm
+        .builtin_runners
+        .iter()
+        .flat_map(|builtin_runner| builtin_runner.initial_stack())
+        .collect::<Vec<_>>();
+    stack.extend(args);
+
+    let return_fp = runner.vm.add_memory_segment();
+    let end = runner
+        .initialize_function_entrypoint(stripped_program.main, stack, return_fp.into())
+        .map_err(|e| e.to_string())?;
+    runner.initialize_vm().map_err(|e| e.to_string())?;
+
+    // Stripped programs don't contain hints.
+    let mut hint_processor = BuiltinHintProcessor::new_empty();
+    runner.run_until_pc(end, &mut hint_processor).map_err(|e| e.to_string())?;
+
+    let Some(output_base) =
+        runner.vm.builtin_runners.iter().find_map(|builtin_runner| match builtin_runner {
+            BuiltinRunner::Output(output_runner) => Some(output_runner.base()),
+            _ => None,
+        })
+    else {
+        return Ok(Vec::new());
+    };
+    runner.vm.segments.compute_effective_sizes();
+    let output_size = runner.vm.segments.get_segment_used_size(output_base).unwrap_or_default();
+    runner
+        .vm
+        .get_integer_range((output_base as isize, 0).into(), output_size)
+        .map(|values| values.into_iter().map(|value| value.into_owned()).collect())
+        .map_err(|e| e.to_string())
+}
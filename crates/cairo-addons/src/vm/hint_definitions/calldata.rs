@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{exec_scope::ExecutionScopes, relocatable::Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+
+use crate::vm::{
+    hint_utils::{read_usize_from_var_name, Uint256},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] =
+    &[abi_decode_uint256, abi_decode_address, abi_decode_bytes_dynamic];
+
+const WORD_SIZE: usize = 32;
+const ADDRESS_SIZE: usize = 20;
+
+/// Decodes the `uint256` at `word_offset` of the ABI-encoded `calldata` and writes it to
+/// `ids.low` and `ids.high`.
+pub fn abi_decode_uint256() -> Hint {
+    Hint::new(
+        String::from("abi_decode_uint256"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let calldata = Calldata::from_ids(vm, ids_data, ap_tracking)?;
+            let word_offset = read_usize_from_var_name("word_offset", vm, ids_data, ap_tracking)?;
+            let value = calldata.read_word(vm, word_offset.saturating_mul(WORD_SIZE))?;
+
+            let Uint256 { low, high } = Uint256::split(&value);
+            insert_value_from_var_name("low", low.into_owned(), vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name("high", high.into_owned(), vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Decodes the `address` at `word_offset` of the ABI-encoded `calldata` and writes it to
+/// `ids.address`, as a little-endian felt like the `Address` type.
+pub fn abi_decode_address() -> Hint {
+    Hint::new(
+        String::from("abi_decode_address"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let calldata = Calldata::from_ids(vm, ids_data, ap_tracking)?;
+            let word_offset = read_usize_from_var_name("word_offset", vm, ids_data, ap_tracking)?;
+            let word = calldata.read_word(vm, word_offset.saturating_mul(WORD_SIZE))?.to_bytes_be();
+
+            let mut bytes = [0u8; WORD_SIZE];
+            bytes[WORD_SIZE - word.len()..].copy_from_slice(&word);
+            let address = Felt252::from_bytes_le_slice(&bytes[WORD_SIZE - ADDRESS_SIZE..]);
+            insert_value_from_var_name("address", address, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Decodes the dynamic `bytes` whose offset is stored at `word_offset` of the ABI-encoded
+/// `calldata`, and writes a pointer to its first byte to `ids.data_ptr` and its length to
+/// `ids.data_len`.
+pub fn abi_decode_bytes_dynamic() -> Hint {
+    Hint::new(
+        String::from("abi_decode_bytes_dynamic"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let calldata = Calldata::from_ids(vm, ids_data, ap_tracking)?;
+            let word_offset = read_usize_from_var_name("word_offset", vm, ids_data, ap_tracking)?;
+
+            let data_offset =
+                calldata.read_word_as_usize(vm, word_offset.saturating_mul(WORD_SIZE))?;
+            let data_len = calldata.read_word_as_usize(vm, data_offset)?;
+            let data_start = data_offset + WORD_SIZE;
+            if data_start.checked_add(data_len).is_none_or(|end| end > calldata.len) {
+                return Err(HintError::CustomHint(Box::from(format!(
+                    "Bytes of length {} at offset {} exceed calldata length {}",
+                    data_len, data_offset, calldata.len
+                ))));
+            }
+
+            let data_ptr = (calldata.ptr + data_start)?;
+            insert_value_from_var_name("data_ptr", data_ptr, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name("data_len", data_len, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// ABI-encoded calldata, stored one byte per felt.
+struct Calldata {
+    ptr: Relocatable,
+    len: usize,
+}
+
+impl Calldata {
+    fn from_ids(
+        vm: &VirtualMachine,
+        ids_data: &HashMap<String, HintReference>,
+        ap_tracking: &ApTracking,
+    ) -> Result<Self, HintError> {
+        Ok(Self {
+            ptr: get_ptr_from_var_name("calldata_ptr", vm, ids_data, ap_tracking)?,
+            len: read_usize_from_var_name("calldata_len", vm, ids_data, ap_tracking)?,
+        })
+    }
+
+    /// Reads the big-endian 32-byte word starting at `byte_offset`.
+    fn read_word(&self, vm: &VirtualMachine, byte_offset: usize) -> Result<BigUint, HintError> {
+        if byte_offset.checked_add(WORD_SIZE).is_none_or(|end| end > self.len) {
+            return Err(HintError::CustomHint(Box::from(format!(
+                "Word at offset {} exceeds calldata length {}",
+                byte_offset, self.len
+            ))));
+        }
+        let bytes = vm
+            .get_integer_range((self.ptr + byte_offset)?, WORD_SIZE)?
+            .iter()
+            .map(|byte| byte.to_u8())
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(|| {
+                HintError::CustomHint(Box::from("Calldata contains a non-byte value"))
+            })?;
+        Ok(BigUint::from_bytes_be(&bytes))
+    }
+
+    fn read_word_as_usize(
+        &self,
+        vm: &VirtualMachine,
+        byte_offset: usize,
+    ) -> Result<usize, HintError> {
+        self.read_word(vm, byte_offset)?.to_usize().ok_or_else(|| {
+            HintError::CustomHint(Box::from(format!(
+                "Word at offset {} does not fit in a usize",
+                byte_offset
+            )))
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use starknet_crypto::poseidon_hash_many;
+
+use crate::vm::{hint_utils::read_usize_from_var_name, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] = &[poseidon_chunk_hash];
+
+/// Maximum number of felts hashed by a single `poseidon_hash_many` call.
+const CHUNK_SIZE: usize = 1024;
+
+/// Hashes the `ids.data_len` felts at `ids.data_ptr` as a tree of Poseidon hashes and writes the
+/// result to `ids.root_hash`.
+///
+/// The data is split in chunks of `CHUNK_SIZE` felts, each hashed with `poseidon_hash_many`, and
+/// the chunk hashes are hashed the same way until a single hash remains. Data that fits in one
+/// chunk hashes to `poseidon_hash_many(data)`.
+pub fn poseidon_chunk_hash() -> Hint {
+    Hint::new(
+        String::from("poseidon_chunk_hash"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let data_ptr = get_ptr_from_var_name("data_ptr", vm, ids_data, ap_tracking)?;
+            let data_len = read_usize_from_var_name("data_len", vm, ids_data, ap_tracking)?;
+            let data = vm
+                .get_integer_range(data_ptr, data_len)?
+                .into_iter()
+                .map(|felt| felt.into_owned())
+                .collect::<Vec<_>>();
+
+            let root_hash = chunk_hash(data);
+            insert_value_from_var_name("root_hash", root_hash, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+fn chunk_hash(mut data: Vec<Felt252>) -> Felt252 {
+    while data.len() > CHUNK_SIZE {
+        data = data.chunks(CHUNK_SIZE).map(poseidon_hash_many).collect();
+    }
+    poseidon_hash_many(&data)
+}
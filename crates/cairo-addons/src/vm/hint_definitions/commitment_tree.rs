@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use starknet_crypto::poseidon_hash;
+
+use crate::vm::{
+    hint_utils::{read_usize_from_var_name, write_collection_to_addr},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[merkle_tree_build, merkle_tree_get_proof];
+
+/// Builds the binary Merkle tree of the `ids.n_leaves` felts at `ids.leaves_ptr`, where each
+/// internal node is the Poseidon hash of its two children. The number of leaves must be a power of
+/// two.
+///
+/// The nodes are written level by level to a new segment, from the leaves to the root, so that the
+/// tree of `n` leaves has `2n - 1` nodes and the root is the last one. The base of the segment is
+/// written to `ids.tree_ptr`, its length to `ids.tree_len` and the root to `ids.root`.
+pub fn merkle_tree_build() -> Hint {
+    Hint::new(
+        String::from("merkle_tree_build"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let n_leaves = read_usize_from_var_name("n_leaves", vm, ids_data, ap_tracking)?;
+            if !n_leaves.is_power_of_two() {
+                return Err(HintError::CustomHint(
+                    format!("Number of leaves is not a power of two: {}", n_leaves).into(),
+                ));
+            }
+            let leaves_ptr = get_ptr_from_var_name("leaves_ptr", vm, ids_data, ap_tracking)?;
+            let leaves = vm
+                .get_integer_range(leaves_ptr, n_leaves)?
+                .into_iter()
+                .map(|felt| felt.into_owned())
+                .collect::<Vec<_>>();
+
+            let tree = build_tree(leaves);
+            let root = tree[tree.len() - 1];
+            let tree_ptr = vm.add_memory_segment();
+            write_collection_to_addr(tree_ptr, &tree, vm)?;
+            insert_value_from_var_name("tree_ptr", tree_ptr, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name(
+                "tree_len",
+                Felt252::from(tree.len()),
+                vm,
+                ids_data,
+                ap_tracking,
+            )?;
+            insert_value_from_var_name("root", root, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes the Merkle proof of the leaf `ids.leaf_index` of the tree of `ids.tree_len` nodes at
+/// `ids.tree_ptr`, as built by `merkle_tree_build`, to a new segment whose base is written to
+/// `ids.proof`.
+///
+/// The proof is made of the `log2(n_leaves)` sibling hashes of the path from the leaf to the root,
+/// starting with the sibling of the leaf.
+pub fn merkle_tree_get_proof() -> Hint {
+    Hint::new(
+        String::from("merkle_tree_get_proof"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let tree_len = read_usize_from_var_name("tree_len", vm, ids_data, ap_tracking)?;
+            let n_leaves = tree_len.div_ceil(2);
+            if !n_leaves.is_power_of_two() || tree_len != 2 * n_leaves - 1 {
+                return Err(HintError::CustomHint(
+                    format!("Invalid Merkle tree length: {}", tree_len).into(),
+                ));
+            }
+            let leaf_index = read_usize_from_var_name("leaf_index", vm, ids_data, ap_tracking)?;
+            if leaf_index >= n_leaves {
+                return Err(HintError::CustomHint(
+                    format!("Leaf index out of range: {}", leaf_index).into(),
+                ));
+            }
+            let tree_ptr = get_ptr_from_var_name("tree_ptr", vm, ids_data, ap_tracking)?;
+            let tree = vm
+                .get_integer_range(tree_ptr, tree_len)?
+                .into_iter()
+                .map(|felt| felt.into_owned())
+                .collect::<Vec<_>>();
+
+            let proof = merkle_proof(&tree, n_leaves, leaf_index);
+            let proof_ptr = vm.add_memory_segment();
+            write_collection_to_addr(proof_ptr, &proof, vm)?;
+            insert_value_from_var_name("proof", proof_ptr, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Returns the nodes of the Merkle tree of `leaves`, level by level from the leaves to the root.
+fn build_tree(leaves: Vec<Felt252>) -> Vec<Felt252> {
+    let mut tree = leaves;
+    let mut level_start = 0;
+    let mut level_len = tree.len();
+    while level_len > 1 {
+        let parents = tree[level_start..level_start + level_len]
+            .chunks(2)
+            .map(|pair| poseidon_hash(pair[0], pair[1]))
+            .collect::<Vec<_>>();
+        level_start += level_len;
+        level_len = parents.len();
+        tree.extend(parents);
+    }
+    tree
+}
+
+/// Returns the siblings of the path from the leaf `leaf_index` to the root of `tree`.
+fn merkle_proof(tree: &[Felt252], n_leaves: usize, mut leaf_index: usize) -> Vec<Felt252> {
+    let mut proof = vec![];
+    let mut level_start = 0;
+    let mut level_len = n_leaves;
+    while level_len > 1 {
+        proof.push(tree[level_start + (leaf_index ^ 1)]);
+        leaf_index /= 2;
+        level_start += level_len;
+        level_len /= 2;
+    }
+    proof
+}
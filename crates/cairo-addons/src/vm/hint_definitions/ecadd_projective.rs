@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use ark_bn254::{g1::Config, Fq, G1Affine, G1Projective};
+use ark_ec::{short_weierstrass::SWCurveConfig, CurveGroup};
+use ark_ff::{Field, PrimeField, Zero};
+use cairo_vm::{
+    hint_processor::hint_processor_definition::HintReference,
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+
+use crate::vm::{hint_utils::Uint256, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] = &[bn254_g1_projective_add, bn254_g1_projective_to_affine];
+
+/// Adds two BN254 G1 points given in projective (Jacobian, as in arkworks) coordinates
+/// `(X1, Y1, Z1)` and `(X2, Y2, Z2)`, and writes the result to `(X3, Y3, Z3)`.
+/// Each coordinate is a `Uint256`.
+pub fn bn254_g1_projective_add() -> Hint {
+    Hint::new(
+        String::from("bn254_g1_projective_add"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let p1 = read_projective(["X1", "Y1", "Z1"], vm, ids_data, ap_tracking)?;
+            let p2 = read_projective(["X2", "Y2", "Z2"], vm, ids_data, ap_tracking)?;
+            let sum = p1 + p2;
+
+            for (name, coordinate) in [("X3", sum.x), ("Y3", sum.y), ("Z3", sum.z)] {
+                Uint256::split(&coordinate.into_bigint().into())._insert_from_var_name(
+                    name,
+                    vm,
+                    ids_data,
+                    ap_tracking,
+                )?;
+            }
+            Ok(())
+        },
+    )
+}
+
+/// Converts the BN254 G1 point `(X, Y, Z)` to affine coordinates and writes them to `(x, y)`.
+/// The point at infinity is written as `(0, 0)`.
+pub fn bn254_g1_projective_to_affine() -> Hint {
+    Hint::new(
+        String::from("bn254_g1_projective_to_affine"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let point = read_projective(["X", "Y", "Z"], vm, ids_data, ap_tracking)?;
+            let affine: G1Affine = point.into_affine();
+            let (x, y) =
+                if affine.infinity { (Fq::zero(), Fq::zero()) } else { (affine.x, affine.y) };
+
+            for (name, coordinate) in [("x", x), ("y", y)] {
+                Uint256::split(&coordinate.into_bigint().into())._insert_from_var_name(
+                    name,
+                    vm,
+                    ids_data,
+                    ap_tracking,
+                )?;
+            }
+            Ok(())
+        },
+    )
+}
+
+/// Reads a point given as `Uint256` projective coordinates, checking it lies on the curve, i.e.
+/// that `Y^2 = X^3 + 3 * Z^6`, unless `Z = 0` which encodes the point at infinity.
+fn read_projective(
+    [x, y, z]: [&str; 3],
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<G1Projective, HintError> {
+    let point = G1Projective::new_unchecked(
+        read_field_element(x, vm, ids_data, ap_tracking)?,
+        read_field_element(y, vm, ids_data, ap_tracking)?,
+        read_field_element(z, vm, ids_data, ap_tracking)?,
+    );
+    let z2 = point.z.square();
+    if !point.z.is_zero() &&
+        point.y.square() != point.x.square() * point.x + Config::COEFF_B * z2.square() * z2
+    {
+        return Err(HintError::CustomHint(
+            format!("({}, {}, {}) is not on the curve", x, y, z).into(),
+        ));
+    }
+    Ok(point)
+}
+
+fn read_field_element(
+    name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<Fq, HintError> {
+    let value = Uint256::from_var_name(name, vm, ids_data, ap_tracking)?.pack();
+    if value >= BigUint::from(Fq::MODULUS) {
+        return Err(HintError::CustomHint(format!("{} is not a valid field element", name).into()));
+    }
+    Ok(Fq::from(value))
+}
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::vm::{
+    hint_utils::{compute_hash_on_elements, read_usize_from_var_name},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[compute_stark_tx_hash];
+
+/// Version of the invoke transactions hashed by `compute_stark_tx_hash`.
+const INVOKE_TX_VERSION: u8 = 1;
+
+/// Computes the hash of a StarkNet v1 invoke transaction and writes it to `ids.tx_hash`.
+///
+/// The hash is `h(["invoke", version, contract_address, selector, h(calldata), max_fee,
+/// chain_id, nonce])`, where `h` chains Pedersen hashes over the elements, starting from 0, and
+/// finally hashes in the number of elements.
+pub fn compute_stark_tx_hash() -> Hint {
+    Hint::new(
+        String::from("compute_stark_tx_hash"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let read = |name| get_integer_from_var_name(name, vm, ids_data, ap_tracking);
+            let contract_address = read("contract_address")?;
+            let selector = read("selector")?;
+            let nonce = read("nonce")?;
+            let max_fee = read("max_fee")?;
+            let chain_id = read("chain_id")?;
+
+            let calldata_ptr = get_ptr_from_var_name("calldata_ptr", vm, ids_data, ap_tracking)?;
+            let calldata_len = read_usize_from_var_name("calldata_len", vm, ids_data, ap_tracking)?;
+            let calldata = vm
+                .get_integer_range(calldata_ptr, calldata_len)?
+                .into_iter()
+                .map(|felt| felt.into_owned())
+                .collect::<Vec<_>>();
+
+            let tx_hash = compute_hash_on_elements(&[
+                Felt252::from_bytes_be_slice(b"invoke"),
+                Felt252::from(INVOKE_TX_VERSION),
+                contract_address,
+                selector,
+                compute_hash_on_elements(&calldata),
+                max_fee,
+                chain_id,
+                nonce,
+            ]);
+            insert_value_from_var_name("tx_hash", tx_hash, vm, ids_data, ap_tracking)
+        },
+    )
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use starknet_crypto::poseidon_hash_many;
+
+use crate::vm::{hint_utils::read_usize_from_var_name, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] = &[hash_event_content, emit_event_and_track];
+
+/// Name of the execution scope variable holding the emitted events.
+const EMITTED_EVENTS: &str = "emitted_events";
+
+/// An emitted event: `(keys, data)`.
+type EmittedEvent = (Vec<Felt252>, Vec<Felt252>);
+
+/// Hashes the keys and the data of an event, respectively the `ids.keys_len` felts at
+/// `ids.keys_ptr` and the `ids.data_len` felts at `ids.data_ptr`, with `poseidon_hash_many`, and
+/// writes the hashes to `ids.keys_hash` and `ids.data_hash`.
+pub fn hash_event_content() -> Hint {
+    Hint::new(
+        String::from("hash_event_content"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let (keys, data) = read_event(vm, ids_data, ap_tracking)?;
+            write_event_hashes(&keys, &data, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Same as `hash_event_content`, and appends the event to the `emitted_events` scope variable.
+pub fn emit_event_and_track() -> Hint {
+    Hint::new(
+        String::from("emit_event_and_track"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let (keys, data) = read_event(vm, ids_data, ap_tracking)?;
+            write_event_hashes(&keys, &data, vm, ids_data, ap_tracking)?;
+            emitted_events(exec_scopes)?.push((keys, data));
+            Ok(())
+        },
+    )
+}
+
+fn read_event(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<EmittedEvent, HintError> {
+    let read_felts = |ptr_name: &str, len_name: &str| -> Result<Vec<Felt252>, HintError> {
+        let len = read_usize_from_var_name(len_name, vm, ids_data, ap_tracking)?;
+        if len == 0 {
+            return Ok(vec![]);
+        }
+        let ptr = get_ptr_from_var_name(ptr_name, vm, ids_data, ap_tracking)?;
+        Ok(vm.get_integer_range(ptr, len)?.into_iter().map(|felt| felt.into_owned()).collect())
+    };
+    Ok((read_felts("keys_ptr", "keys_len")?, read_felts("data_ptr", "data_len")?))
+}
+
+fn write_event_hashes(
+    keys: &[Felt252],
+    data: &[Felt252],
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    insert_value_from_var_name("keys_hash", poseidon_hash_many(keys), vm, ids_data, ap_tracking)?;
+    insert_value_from_var_name("data_hash", poseidon_hash_many(data), vm, ids_data, ap_tracking)
+}
+
+/// Returns the emitted events of the current scope, creating them if needed.
+fn emitted_events(exec_scopes: &mut ExecutionScopes) -> Result<&mut Vec<EmittedEvent>, HintError> {
+    if exec_scopes.get_ref::<Vec<EmittedEvent>>(EMITTED_EVENTS).is_err() {
+        exec_scopes.assign_or_update_variable(EMITTED_EVENTS, Box::new(Vec::<EmittedEvent>::new()));
+    }
+    exec_scopes.get_mut_ref::<Vec<EmittedEvent>>(EMITTED_EVENTS)
+}
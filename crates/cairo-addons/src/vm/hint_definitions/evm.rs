@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+use revm::primitives::keccak256;
+
+use crate::vm::{hint_utils::mpt::encode_list, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] = &[compute_create_address, compute_create2_address];
+
+/// Number of bytes of an EVM address.
+const ADDRESS_SIZE: usize = 20;
+
+/// Computes the address of a contract created by `CREATE`, the last 20 bytes of
+/// `keccak256(rlp([sender, nonce]))`, and writes it to `ids.address`.
+///
+/// As in `compute_contract_address`, addresses are little-endian felts of their 20 bytes. The
+/// nonce is encoded as an RLP integer, so that a nonce of 0 is the empty string `0x80`.
+pub fn compute_create_address() -> Hint {
+    Hint::new(
+        String::from("compute_create_address"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let sender = read_address("sender", vm, ids_data, ap_tracking)?;
+            let nonce = get_integer_from_var_name("nonce", vm, ids_data, ap_tracking)?;
+            // Nonces are bounded by 2^64 - 1 since EIP-2681.
+            let nonce = nonce
+                .to_u64()
+                .ok_or_else(|| HintError::CustomHint(Box::from("nonce exceeds 64 bits")))?;
+
+            let message =
+                encode_list(&[alloy_rlp::encode(sender.as_slice()), alloy_rlp::encode(nonce)]);
+            let hash = keccak256(&message);
+            let address = Felt252::from_bytes_le_slice(&hash[32 - ADDRESS_SIZE..]);
+            insert_value_from_var_name("address", address, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Computes the address of a contract created by `CREATE2`, the last 20 bytes of
+/// `keccak256(0xff ++ sender ++ salt ++ init_code_hash)` as defined in EIP-1014, and writes it to
+/// `ids.address`.
+///
+/// As in `compute_create2_contract_address`, addresses are little-endian felts of their 20 bytes,
+/// and the salt and init code hash are `Bytes32` given by their little-endian 128-bit halves
+/// `ids.salt_low`, `ids.salt_high` and `ids.init_code_hash_low`, `ids.init_code_hash_high`.
+pub fn compute_create2_address() -> Hint {
+    Hint::new(
+        String::from("compute_create2_address"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let mut preimage = Vec::with_capacity(1 + ADDRESS_SIZE + 32 + 32);
+            preimage.push(0xff);
+            preimage.extend_from_slice(&read_address("sender", vm, ids_data, ap_tracking)?);
+            for name in ["salt_low", "salt_high", "init_code_hash_low", "init_code_hash_high"] {
+                let limb = get_integer_from_var_name(name, vm, ids_data, ap_tracking)?;
+                let limb = limb.to_u128().ok_or_else(|| {
+                    HintError::CustomHint(format!("{} exceeds 128 bits", name).into())
+                })?;
+                preimage.extend_from_slice(&limb.to_le_bytes());
+            }
+
+            let hash = keccak256(&preimage);
+            let address = Felt252::from_bytes_le_slice(&hash[32 - ADDRESS_SIZE..]);
+            insert_value_from_var_name("address", address, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Reads the address `ids.<name>`, a little-endian felt, as its 20 bytes.
+fn read_address(
+    name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<[u8; ADDRESS_SIZE], HintError> {
+    let bytes = get_integer_from_var_name(name, vm, ids_data, ap_tracking)?.to_bytes_le();
+    if bytes[ADDRESS_SIZE..].iter().any(|byte| *byte != 0) {
+        return Err(HintError::CustomHint(format!("{} is not a 20-byte address", name).into()));
+    }
+    let mut address = [0u8; ADDRESS_SIZE];
+    address.copy_from_slice(&bytes[..ADDRESS_SIZE]);
+    Ok(address)
+}
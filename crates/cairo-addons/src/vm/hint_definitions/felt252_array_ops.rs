@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::vm::{
+    hint_utils::{read_usize_from_var_name, write_collection_to_addr},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[felt_array_add, felt_array_mul, felt_array_dot];
+
+/// Writes the element-wise sum of the arrays `a_ptr` and `b_ptr` to a new segment, whose base
+/// is written to `ids.result_ptr`.
+pub fn felt_array_add() -> Hint {
+    Hint::new(
+        String::from("felt_array_add"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let (a, b) = read_arrays(vm, ids_data, ap_tracking)?;
+            let result = a.iter().zip(&b).map(|(a, b)| *a + *b).collect::<Vec<Felt252>>();
+            write_result_array(&result, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes the element-wise product of the arrays `a_ptr` and `b_ptr` to a new segment, whose
+/// base is written to `ids.result_ptr`.
+pub fn felt_array_mul() -> Hint {
+    Hint::new(
+        String::from("felt_array_mul"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let (a, b) = read_arrays(vm, ids_data, ap_tracking)?;
+            let result = a.iter().zip(&b).map(|(a, b)| *a * *b).collect::<Vec<Felt252>>();
+            write_result_array(&result, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes the dot product of the arrays `a_ptr` and `b_ptr` to `ids.result`.
+pub fn felt_array_dot() -> Hint {
+    Hint::new(
+        String::from("felt_array_dot"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let (a, b) = read_arrays(vm, ids_data, ap_tracking)?;
+            let result = a.iter().zip(&b).fold(Felt252::ZERO, |acc, (a, b)| acc + *a * *b);
+            insert_value_from_var_name("result", result, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Reads the `len` elements of the arrays `a_ptr` and `b_ptr`.
+fn read_arrays(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(Vec<Felt252>, Vec<Felt252>), HintError> {
+    let len = read_usize_from_var_name("len", vm, ids_data, ap_tracking)?;
+    let read_array = |name: &str| -> Result<Vec<Felt252>, HintError> {
+        let ptr = get_ptr_from_var_name(name, vm, ids_data, ap_tracking)?;
+        Ok(vm.get_integer_range(ptr, len)?.into_iter().map(|x| x.into_owned()).collect())
+    };
+    Ok((read_array("a_ptr")?, read_array("b_ptr")?))
+}
+
+fn write_result_array(
+    result: &[Felt252],
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let result_ptr = vm.add_memory_segment();
+    write_collection_to_addr(result_ptr, result, vm)?;
+    insert_value_from_var_name("result_ptr", result_ptr, vm, ids_data, ap_tracking)
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::{
+            dict_hint_utils::DICT_ACCESS_SIZE,
+            dict_manager::DictKey,
+            hint_utils::{
+                get_integer_from_var_name, get_maybe_relocatable_from_var_name,
+                get_ptr_from_var_name, insert_value_from_var_name,
+            },
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::vm::hints::Hint;
+
+pub const HINTS: &[fn() -> Hint] = &[felt252_dict_entry_init, felt252_dict_entry_finalize];
+
+/// Name of the execution scope variable holding the key of the entry being accessed.
+const OPEN_ENTRY_KEY: &str = "open_entry_key";
+
+/// Opens an entry of the dict at `ids.dict_ptr`: writes the current value of `ids.key` to
+/// `ids.current_value` and keeps the key in the `open_entry_key` execution scope variable.
+pub fn felt252_dict_entry_init() -> Hint {
+    Hint::new(
+        String::from("felt252_dict_entry_init"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            if let Ok(open_key) = exec_scopes.get::<Felt252>(OPEN_ENTRY_KEY) {
+                return Err(HintError::CustomHint(
+                    format!("Dict entry {} is already open", open_key).into(),
+                ));
+            }
+
+            let dict_ptr = get_ptr_from_var_name("dict_ptr", vm, ids_data, ap_tracking)?;
+            let key = get_integer_from_var_name("key", vm, ids_data, ap_tracking)?;
+            let current_value = exec_scopes
+                .get_dict_manager()?
+                .borrow_mut()
+                .get_tracker_mut(dict_ptr)?
+                .get_value(&DictKey::Simple(MaybeRelocatable::Int(key)))?
+                .clone();
+
+            insert_value_from_var_name("current_value", current_value, vm, ids_data, ap_tracking)?;
+            exec_scopes.assign_or_update_variable(OPEN_ENTRY_KEY, Box::new(key));
+            Ok(())
+        },
+    )
+}
+
+/// Closes the entry opened by `felt252_dict_entry_init`: sets `ids.key` to `ids.new_value` in the
+/// dict at `ids.dict_ptr` and advances the dict pointer past the corresponding `DictAccess`.
+pub fn felt252_dict_entry_finalize() -> Hint {
+    Hint::new(
+        String::from("felt252_dict_entry_finalize"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let open_key = exec_scopes.get::<Felt252>(OPEN_ENTRY_KEY).map_err(|_| {
+                HintError::CustomHint("Dict entry finalized without being initialized".into())
+            })?;
+            let key = get_integer_from_var_name("key", vm, ids_data, ap_tracking)?;
+            if key != open_key {
+                return Err(HintError::CustomHint(
+                    format!("Dict entry {} finalized while entry {} is open", key, open_key).into(),
+                ));
+            }
+
+            let dict_ptr = get_ptr_from_var_name("dict_ptr", vm, ids_data, ap_tracking)?;
+            let new_value =
+                get_maybe_relocatable_from_var_name("new_value", vm, ids_data, ap_tracking)?;
+            let dict_manager_ref = exec_scopes.get_dict_manager()?;
+            let mut dict_manager = dict_manager_ref.borrow_mut();
+            let tracker = dict_manager.get_tracker_mut(dict_ptr)?;
+            tracker.insert_value(&DictKey::Simple(MaybeRelocatable::Int(key)), &new_value);
+            tracker.current_ptr.offset += DICT_ACCESS_SIZE;
+
+            exec_scopes.delete_variable(OPEN_ENTRY_KEY);
+            Ok(())
+        },
+    )
+}
@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::{dict_manager::DictKey, hint_utils::insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::vm::hints::Hint;
+
+pub const HINTS: &[fn() -> Hint] = &[felt252_dict_init_from_scope];
+
+/// Name of the execution scope variable holding the initial entries of the dict.
+const INIT_KEY: &str = "init_key";
+
+/// Creates a new dict pre-populated with the `Vec<(Felt252, MaybeRelocatable)>` stored in the
+/// `init_key` execution scope variable, and writes its base pointer to `ids.dict_ptr`.
+pub fn felt252_dict_init_from_scope() -> Hint {
+    Hint::new(
+        String::from("felt252_dict_init_from_scope"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let initial_dict = exec_scopes
+                .get_ref::<Vec<(Felt252, MaybeRelocatable)>>(INIT_KEY)?
+                .iter()
+                .map(|(key, value)| (DictKey::Simple(MaybeRelocatable::Int(*key)), value.clone()))
+                .collect::<HashMap<DictKey, MaybeRelocatable>>();
+
+            let base = exec_scopes.get_dict_manager()?.borrow_mut().new_dict(vm, initial_dict)?;
+            insert_value_from_var_name("dict_ptr", base, vm, ids_data, ap_tracking)
+        },
+    )
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigInt;
+
+use crate::vm::hints::Hint;
+
+pub const HINTS: &[fn() -> Hint] = &[is_felt_negative, felt_abs, felt_min, is_felt_le, is_felt_ge];
+
+/// Writes 1 to `ids.is_neg` if `ids.value` is negative, i.e. greater than `PRIME / 2`, and 0
+/// otherwise.
+pub fn is_felt_negative() -> Hint {
+    Hint::new(
+        String::from("is_felt_negative"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let value = get_integer_from_var_name("value", vm, ids_data, ap_tracking)?;
+            let is_neg = if is_negative(&value) { Felt252::ONE } else { Felt252::ZERO };
+            insert_value_from_var_name("is_neg", is_neg, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes the absolute value of `ids.value` to `ids.abs`, and its sign to `ids.sign`: 0 if the
+/// value is positive, 1 if it is negative.
+pub fn felt_abs() -> Hint {
+    Hint::new(
+        String::from("felt_abs"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let value = get_integer_from_var_name("value", vm, ids_data, ap_tracking)?;
+            let (abs, sign) =
+                if is_negative(&value) { (-value, Felt252::ONE) } else { (value, Felt252::ZERO) };
+            insert_value_from_var_name("abs", abs, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name("sign", sign, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes the minimum of `ids.a` and `ids.b` to `ids.min`, comparing them as signed values.
+pub fn felt_min() -> Hint {
+    Hint::new(
+        String::from("felt_min"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let a = get_integer_from_var_name("a", vm, ids_data, ap_tracking)?;
+            let b = get_integer_from_var_name("b", vm, ids_data, ap_tracking)?;
+            let min = if signed_value(&a) <= signed_value(&b) { a } else { b };
+            insert_value_from_var_name("min", min, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes 1 to `ids.is_le` if `ids.a <= ids.b` as signed values, and 0 otherwise.
+pub fn is_felt_le() -> Hint {
+    Hint::new(
+        String::from("is_felt_le"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let a = get_integer_from_var_name("a", vm, ids_data, ap_tracking)?;
+            let b = get_integer_from_var_name("b", vm, ids_data, ap_tracking)?;
+            let is_le =
+                if signed_value(&a) <= signed_value(&b) { Felt252::ONE } else { Felt252::ZERO };
+            insert_value_from_var_name("is_le", is_le, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes 1 to `ids.is_ge` if `ids.a >= ids.b` as signed values, and 0 otherwise.
+pub fn is_felt_ge() -> Hint {
+    Hint::new(
+        String::from("is_felt_ge"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let a = get_integer_from_var_name("a", vm, ids_data, ap_tracking)?;
+            let b = get_integer_from_var_name("b", vm, ids_data, ap_tracking)?;
+            let is_ge =
+                if signed_value(&a) >= signed_value(&b) { Felt252::ONE } else { Felt252::ZERO };
+            insert_value_from_var_name("is_ge", is_ge, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Returns whether `value` is negative, i.e. greater than `PRIME / 2`.
+fn is_negative(value: &Felt252) -> bool {
+    *value > Felt252::from(Felt252::MAX.to_biguint() / 2_u32)
+}
+
+/// Returns the signed value of `value`, in `[-PRIME / 2, PRIME / 2]`.
+fn signed_value(value: &Felt252) -> BigInt {
+    if is_negative(value) {
+        -BigInt::from((-*value).to_biguint())
+    } else {
+        BigInt::from(value.to_biguint())
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+
+use crate::vm::hints::Hint;
+
+pub const HINTS: &[fn() -> Hint] = &[evm_opcode_gas_cost, evm_opcode_is_static_gas];
+
+const GAS_ZERO: u64 = 0;
+const GAS_JUMPDEST: u64 = 1;
+const GAS_BASE: u64 = 2;
+const GAS_VERY_LOW: u64 = 3;
+const GAS_LOW: u64 = 5;
+const GAS_MID: u64 = 8;
+const GAS_HIGH: u64 = 10;
+const GAS_EXPONENTIATION: u64 = 10;
+const GAS_BLOCK_HASH: u64 = 20;
+const GAS_KECCAK256: u64 = 30;
+const GAS_WARM_ACCESS: u64 = 100;
+const GAS_LOG: u64 = 375;
+const GAS_LOG_TOPIC: u64 = 375;
+const GAS_SELF_DESTRUCT: u64 = 5000;
+const GAS_CREATE: u64 = 32000;
+
+/// Base gas cost of each EVM opcode, as of Cancun. For opcodes with a dynamic cost, this is the
+/// part of the cost charged regardless of the operands, e.g. the warm access cost of `SLOAD` or
+/// the topics cost of `LOG2`. Undefined opcodes cost 0.
+const GAS_TABLE: [u64; 256] = build_gas_table();
+
+const fn build_gas_table() -> [u64; 256] {
+    let mut table = [GAS_ZERO; 256];
+
+    // 0x00 - 0x0B: stop and arithmetic operations
+    table[0x01] = GAS_VERY_LOW; // ADD
+    table[0x02] = GAS_LOW; // MUL
+    table[0x03] = GAS_VERY_LOW; // SUB
+    table[0x04] = GAS_LOW; // DIV
+    table[0x05] = GAS_LOW; // SDIV
+    table[0x06] = GAS_LOW; // MOD
+    table[0x07] = GAS_LOW; // SMOD
+    table[0x08] = GAS_MID; // ADDMOD
+    table[0x09] = GAS_MID; // MULMOD
+    table[0x0A] = GAS_EXPONENTIATION; // EXP
+    table[0x0B] = GAS_LOW; // SIGNEXTEND
+
+    // 0x10 - 0x1D: comparison and bitwise operations
+    let mut opcode = 0x10;
+    while opcode <= 0x1D {
+        table[opcode] = GAS_VERY_LOW;
+        opcode += 1;
+    }
+
+    table[0x20] = GAS_KECCAK256; // KECCAK256
+
+    // 0x30 - 0x3F: environmental information
+    table[0x30] = GAS_BASE; // ADDRESS
+    table[0x31] = GAS_WARM_ACCESS; // BALANCE
+    table[0x32] = GAS_BASE; // ORIGIN
+    table[0x33] = GAS_BASE; // CALLER
+    table[0x34] = GAS_BASE; // CALLVALUE
+    table[0x35] = GAS_VERY_LOW; // CALLDATALOAD
+    table[0x36] = GAS_BASE; // CALLDATASIZE
+    table[0x37] = GAS_VERY_LOW; // CALLDATACOPY
+    table[0x38] = GAS_BASE; // CODESIZE
+    table[0x39] = GAS_VERY_LOW; // CODECOPY
+    table[0x3A] = GAS_BASE; // GASPRICE
+    table[0x3B] = GAS_WARM_ACCESS; // EXTCODESIZE
+    table[0x3C] = GAS_WARM_ACCESS; // EXTCODECOPY
+    table[0x3D] = GAS_BASE; // RETURNDATASIZE
+    table[0x3E] = GAS_VERY_LOW; // RETURNDATACOPY
+    table[0x3F] = GAS_WARM_ACCESS; // EXTCODEHASH
+
+    // 0x40 - 0x4A: block information
+    table[0x40] = GAS_BLOCK_HASH; // BLOCKHASH
+    table[0x41] = GAS_BASE; // COINBASE
+    table[0x42] = GAS_BASE; // TIMESTAMP
+    table[0x43] = GAS_BASE; // NUMBER
+    table[0x44] = GAS_BASE; // PREVRANDAO
+    table[0x45] = GAS_BASE; // GASLIMIT
+    table[0x46] = GAS_BASE; // CHAINID
+    table[0x47] = GAS_LOW; // SELFBALANCE
+    table[0x48] = GAS_BASE; // BASEFEE
+    table[0x49] = GAS_VERY_LOW; // BLOBHASH
+    table[0x4A] = GAS_BASE; // BLOBBASEFEE
+
+    // 0x50 - 0x5F: stack, memory, storage and flow operations
+    table[0x50] = GAS_BASE; // POP
+    table[0x51] = GAS_VERY_LOW; // MLOAD
+    table[0x52] = GAS_VERY_LOW; // MSTORE
+    table[0x53] = GAS_VERY_LOW; // MSTORE8
+    table[0x54] = GAS_WARM_ACCESS; // SLOAD
+    table[0x55] = GAS_WARM_ACCESS; // SSTORE
+    table[0x56] = GAS_MID; // JUMP
+    table[0x57] = GAS_HIGH; // JUMPI
+    table[0x58] = GAS_BASE; // PC
+    table[0x59] = GAS_BASE; // MSIZE
+    table[0x5A] = GAS_BASE; // GAS
+    table[0x5B] = GAS_JUMPDEST; // JUMPDEST
+    table[0x5C] = GAS_WARM_ACCESS; // TLOAD
+    table[0x5D] = GAS_WARM_ACCESS; // TSTORE
+    table[0x5E] = GAS_VERY_LOW; // MCOPY
+    table[0x5F] = GAS_BASE; // PUSH0
+
+    // 0x60 - 0x9F: PUSH1 - PUSH32, DUP1 - DUP16 and SWAP1 - SWAP16
+    let mut opcode = 0x60;
+    while opcode <= 0x9F {
+        table[opcode] = GAS_VERY_LOW;
+        opcode += 1;
+    }
+
+    // 0xA0 - 0xA4: LOG0 - LOG4
+    let mut topics = 0;
+    while topics <= 4 {
+        table[0xA0 + topics] = GAS_LOG + GAS_LOG_TOPIC * topics as u64;
+        topics += 1;
+    }
+
+    // 0xF0 - 0xFF: system operations
+    table[0xF0] = GAS_CREATE; // CREATE
+    table[0xF1] = GAS_WARM_ACCESS; // CALL
+    table[0xF2] = GAS_WARM_ACCESS; // CALLCODE
+    table[0xF4] = GAS_WARM_ACCESS; // DELEGATECALL
+    table[0xF5] = GAS_CREATE; // CREATE2
+    table[0xFA] = GAS_WARM_ACCESS; // STATICCALL
+    table[0xFF] = GAS_SELF_DESTRUCT; // SELFDESTRUCT
+
+    table
+}
+
+/// Writes the base gas cost of the EVM opcode `ids.opcode` to `ids.base_gas`.
+pub fn evm_opcode_gas_cost() -> Hint {
+    Hint::new(
+        String::from("evm_opcode_gas_cost"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let opcode = read_opcode(vm, ids_data, ap_tracking)?;
+            let base_gas = Felt252::from(GAS_TABLE[opcode as usize]);
+            insert_value_from_var_name("base_gas", base_gas, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes 1 to `ids.is_static_gas` if the EVM opcode `ids.opcode` has a constant gas cost, and 0
+/// if its cost depends on its operands, the memory expansion or the accessed state. Undefined
+/// opcodes, which consume all the gas left, are not static.
+pub fn evm_opcode_is_static_gas() -> Hint {
+    Hint::new(
+        String::from("evm_opcode_is_static_gas"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let opcode = read_opcode(vm, ids_data, ap_tracking)?;
+            let is_static_gas = if is_static_gas(opcode) { Felt252::ONE } else { Felt252::ZERO };
+            insert_value_from_var_name("is_static_gas", is_static_gas, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+fn read_opcode(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<u8, HintError> {
+    let opcode = get_integer_from_var_name("opcode", vm, ids_data, ap_tracking)?;
+    opcode
+        .to_u8()
+        .ok_or_else(|| HintError::CustomHint(format!("Invalid opcode: {}", opcode).into()))
+}
+
+/// Returns whether `opcode` is a defined opcode whose gas cost is `GAS_TABLE[opcode]`.
+fn is_static_gas(opcode: u8) -> bool {
+    match opcode {
+        // EXP, KECCAK256
+        0x0A | 0x20 => false,
+        // BALANCE, CALLDATACOPY, CODECOPY, EXTCODESIZE, EXTCODECOPY, RETURNDATACOPY, EXTCODEHASH
+        0x31 | 0x37 | 0x39 | 0x3B | 0x3C | 0x3E | 0x3F => false,
+        // MLOAD, MSTORE, MSTORE8, SLOAD, SSTORE, MCOPY
+        0x51..=0x55 | 0x5E => false,
+        // LOG0 - LOG4
+        0xA0..=0xA4 => false,
+        // CREATE, CALL, CALLCODE, RETURN, DELEGATECALL, CREATE2, STATICCALL, REVERT, SELFDESTRUCT
+        0xF0..=0xF5 | 0xFA | 0xFD | 0xFF => false,
+        0x00..=0x0B | 0x10..=0x1D | 0x30..=0x4A | 0x50..=0x9F => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+use pyo3::prelude::*;
+use revm_precompile::Precompiles;
+
+use crate::vm::{hint_utils::write_collection_to_addr, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] = &[get_precompile_addresses];
+
+/// The Ethereum hardforks supported by the hints, passed to Cairo as their integer value.
+#[pyclass(name = "Hardfork", eq, eq_int)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PyHardfork {
+    Shanghai = 0,
+    Cancun = 1,
+    Prague = 2,
+}
+
+impl PyHardfork {
+    /// Returns the precompiled contracts active at this hardfork.
+    pub fn precompiles(self) -> &'static Precompiles {
+        match self {
+            // Shanghai has the same precompiles as Berlin.
+            PyHardfork::Shanghai => Precompiles::berlin(),
+            PyHardfork::Cancun => Precompiles::cancun(),
+            PyHardfork::Prague => Precompiles::prague(),
+        }
+    }
+}
+
+impl TryFrom<&Felt252> for PyHardfork {
+    type Error = HintError;
+
+    fn try_from(value: &Felt252) -> Result<Self, Self::Error> {
+        match value.to_u8() {
+            Some(0) => Ok(PyHardfork::Shanghai),
+            Some(1) => Ok(PyHardfork::Cancun),
+            Some(2) => Ok(PyHardfork::Prague),
+            _ => Err(HintError::CustomHint(format!("Unknown hardfork: {}", value).into())),
+        }
+    }
+}
+
+/// Writes the addresses of the precompiled contracts of the hardfork `ids.hardfork`, in
+/// increasing order, to a new segment whose base is written to `ids.precompiles`, and their count
+/// to `ids.precompiles_len`.
+///
+/// Like the addresses hashed in dicts, each address is encoded as the little-endian felt of its 20
+/// bytes, e.g. `0x01` is `0x0100000000000000000000000000000000000000`.
+pub fn get_precompile_addresses() -> Hint {
+    Hint::new(
+        String::from("get_precompile_addresses"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let hardfork = get_integer_from_var_name("hardfork", vm, ids_data, ap_tracking)?;
+            let hardfork = PyHardfork::try_from(&hardfork)?;
+
+            let mut addresses = hardfork.precompiles().addresses().collect::<Vec<_>>();
+            addresses.sort();
+            let addresses = addresses
+                .into_iter()
+                .map(|address| Felt252::from_bytes_le_slice(address.as_slice()))
+                .collect::<Vec<_>>();
+
+            let precompiles = vm.add_memory_segment();
+            write_collection_to_addr(precompiles, &addresses, vm)?;
+            insert_value_from_var_name("precompiles", precompiles, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name(
+                "precompiles_len",
+                Felt252::from(addresses.len()),
+                vm,
+                ids_data,
+                ap_tracking,
+            )
+        },
+    )
+}
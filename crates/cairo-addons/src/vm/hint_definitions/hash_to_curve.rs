@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_integer_from_var_name, get_ptr_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{errors::math_errors::MathError, exec_scope::ExecutionScopes},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+
+use crate::vm::{
+    hint_utils::{write_collection_from_var_name, Uint384},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[bls12_381_hash_to_g2];
+
+/// Size in bytes of a serialized BLS12-381 base field element.
+const FP_SIZE: usize = 48;
+
+/// Hashes a message to a point of G2 following the hash_to_curve spec (RFC 9380),
+/// i.e. `expand_message_xmd` + `map_to_curve` + `clear_cofactor`.
+///
+/// The resulting affine point is written to `ids.result` as a `cairo_ec.curve.g2_point.G2Point`,
+/// where `x = a0 * i + b0` and `y = a1 * i + b1`, each coefficient being a `UInt384`.
+pub fn bls12_381_hash_to_g2() -> Hint {
+    Hint::new(
+        String::from("bls12_381_hash_to_g2"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let message = read_bytes("message_ptr", "message_len", vm, ids_data, ap_tracking)?;
+            let dst = read_bytes("dst_ptr", "dst_len", vm, ids_data, ap_tracking)?;
+
+            let mut point = blst::blst_p2::default();
+            let mut affine = blst::blst_p2_affine::default();
+            let mut serialized = [0u8; 4 * FP_SIZE];
+            // SAFETY:
+            // - `blst_hash_to_g2` only reads `message.len()` bytes from `message.as_ptr()` and
+            //   `dst.len()` bytes from `dst.as_ptr()`, both borrowed from live `Vec`s, and the
+            //   augmentation string is empty, for which a null pointer with length 0 is accepted.
+            // - `point` and `affine` are initialized, exclusively borrowed blst values, so the out
+            //   pointers are valid for writes and don't alias the inputs.
+            // - `blst_p2_affine_serialize` writes the uncompressed encoding, i.e. exactly `4 *
+            //   FP_SIZE` (192) bytes, which is the size of `serialized`.
+            unsafe {
+                blst::blst_hash_to_g2(
+                    &mut point,
+                    message.as_ptr(),
+                    message.len(),
+                    dst.as_ptr(),
+                    dst.len(),
+                    std::ptr::null(),
+                    0,
+                );
+                blst::blst_p2_to_affine(&mut affine, &point);
+                blst::blst_p2_affine_serialize(serialized.as_mut_ptr(), &affine);
+            }
+
+            // Uncompressed serialization is x.c1 || x.c0 || y.c1 || y.c0, big-endian.
+            let limbs = serialized
+                .chunks(FP_SIZE)
+                .flat_map(|chunk| Uint384::split(&BigUint::from_bytes_be(chunk)).limbs)
+                .map(std::borrow::Cow::into_owned)
+                .collect::<Vec<Felt252>>();
+
+            write_collection_from_var_name("result", &limbs, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Reads `len_name` bytes, stored one per felt, starting at the pointer `ptr_name`.
+fn read_bytes(
+    ptr_name: &str,
+    len_name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<Vec<u8>, HintError> {
+    let ptr = get_ptr_from_var_name(ptr_name, vm, ids_data, ap_tracking)?;
+    let len_felt = get_integer_from_var_name(len_name, vm, ids_data, ap_tracking)?;
+    let len: usize =
+        len_felt.try_into().map_err(|_| MathError::Felt252ToUsizeConversion(Box::new(len_felt)))?;
+
+    vm.get_integer_range(ptr, len)?
+        .into_iter()
+        .map(|byte| {
+            byte.to_u8().ok_or_else(|| {
+                HintError::CustomHint(Box::from(format!("{ptr_name} contains a non-byte value")))
+            })
+        })
+        .collect()
+}
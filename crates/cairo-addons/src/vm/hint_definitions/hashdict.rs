@@ -1,10 +1,10 @@
-use std::collections::HashMap;
+use std::{cmp::Ordering, collections::HashMap};
 
 use cairo_vm::{
     hint_processor::{
         builtin_hint_processor::{
             dict_hint_utils::DICT_ACCESS_SIZE,
-            dict_manager::DictKey,
+            dict_manager::{DictKey, DictTracker, Dictionary},
             hint_utils::{
                 get_integer_from_var_name, get_maybe_relocatable_from_var_name,
                 get_ptr_from_var_name, insert_value_from_var_name,
@@ -25,14 +25,20 @@ use cairo_vm::{
 use num_traits::Zero;
 use starknet_crypto::poseidon_hash_many;
 
-use crate::vm::hints::Hint;
+use super::PreimageRegistry;
+use crate::vm::{hint_utils::read_usize_from_var_name, hints::Hint};
 
 pub const HINTS: &[fn() -> Hint] = &[
     hashdict_read,
     hashdict_write,
     hashdict_read_from_key,
+    hashdict_delete,
+    hashdict_contains,
+    hashdict_merge,
+    hashdict_len,
     get_preimage_for_key,
     copy_hashdict_tracker_entry,
+    copy_hashdict_tracker_range,
     get_keys_for_address_prefix,
     get_storage_keys_for_address,
 ];
@@ -48,6 +54,7 @@ pub fn hashdict_read() -> Hint {
          -> Result<(), HintError> {
             // Get dictionary pointer and setup tracker
             let dict_ptr = get_ptr_from_var_name("dict_ptr", vm, ids_data, ap_tracking)?;
+            let registry = PreimageRegistry::from_exec_scopes(exec_scopes);
             let dict_manager_ref = exec_scopes.get_dict_manager()?;
             let mut dict_manager = dict_manager_ref.borrow_mut();
             let tracker = dict_manager.get_tracker_mut(dict_ptr)?;
@@ -61,15 +68,11 @@ pub fn hashdict_read() -> Hint {
                 .map_err(|_| MathError::Felt252ToUsizeConversion(Box::new(key_len_felt)))?;
 
             // Build and process compound key
-            let dict_key = build_compound_key(vm, &key, key_len)?;
+            let dict_key = build_compound_key(vm, &key, key_len, &mut registry.borrow_mut())?;
 
             tracker.get_value(&dict_key).and_then(|value| {
                 insert_value_from_var_name("value", value.clone(), vm, ids_data, ap_tracking)
-            })?;
-
-            let hashed_key = compute_hash_key(&dict_key, key_len);
-            dict_manager.preimages.insert(hashed_key.into(), dict_key);
-            Ok(())
+            })
         },
     )
 }
@@ -85,6 +88,7 @@ pub fn hashdict_write() -> Hint {
          -> Result<(), HintError> {
             // Get dictionary pointer and setup tracker
             let dict_ptr = get_ptr_from_var_name("dict_ptr", vm, ids_data, ap_tracking)?;
+            let registry = PreimageRegistry::from_exec_scopes(exec_scopes);
             let dict_manager_ref = exec_scopes.get_dict_manager()?;
             let mut dict_manager = dict_manager_ref.borrow_mut();
             let tracker = dict_manager.get_tracker_mut(dict_ptr)?;
@@ -98,7 +102,7 @@ pub fn hashdict_write() -> Hint {
                 .map_err(|_| MathError::Felt252ToUsizeConversion(Box::new(key_len_felt)))?;
 
             // Build compound key and get new value
-            let dict_key = build_compound_key(vm, &key, key_len)?;
+            let dict_key = build_compound_key(vm, &key, key_len, &mut registry.borrow_mut())?;
             let new_value =
                 get_maybe_relocatable_from_var_name("new_value", vm, ids_data, ap_tracking)?;
             let dict_ptr_prev_value = (dict_ptr + 1_i32)?;
@@ -110,14 +114,141 @@ pub fn hashdict_write() -> Hint {
                 })
             })?;
             tracker.insert_value(&dict_key, &new_value);
+            Ok(())
+        },
+    )
+}
+
+/// Removes the compound key of `ids.key_len` elements at `ids.key` from the dict tracker of
+/// `ids.dict_ptr`. Fails if the key is not in the dictionary, leaving the tracker unchanged.
+pub fn hashdict_delete() -> Hint {
+    Hint::new(
+        String::from("hashdict_delete"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            // Get dictionary pointer and setup tracker
+            let dict_ptr = get_ptr_from_var_name("dict_ptr", vm, ids_data, ap_tracking)?;
+            let registry = PreimageRegistry::from_exec_scopes(exec_scopes);
+            let dict_manager_ref = exec_scopes.get_dict_manager()?;
+            let mut dict_manager = dict_manager_ref.borrow_mut();
+            let tracker = dict_manager.get_tracker_mut(dict_ptr)?;
+
+            let key = get_ptr_from_var_name("key", vm, ids_data, ap_tracking)?;
+            let key_len_felt: Felt252 =
+                get_integer_from_var_name("key_len", vm, ids_data, ap_tracking)?;
+            let key_len: usize = key_len_felt
+                .try_into()
+                .map_err(|_| MathError::Felt252ToUsizeConversion(Box::new(key_len_felt)))?;
+
+            // Build compound key and remove it from the underlying dictionary
+            let dict_key = build_compound_key(vm, &key, key_len, &mut registry.borrow_mut())?;
+            let dict = match &mut tracker.data {
+                Dictionary::SimpleDictionary(dict) => dict,
+                Dictionary::DefaultDictionary { dict, .. } => dict,
+            };
+            dict.remove(&dict_key).ok_or_else(|| {
+                HintError::CustomHint(format!("No value found for preimage {}", dict_key).into())
+            })?;
+
+            // Only a successful delete is a dict access.
+            tracker.current_ptr.offset += DICT_ACCESS_SIZE;
+            Ok(())
+        },
+    )
+}
+
+/// Writes 1 to `ids.result` if the compound key of `ids.key_len` elements at `ids.key` is in the
+/// dict tracker of `ids.dict_ptr`, and 0 otherwise. Unlike `hashdict_read`, this is not a dict
+/// access: the tracker pointer is not advanced.
+pub fn hashdict_contains() -> Hint {
+    Hint::new(
+        String::from("hashdict_contains"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            // Get dictionary tracker
+            let dict_ptr = get_ptr_from_var_name("dict_ptr", vm, ids_data, ap_tracking)?;
+            let registry = PreimageRegistry::from_exec_scopes(exec_scopes);
+            let dict_manager_ref = exec_scopes.get_dict_manager()?;
+            let dict_manager = dict_manager_ref.borrow();
+            let tracker = dict_manager.get_tracker(dict_ptr)?;
+
+            let key = get_ptr_from_var_name("key", vm, ids_data, ap_tracking)?;
+            let key_len_felt: Felt252 =
+                get_integer_from_var_name("key_len", vm, ids_data, ap_tracking)?;
+            let key_len: usize = key_len_felt
+                .try_into()
+                .map_err(|_| MathError::Felt252ToUsizeConversion(Box::new(key_len_felt)))?;
+
+            let dict_key = build_compound_key(vm, &key, key_len, &mut registry.borrow_mut())?;
+            let result = if tracker.get_dictionary_ref().contains_key(&dict_key) {
+                Felt252::ONE
+            } else {
+                Felt252::ZERO
+            };
+            insert_value_from_var_name("result", result, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Copies all the entries of the dict tracker of `ids.src_dict_ptr` into the dict tracker of
+/// `ids.dst_dict_ptr`, overwriting the entries of the destination with the same keys. The
+/// destination pointer is advanced by one dict access per copied entry.
+pub fn hashdict_merge() -> Hint {
+    Hint::new(
+        String::from("hashdict_merge"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let src_dict_ptr = get_ptr_from_var_name("src_dict_ptr", vm, ids_data, ap_tracking)?;
+            let dst_dict_ptr = get_ptr_from_var_name("dst_dict_ptr", vm, ids_data, ap_tracking)?;
+            let dict_manager_ref = exec_scopes.get_dict_manager()?;
+            let mut dict_manager = dict_manager_ref.borrow_mut();
 
-            let hashed_key = compute_hash_key(&dict_key, key_len);
-            dict_manager.preimages.insert(hashed_key.into(), dict_key);
+            let entries = dict_manager.get_tracker(src_dict_ptr)?.get_dictionary_copy();
+            let dst_tracker = dict_manager.get_tracker_mut(dst_dict_ptr)?;
+            dst_tracker.current_ptr.offset += entries.len() * DICT_ACCESS_SIZE;
+            for (key, value) in entries.iter() {
+                dst_tracker.insert_value(key, value);
+            }
             Ok(())
         },
     )
 }
 
+/// Writes the number of entries of the dict tracker of `ids.dict_ptr` to `ids.len`. Like
+/// `hashdict_contains`, this is not a dict access: the tracker pointer is not advanced.
+pub fn hashdict_len() -> Hint {
+    Hint::new(
+        String::from("hashdict_len"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let dict_ptr = get_ptr_from_var_name("dict_ptr", vm, ids_data, ap_tracking)?;
+            let dict_manager_ref = exec_scopes.get_dict_manager()?;
+            let dict_manager = dict_manager_ref.borrow();
+            let len = dict_manager.get_tracker(dict_ptr)?.get_dictionary_ref().len();
+            insert_value_from_var_name("len", Felt252::from(len), vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes the keys of `ids.dict_ptr` whose preimage starts with the `ids.prefix_len` felts at
+/// `ids.prefix` to `ids.keys` and their count to `ids.keys_len`. If the optional `ids.sorted` is 1,
+/// the keys are sorted lexicographically by preimage.
 pub fn get_keys_for_address_prefix() -> Hint {
     Hint::new(
         String::from("get_keys_for_address_prefix"),
@@ -151,7 +282,7 @@ pub fn get_keys_for_address_prefix() -> Hint {
                 .collect::<Result<_, _>>()?;
 
             // Find matching preimages
-            let matching_preimages: Vec<&Vec<MaybeRelocatable>> = tracker
+            let mut matching_preimages: Vec<&Vec<MaybeRelocatable>> = tracker
                 .get_dictionary_ref()
                 .keys()
                 .filter_map(|key| {
@@ -168,6 +299,14 @@ pub fn get_keys_for_address_prefix() -> Hint {
                 })
                 .collect();
 
+            // Sort the preimages lexicographically if requested with the optional `ids.sorted`, as
+            // the iteration order of the tracker is not deterministic.
+            let sorted = ids_data.contains_key("sorted") &&
+                get_integer_from_var_name("sorted", vm, ids_data, ap_tracking)? == Felt252::ONE;
+            if sorted {
+                matching_preimages.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            }
+
             // Allocate memory segments and write results
             let base = vm.add_memory_segment();
             for (i, preimage) in matching_preimages.iter().enumerate() {
@@ -315,17 +454,20 @@ pub fn hashdict_read_from_key() -> Hint {
 
             // Get dictionary tracker
             let dict_ptr = get_ptr_from_var_name("dict_ptr_stop", vm, ids_data, ap_tracking)?;
+            let registry = PreimageRegistry::from_exec_scopes(exec_scopes);
             let dict_manager_ref = exec_scopes.get_dict_manager()?;
             let mut dict_manager = dict_manager_ref.borrow_mut();
-            let preimages = &dict_manager.preimages.clone();
+            let preimage = _get_preimage_for_hashed_key(
+                hashed_key,
+                &registry.borrow(),
+                &dict_manager.preimages,
+                Some(dict_manager.get_tracker(dict_ptr)?),
+            );
             let tracker = dict_manager.get_tracker_mut(dict_ptr)?;
 
             // Find matching preimage and get its value. This hint can also be called on non-hashed
             // keys.
-            let simple_key = DictKey::Simple(hashed_key.into());
-            let preimage = _get_preimage_for_hashed_key(hashed_key.into(), preimages)
-                .unwrap_or(&simple_key)
-                .clone();
+            let preimage = preimage.unwrap_or(DictKey::Simple(hashed_key.into()));
             let value = tracker
                 .get_value(&preimage)
                 .map_err(|_| {
@@ -354,12 +496,17 @@ pub fn get_preimage_for_key() -> Hint {
             let hashed_key = get_integer_from_var_name("key", vm, ids_data, ap_tracking)?;
 
             // Get dictionary tracker
+            let registry = PreimageRegistry::from_exec_scopes(exec_scopes);
             let dict_manager_ref = exec_scopes.get_dict_manager()?;
             let dict_manager = dict_manager_ref.borrow();
-            let preimages = &dict_manager.preimages;
 
             // Find matching preimage
-            let preimage = _get_preimage_for_hashed_key(hashed_key.into(), preimages)?;
+            let preimage = _get_preimage_for_hashed_key(
+                hashed_key,
+                &registry.borrow(),
+                &dict_manager.preimages,
+                None,
+            )?;
 
             // Write preimage data to memory
             let preimage_data_ptr =
@@ -396,15 +543,20 @@ pub fn copy_hashdict_tracker_entry() -> Hint {
             let source_ptr_stop =
                 get_ptr_from_var_name("source_ptr_stop", vm, ids_data, ap_tracking)?;
             let dest_ptr = get_ptr_from_var_name("dest_ptr", vm, ids_data, ap_tracking)?;
+            let registry = PreimageRegistry::from_exec_scopes(exec_scopes);
             let dict_manager_ref = exec_scopes.get_dict_manager()?;
             let mut dict_manager = dict_manager_ref.borrow_mut();
-            let preimages = &dict_manager.preimages.clone();
-
-            let source_tracker = dict_manager.get_tracker_mut(source_ptr_stop)?;
 
             // Find matching preimage from source tracker data
             let key_hash = get_integer_from_var_name("source_key", vm, ids_data, ap_tracking)?;
-            let preimage = _get_preimage_for_hashed_key(key_hash.into(), preimages)?.clone();
+            let preimage = _get_preimage_for_hashed_key(
+                key_hash,
+                &registry.borrow(),
+                &dict_manager.preimages,
+                Some(dict_manager.get_tracker(source_ptr_stop)?),
+            )?;
+
+            let source_tracker = dict_manager.get_tracker_mut(source_ptr_stop)?;
             let value = source_tracker
                 .get_value(&preimage)
                 .map_err(|_| {
@@ -424,30 +576,120 @@ pub fn copy_hashdict_tracker_entry() -> Hint {
     )
 }
 
-fn build_compound_key(
+/// Copies all the entries of the tracker of `ids.source_ptr_stop` whose compound key starts with
+/// the `ids.prefix_len` felts at `ids.prefix_ptr` to the tracker of `ids.dest_ptr`, e.g. all the
+/// storage slots of an account, and writes their count to `ids.count`.
+///
+/// The current pointer of the destination tracker is advanced by one dict access per copied entry.
+pub fn copy_hashdict_tracker_range() -> Hint {
+    Hint::new(
+        String::from("copy_hashdict_tracker_range"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let source_ptr_stop =
+                get_ptr_from_var_name("source_ptr_stop", vm, ids_data, ap_tracking)?;
+            let dest_ptr = get_ptr_from_var_name("dest_ptr", vm, ids_data, ap_tracking)?;
+            let prefix_len = read_usize_from_var_name("prefix_len", vm, ids_data, ap_tracking)?;
+            let prefix = if prefix_len == 0 {
+                vec![]
+            } else {
+                let prefix_ptr = get_ptr_from_var_name("prefix_ptr", vm, ids_data, ap_tracking)?;
+                vm.get_continuous_range(prefix_ptr, prefix_len)?
+            };
+
+            let dict_manager_ref = exec_scopes.get_dict_manager()?;
+            let mut dict_manager = dict_manager_ref.borrow_mut();
+            let entries = dict_manager
+                .get_tracker(source_ptr_stop)?
+                .get_dictionary_ref()
+                .iter()
+                .filter(|(key, _)| match key {
+                    DictKey::Compound(values) => values.starts_with(&prefix),
+                    DictKey::Simple(_) => false,
+                })
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect::<Vec<_>>();
+
+            let dest_tracker = dict_manager.get_tracker_mut(dest_ptr)?;
+            dest_tracker.current_ptr.offset += entries.len() * DICT_ACCESS_SIZE;
+            for (key, value) in entries.iter() {
+                dest_tracker.insert_value(key, value);
+            }
+
+            insert_value_from_var_name(
+                "count",
+                Felt252::from(entries.len()),
+                vm,
+                ids_data,
+                ap_tracking,
+            )
+        },
+    )
+}
+
+/// Reads the compound key of `key_len` elements at `key`, and registers it as the preimage of its
+/// hash in `registry`, the single store of the preimages of the hashdict hints.
+pub fn build_compound_key(
     vm: &VirtualMachine,
     key: &cairo_vm::types::relocatable::Relocatable,
     key_len: usize,
+    registry: &mut PreimageRegistry,
 ) -> Result<DictKey, HintError> {
-    (0..key_len)
+    let values = (0..key_len)
         .map(|i| {
             let mem_addr = (*key + i)?;
             vm.get_maybe(&mem_addr).ok_or_else(|| {
                 HintError::Memory(MemoryError::UnknownMemoryCell(Box::from(mem_addr)))
             })
         })
-        .collect::<Result<Vec<_>, _>>()
-        .map(DictKey::Compound)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (dict_key, hashed_key) = compound_key_from_values(values)?;
+    if let DictKey::Compound(values) = &dict_key {
+        registry.register(hashed_key, values);
+    }
+    Ok(dict_key)
+}
+
+/// Builds the compound key made of `values`, and returns it along with its hash: the value itself
+/// for single-element keys, and the Poseidon hash of the values otherwise. All the values must be
+/// integers.
+pub fn compound_key_from_values(
+    values: Vec<MaybeRelocatable>,
+) -> Result<(DictKey, Felt252), HintError> {
+    if let Some(value) = values.iter().find(|value| value.get_int().is_none()) {
+        return Err(HintError::CustomHint(
+            format!("Compound key values must be integers, got {}", value).into(),
+        ));
+    }
+    let key_len = values.len();
+    let dict_key = DictKey::Compound(values);
+    let hashed_key = compute_hash_key(&dict_key, key_len);
+    Ok((dict_key, hashed_key))
 }
 
-/// Helper function to find a preimage in a tracker's dictionary given a hashed key
+/// Helper function to find the preimage of a hashed key, looking it up in the preimage registry
+/// first, then in the preimages of the dict manager.
+///
+/// If `tracker` holds `hashed_key` itself as a simple key, e.g. written with `dict_write` rather
+/// than through a compound key, that simple key is returned instead.
 fn _get_preimage_for_hashed_key(
-    hashed_key: MaybeRelocatable,
+    hashed_key: Felt252,
+    registry: &PreimageRegistry,
     preimages: &HashMap<MaybeRelocatable, DictKey>,
-) -> Result<&DictKey, HintError> {
-    preimages.get(&hashed_key).ok_or_else(|| {
-        HintError::CustomHint(format!("No preimage found for hashed key {}", hashed_key).into())
-    })
+    tracker: Option<&DictTracker>,
+) -> Result<DictKey, HintError> {
+    let simple_key = DictKey::Simple(hashed_key.into());
+    if tracker.is_some_and(|tracker| tracker.get_dictionary_ref().contains_key(&simple_key)) {
+        return Ok(simple_key);
+    }
+    registry.get(&hashed_key).or_else(|| preimages.get(&hashed_key.into()).cloned()).ok_or_else(
+        || HintError::CustomHint(format!("No preimage found for hashed key {}", hashed_key).into()),
+    )
 }
 
 /// Helper function to compute the hash key from a DictKey
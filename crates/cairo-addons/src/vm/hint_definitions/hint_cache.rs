@@ -0,0 +1,142 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use cairo_vm::{types::exec_scope::ExecutionScopes, vm::errors::hint_errors::HintError, Felt252};
+use starknet_crypto::poseidon_hash_many;
+
+/// Name of the execution scope variable holding the shared `HintCache`.
+const HINT_CACHE_SCOPE_KEY: &str = "__hint_cache__";
+
+/// Number of bytes packed in each felt when hashing a cache key, so that every chunk fits in the
+/// field.
+const KEY_CHUNK_SIZE: usize = 31;
+
+/// Memoizes the results of expensive hints (e.g. pairing checks, hash block processing) that are
+/// called several times with identical inputs during a run.
+///
+/// Entries are keyed by the Poseidon hash of `len(hint_name) || hint_name || input`.
+#[derive(Debug, Default)]
+pub struct HintCache {
+    entries: HashMap<Felt252, Vec<u8>>,
+}
+
+impl HintCache {
+    /// Builds the cache key of a hint invocation from the hint name and its serialized input.
+    /// The name is length-prefixed so that distinct `(hint_name, input)` pairs never share a key.
+    pub fn key(hint_name: &str, input: &[u8]) -> Vec<u8> {
+        [&(hint_name.len() as u64).to_be_bytes(), hint_name.as_bytes(), input].concat()
+    }
+
+    /// Runs `compute` for the invocation of `hint_name` on `input`, reusing the result of a
+    /// previous identical invocation stored in the cache of `exec_scopes`.
+    pub fn memoize(
+        exec_scopes: &mut ExecutionScopes,
+        hint_name: &str,
+        input: &[u8],
+        compute: impl FnOnce() -> Result<Vec<u8>, HintError>,
+    ) -> Result<Vec<u8>, HintError> {
+        let cache = Self::from_exec_scopes(exec_scopes);
+        let result = cache.borrow_mut().get_or_compute(&Self::key(hint_name, input), compute);
+        result
+    }
+
+    /// Returns the cached result for `key`, or runs `compute` and caches its result.
+    /// Errors returned by `compute` are not cached.
+    pub fn get_or_compute(
+        &mut self,
+        key: &[u8],
+        compute: impl FnOnce() -> Result<Vec<u8>, HintError>,
+    ) -> Result<Vec<u8>, HintError> {
+        let hashed_key = hash_key(key);
+        if let Some(result) = self.entries.get(&hashed_key) {
+            return Ok(result.clone());
+        }
+
+        let result = compute()?;
+        self.entries.insert(hashed_key, result.clone());
+        Ok(result)
+    }
+
+    /// Returns the cache stored in the execution scopes, creating it in the main scope if it
+    /// doesn't exist yet so that it outlives the scope of the hint that first used it.
+    pub fn from_exec_scopes(exec_scopes: &mut ExecutionScopes) -> Rc<RefCell<HintCache>> {
+        if let Ok(cache) = exec_scopes.get::<Rc<RefCell<HintCache>>>(HINT_CACHE_SCOPE_KEY) {
+            return cache;
+        }
+
+        let cache = Rc::new(RefCell::new(HintCache::default()));
+        exec_scopes.data[0].insert(HINT_CACHE_SCOPE_KEY.to_string(), Box::new(cache.clone()));
+        cache
+    }
+}
+
+/// Hashes a cache key with Poseidon, packing it big-endian in 31-byte chunks. The key length is
+/// appended to avoid collisions between keys differing only by leading zero bytes.
+fn hash_key(key: &[u8]) -> Felt252 {
+    let mut felts =
+        key.chunks(KEY_CHUNK_SIZE).map(Felt252::from_bytes_be_slice).collect::<Vec<_>>();
+    felts.push(Felt252::from(key.len()));
+    poseidon_hash_many(&felts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_or_compute_reuses_cached_result() {
+        let mut cache = HintCache::default();
+        let key = HintCache::key("hint", &[1, 2, 3]);
+
+        let result = cache.get_or_compute(&key, || Ok(vec![42]));
+        assert_eq!(result.unwrap(), vec![42]);
+        let result = cache.get_or_compute(&key, || panic!("result should be cached"));
+        assert_eq!(result.unwrap(), vec![42]);
+    }
+
+    #[test]
+    fn test_get_or_compute_does_not_cache_errors() {
+        let mut cache = HintCache::default();
+        let key = HintCache::key("hint", &[1, 2, 3]);
+
+        let result = cache.get_or_compute(&key, || Err(HintError::CustomHint("failed".into())));
+        assert!(result.is_err());
+        let result = cache.get_or_compute(&key, || Ok(vec![42]));
+        assert_eq!(result.unwrap(), vec![42]);
+    }
+
+    #[test]
+    fn test_key_separates_hint_name_and_input() {
+        assert_ne!(hash_key(&HintCache::key("ab", b"c")), hash_key(&HintCache::key("a", b"bc")));
+        assert_ne!(hash_key(&HintCache::key("a", &[])), hash_key(&HintCache::key("", b"a")));
+    }
+
+    #[test]
+    fn test_hash_key_distinguishes_leading_zeros() {
+        assert_ne!(hash_key(&[1]), hash_key(&[0, 1]));
+        assert_ne!(hash_key(&[0; KEY_CHUNK_SIZE]), hash_key(&[0; KEY_CHUNK_SIZE + 1]));
+    }
+
+    #[test]
+    fn test_from_exec_scopes_outlives_inner_scopes() {
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.enter_scope(HashMap::new());
+        let cache = HintCache::from_exec_scopes(&mut exec_scopes);
+        exec_scopes.exit_scope().unwrap();
+
+        assert!(Rc::ptr_eq(&cache, &HintCache::from_exec_scopes(&mut exec_scopes)));
+    }
+
+    #[test]
+    fn test_memoize_shares_results_across_calls() {
+        let mut exec_scopes = ExecutionScopes::new();
+        let result = HintCache::memoize(&mut exec_scopes, "hint", &[1], || Ok(vec![42]));
+        assert_eq!(result.unwrap(), vec![42]);
+
+        let result = HintCache::memoize(&mut exec_scopes, "hint", &[1], || {
+            panic!("result should be cached")
+        });
+        assert_eq!(result.unwrap(), vec![42]);
+        let result = HintCache::memoize(&mut exec_scopes, "other_hint", &[1], || Ok(vec![0]));
+        assert_eq!(result.unwrap(), vec![0]);
+    }
+}
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use revm::primitives::keccak256;
+
+use crate::vm::{
+    hint_utils::{read_usize_from_var_name, Uint256},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[compute_keccak256];
+
+/// Computes the keccak256 hash of the `ids.data_len` bytes at `ids.data_ptr`, one byte per felt,
+/// and writes its 128-bit halves to `ids.low` and `ids.high`.
+pub fn compute_keccak256() -> Hint {
+    Hint::new(
+        String::from("compute_keccak256"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let data_len = read_usize_from_var_name("data_len", vm, ids_data, ap_tracking)?;
+            let data = if data_len == 0 {
+                vec![]
+            } else {
+                let data_ptr = get_ptr_from_var_name("data_ptr", vm, ids_data, ap_tracking)?;
+                vm.get_integer_range(data_ptr, data_len)?
+                    .into_iter()
+                    .map(|byte| {
+                        byte.to_u8()
+                            .ok_or_else(|| HintError::CustomHint(Box::from("Value is not a byte")))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+            };
+
+            let hash = keccak256(data).0;
+            let Uint256 { low, high } = Uint256::split(&BigUint::from_bytes_be(&hash));
+            insert_value_from_var_name("low", low.into_owned(), vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name("high", high.into_owned(), vm, ids_data, ap_tracking)
+        },
+    )
+}
@@ -0,0 +1,165 @@
+use std::{cell::RefCell, collections::HashMap, num::NonZeroUsize, rc::Rc};
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use lru::LruCache;
+use num_traits::ToPrimitive;
+
+use crate::vm::{
+    hint_utils::{read_usize_from_var_name, write_collection_to_addr},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[kv_cache_put, kv_cache_get, kv_cache_stats];
+
+/// Name of the execution scope variable holding the shared `KvCache`.
+const KV_CACHE_SCOPE_KEY: &str = "kv_cache";
+
+/// Maximum number of entries of the `KvCache`. Least recently used entries are evicted first.
+const KV_CACHE_SIZE: usize = 1024;
+
+/// A bounded cache of byte values keyed by felts, for hints looking up values in large external
+/// data structures with high latency, e.g. preimage databases.
+#[derive(Debug)]
+pub struct KvCache {
+    entries: LruCache<Felt252, Vec<u8>>,
+    hits: u64,
+    misses: u64,
+}
+
+impl Default for KvCache {
+    fn default() -> Self {
+        Self {
+            entries: LruCache::new(NonZeroUsize::new(KV_CACHE_SIZE).unwrap()),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl KvCache {
+    /// Returns the value cached for `key`, marking it as the most recently used entry.
+    pub fn get(&mut self, key: &Felt252) -> Option<Vec<u8>> {
+        let value = self.entries.get(key).cloned();
+        if value.is_some() {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        value
+    }
+
+    /// Caches `value` for `key`, evicting the least recently used entry if the cache is full.
+    pub fn put(&mut self, key: Felt252, value: Vec<u8>) {
+        self.entries.put(key, value);
+    }
+
+    /// Returns the cache stored in the main execution scope, creating it if it doesn't exist yet
+    /// so that it outlives the scope of the hint that first used it.
+    pub fn from_exec_scopes(exec_scopes: &mut ExecutionScopes) -> Rc<RefCell<KvCache>> {
+        if let Some(cache) = exec_scopes.data[0]
+            .get(KV_CACHE_SCOPE_KEY)
+            .and_then(|cache| cache.downcast_ref::<Rc<RefCell<KvCache>>>())
+        {
+            return cache.clone();
+        }
+
+        let cache = Rc::new(RefCell::new(KvCache::default()));
+        exec_scopes.data[0].insert(KV_CACHE_SCOPE_KEY.to_string(), Box::new(cache.clone()));
+        cache
+    }
+}
+
+/// Caches the `ids.value_len` bytes at `ids.value_ptr` for `ids.key`.
+pub fn kv_cache_put() -> Hint {
+    Hint::new(
+        String::from("kv_cache_put"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let key = get_integer_from_var_name("key", vm, ids_data, ap_tracking)?;
+            let value_len = read_usize_from_var_name("value_len", vm, ids_data, ap_tracking)?;
+            let value = if value_len == 0 {
+                vec![]
+            } else {
+                let value_ptr = get_ptr_from_var_name("value_ptr", vm, ids_data, ap_tracking)?;
+                vm.get_integer_range(value_ptr, value_len)?
+                    .into_iter()
+                    .map(|byte| {
+                        byte.to_u8()
+                            .ok_or_else(|| HintError::CustomHint(Box::from("Value is not a byte")))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+            };
+            KvCache::from_exec_scopes(exec_scopes).borrow_mut().put(key, value);
+            Ok(())
+        },
+    )
+}
+
+/// Looks up `ids.key` in the cache. On a hit, the cached bytes are written to a new segment whose
+/// base is written to `ids.value_ptr`, their length to `ids.value_len`, and `ids.found` is set to
+/// 1. On a miss, `ids.value_ptr` is an empty segment, `ids.value_len` is 0 and `ids.found` is 0.
+pub fn kv_cache_get() -> Hint {
+    Hint::new(
+        String::from("kv_cache_get"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let key = get_integer_from_var_name("key", vm, ids_data, ap_tracking)?;
+            let value = KvCache::from_exec_scopes(exec_scopes).borrow_mut().get(&key);
+            let found = if value.is_some() { Felt252::ONE } else { Felt252::ZERO };
+            let value =
+                value.unwrap_or_default().into_iter().map(Felt252::from).collect::<Vec<_>>();
+
+            let value_ptr = vm.add_memory_segment();
+            write_collection_to_addr(value_ptr, &value, vm)?;
+            insert_value_from_var_name("value_ptr", value_ptr, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name(
+                "value_len",
+                Felt252::from(value.len()),
+                vm,
+                ids_data,
+                ap_tracking,
+            )?;
+            insert_value_from_var_name("found", found, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes the number of cache hits and misses of `kv_cache_get` to `ids.hits` and `ids.misses`.
+pub fn kv_cache_stats() -> Hint {
+    Hint::new(
+        String::from("kv_cache_stats"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let cache = KvCache::from_exec_scopes(exec_scopes);
+            let (hits, misses) = {
+                let cache = cache.borrow();
+                (cache.hits, cache.misses)
+            };
+            insert_value_from_var_name("hits", Felt252::from(hits), vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name("misses", Felt252::from(misses), vm, ids_data, ap_tracking)
+        },
+    )
+}
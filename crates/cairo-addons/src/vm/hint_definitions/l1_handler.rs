@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::vm::{
+    hint_utils::{compute_hash_on_elements, read_usize_from_var_name},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[compute_l1_handler_hash, verify_l1_handler_nonce];
+
+/// Version of the L1 handler transactions hashed by `compute_l1_handler_hash`.
+const L1_HANDLER_TX_VERSION: u8 = 0;
+
+/// Computes the hash of the L1 handler transaction consuming an L1 -> L2 message and writes it to
+/// `ids.message_hash`.
+///
+/// The message is sent by `ids.from_address` on L1 to the `ids.selector` entrypoint of
+/// `ids.to_address`, with the `ids.payload_len` felts at `ids.payload_ptr`. The hash is
+/// `h(["l1_handler", version, to_address, selector, h([from_address, *payload]), max_fee,
+/// chain_id, nonce])`, with `max_fee = 0` and the chain read from `ids.chain_id`.
+pub fn compute_l1_handler_hash() -> Hint {
+    Hint::new(
+        String::from("compute_l1_handler_hash"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let read = |name| get_integer_from_var_name(name, vm, ids_data, ap_tracking);
+            let from_address = read("from_address")?;
+            let to_address = read("to_address")?;
+            let selector = read("selector")?;
+            let nonce = read("nonce")?;
+            let chain_id = read("chain_id")?;
+
+            let payload_ptr = get_ptr_from_var_name("payload_ptr", vm, ids_data, ap_tracking)?;
+            let payload_len = read_usize_from_var_name("payload_len", vm, ids_data, ap_tracking)?;
+            let calldata = std::iter::once(from_address)
+                .chain(
+                    vm.get_integer_range(payload_ptr, payload_len)?
+                        .into_iter()
+                        .map(|felt| felt.into_owned()),
+                )
+                .collect::<Vec<_>>();
+
+            let message_hash = compute_hash_on_elements(&[
+                Felt252::from_bytes_be_slice(b"l1_handler"),
+                Felt252::from(L1_HANDLER_TX_VERSION),
+                to_address,
+                selector,
+                compute_hash_on_elements(&calldata),
+                Felt252::ZERO,
+                chain_id,
+                nonce,
+            ]);
+            insert_value_from_var_name("message_hash", message_hash, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Checks that the nonce of an L1 -> L2 message, `ids.nonce`, is `ids.expected_nonce`.
+pub fn verify_l1_handler_nonce() -> Hint {
+    Hint::new(
+        String::from("verify_l1_handler_nonce"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let nonce = get_integer_from_var_name("nonce", vm, ids_data, ap_tracking)?;
+            let expected_nonce =
+                get_integer_from_var_name("expected_nonce", vm, ids_data, ap_tracking)?;
+            if nonce != expected_nonce {
+                return Err(HintError::CustomHint(
+                    format!("Invalid L1 handler nonce: expected {}, got {}", expected_nonce, nonce)
+                        .into(),
+                ));
+            }
+            Ok(())
+        },
+    )
+}
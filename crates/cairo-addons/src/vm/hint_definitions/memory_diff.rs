@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::vm::{hint_utils::read_usize_from_var_name, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] = &[compare_segments];
+
+/// Compares the `ids.seg_size` cells starting at `ids.seg_a_base` and `ids.seg_b_base`.
+///
+/// Writes the `(offset, a_value, b_value)` triples of the cells that differ to a new segment,
+/// whose base is written to `ids.diff_ptr` and number of triples to `ids.diff_len`. Cells that
+/// are holes in both segments are skipped; a hole in only one of them is a difference, with the
+/// missing value written as 0.
+pub fn compare_segments() -> Hint {
+    Hint::new(
+        String::from("compare_segments"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let seg_a_base = get_ptr_from_var_name("seg_a_base", vm, ids_data, ap_tracking)?;
+            let seg_b_base = get_ptr_from_var_name("seg_b_base", vm, ids_data, ap_tracking)?;
+            let seg_size = read_usize_from_var_name("seg_size", vm, ids_data, ap_tracking)?;
+
+            let mut diff = Vec::new();
+            for offset in 0..seg_size {
+                let a_value = vm.get_maybe(&(seg_a_base + offset)?);
+                let b_value = vm.get_maybe(&(seg_b_base + offset)?);
+                if a_value == b_value {
+                    continue;
+                }
+                let hole = MaybeRelocatable::from(Felt252::ZERO);
+                diff.extend([
+                    MaybeRelocatable::from(Felt252::from(offset)),
+                    a_value.unwrap_or_else(|| hole.clone()),
+                    b_value.unwrap_or(hole),
+                ]);
+            }
+
+            let diff_ptr = vm.add_memory_segment();
+            vm.load_data(diff_ptr, &diff)?;
+            insert_value_from_var_name("diff_ptr", diff_ptr, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name(
+                "diff_len",
+                Felt252::from(diff.len() / 3),
+                vm,
+                ids_data,
+                ap_tracking,
+            )
+        },
+    )
+}
@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+
+use alloy_rlp::Header;
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{exec_scope::ExecutionScopes, relocatable::Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use revm::primitives::keccak256;
+
+use crate::vm::{
+    hint_utils::{
+        mpt::{bytes_to_nibbles, compact_to_nibble_list},
+        read_usize_from_var_name, Uint256,
+    },
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[compute_mpt_node_hash, verify_mpt_inclusion];
+
+/// Number of items of a branch node: one child per nibble, and the value.
+const BRANCH_NODE_SIZE: usize = 17;
+
+/// Number of items of a leaf or extension node: the compact-encoded path, and the value or the
+/// child.
+const LEAF_OR_EXTENSION_NODE_SIZE: usize = 2;
+
+/// Computes the keccak256 hash of the RLP-encoded Merkle Patricia Trie node made of the
+/// `ids.node_len` bytes at `ids.node_ptr`, one byte per felt, and writes its 128-bit halves to
+/// `ids.hash_low` and `ids.hash_high`.
+///
+/// The node must be a single RLP list, either a leaf or extension node whose first item is a
+/// compact-encoded path, or a branch node of 17 items.
+pub fn compute_mpt_node_hash() -> Hint {
+    Hint::new(
+        String::from("compute_mpt_node_hash"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let node =
+                read_bytes_from_var_names("node_ptr", "node_len", vm, ids_data, ap_tracking)?;
+            validate_node(&node)?;
+
+            let hash = keccak256(&node).0;
+            let Uint256 { low, high } = Uint256::split(&BigUint::from_bytes_be(&hash));
+            insert_value_from_var_name("hash_low", low.into_owned(), vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name("hash_high", high.into_owned(), vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Verifies the Merkle Patricia Trie proof that the trie of root `ids.root_low`, `ids.root_high`
+/// maps the `ids.key_len` bytes at `ids.key_ptr` to the `ids.value_len` bytes at `ids.value_ptr`.
+/// Keys are used as-is, callers are responsible for hashing them for secure tries.
+///
+/// The proof is made of the `ids.proof_len` RLP-encoded nodes of the path from the root to the
+/// value, excluding the nodes embedded in their parent, stored contiguously at `ids.proof_ptr`,
+/// each as its length followed by its bytes, one byte per felt.
+///
+/// Writes 1 to `ids.valid` and 0 to `ids.error_code` if the proof is valid, and 0 to `ids.valid`
+/// and the `MptProofError` code of the first failed check to `ids.error_code` otherwise.
+pub fn verify_mpt_inclusion() -> Hint {
+    Hint::new(
+        String::from("verify_mpt_inclusion"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let mut root = [0u8; 32];
+            let (root_high, root_low) = root.split_at_mut(16);
+            for (name, bytes) in [("root_high", root_high), ("root_low", root_low)] {
+                let limb = get_integer_from_var_name(name, vm, ids_data, ap_tracking)?;
+                let limb = limb.to_u128().ok_or_else(|| {
+                    HintError::CustomHint(format!("{} exceeds 128 bits", name).into())
+                })?;
+                bytes.copy_from_slice(&limb.to_be_bytes());
+            }
+            let key = read_bytes_from_var_names("key_ptr", "key_len", vm, ids_data, ap_tracking)?;
+            let value =
+                read_bytes_from_var_names("value_ptr", "value_len", vm, ids_data, ap_tracking)?;
+
+            let proof_len = read_usize_from_var_name("proof_len", vm, ids_data, ap_tracking)?;
+            let mut proof = Vec::with_capacity(proof_len);
+            if proof_len > 0 {
+                let mut node_ptr = get_ptr_from_var_name("proof_ptr", vm, ids_data, ap_tracking)?;
+                for _ in 0..proof_len {
+                    let node_len = vm.get_integer(node_ptr)?.into_owned();
+                    let node_len = node_len.to_usize().ok_or_else(|| {
+                        HintError::CustomHint(format!("Invalid node length: {}", node_len).into())
+                    })?;
+                    proof.push(read_bytes(vm, (node_ptr + 1)?, node_len)?);
+                    node_ptr = (node_ptr + (node_len + 1))?;
+                }
+            }
+
+            let error_code = match verify_inclusion(&root, &key, &value, &proof) {
+                Ok(()) => Felt252::ZERO,
+                Err(e) => Felt252::from(e as u8),
+            };
+            let valid = if error_code == Felt252::ZERO { Felt252::ONE } else { Felt252::ZERO };
+            insert_value_from_var_name("valid", valid, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name("error_code", error_code, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Reason for which an MPT inclusion proof is invalid, written to `ids.error_code` by
+/// `verify_mpt_inclusion`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MptProofError {
+    /// A node of the proof is not a valid leaf, extension or branch node.
+    InvalidNode = 1,
+    /// A node of the proof doesn't hash to the reference held by its parent, or the root.
+    NodeHashMismatch = 2,
+    /// The key is not in the trie: the path diverges from the key or reaches an empty branch.
+    KeyNotFound = 3,
+    /// The key is in the trie but holds a different value.
+    ValueMismatch = 4,
+    /// The proof ends before reaching the value.
+    IncompleteProof = 5,
+    /// The proof has nodes left after reaching the value.
+    UnusedProofNodes = 6,
+}
+
+/// Reference to a child node, as held by its parent.
+enum NodeReference<'a> {
+    /// The keccak256 hash of a node of at least 32 bytes, which is the next node of the proof.
+    Hash(&'a [u8]),
+    /// The encoding of a node shorter than 32 bytes, embedded in its parent.
+    Embedded(&'a [u8]),
+}
+
+/// An item of an RLP list.
+struct RlpItem<'a> {
+    list: bool,
+    payload: &'a [u8],
+    encoding: &'a [u8],
+}
+
+/// Walks the proof from `root` along the nibbles of `key`, checking each node against the
+/// reference held by its parent, until reaching the value of the key.
+fn verify_inclusion(
+    root: &[u8; 32],
+    key: &[u8],
+    value: &[u8],
+    proof: &[Vec<u8>],
+) -> Result<(), MptProofError> {
+    let key = bytes_to_nibbles(key);
+    let mut position = 0;
+    let mut nodes = proof.iter();
+    let mut reference = NodeReference::Hash(root.as_slice());
+    loop {
+        let node = match reference {
+            NodeReference::Hash(hash) => {
+                let node = nodes.next().ok_or(MptProofError::IncompleteProof)?;
+                if keccak256(node).as_slice() != hash {
+                    return Err(MptProofError::NodeHashMismatch);
+                }
+                node.as_slice()
+            }
+            NodeReference::Embedded(node) => node,
+        };
+        let items = decode_node_items(node).map_err(|_| MptProofError::InvalidNode)?;
+
+        let leaf_value = match items.as_slice() {
+            [path, child] => {
+                let (path, is_leaf) = (!path.list)
+                    .then(|| compact_to_nibble_list(path.payload))
+                    .flatten()
+                    .ok_or(MptProofError::InvalidNode)?;
+                if !key[position..].starts_with(&path) {
+                    return Err(MptProofError::KeyNotFound);
+                }
+                position += path.len();
+                if !is_leaf {
+                    reference = child_reference(child)?.ok_or(MptProofError::InvalidNode)?;
+                    None
+                } else if position != key.len() {
+                    return Err(MptProofError::KeyNotFound);
+                } else {
+                    Some(child)
+                }
+            }
+            items if items.len() == BRANCH_NODE_SIZE => {
+                if position == key.len() {
+                    Some(&items[BRANCH_NODE_SIZE - 1])
+                } else {
+                    reference = child_reference(&items[key[position] as usize])?
+                        .ok_or(MptProofError::KeyNotFound)?;
+                    position += 1;
+                    None
+                }
+            }
+            _ => return Err(MptProofError::InvalidNode),
+        };
+
+        if let Some(leaf_value) = leaf_value {
+            if leaf_value.list {
+                return Err(MptProofError::InvalidNode);
+            }
+            if leaf_value.payload != value {
+                return Err(MptProofError::ValueMismatch);
+            }
+            if nodes.next().is_some() {
+                return Err(MptProofError::UnusedProofNodes);
+            }
+            return Ok(());
+        }
+    }
+}
+
+/// Returns the reference to the child node held by `item`, or `None` for an empty child.
+fn child_reference<'a>(item: &RlpItem<'a>) -> Result<Option<NodeReference<'a>>, MptProofError> {
+    match *item {
+        RlpItem { list: true, encoding, .. } => Ok(Some(NodeReference::Embedded(encoding))),
+        RlpItem { payload: [], .. } => Ok(None),
+        RlpItem { payload, .. } if payload.len() == 32 => Ok(Some(NodeReference::Hash(payload))),
+        _ => Err(MptProofError::InvalidNode),
+    }
+}
+
+/// Checks that `node` is the RLP encoding of a leaf, extension or branch node.
+fn validate_node(node: &[u8]) -> Result<(), HintError> {
+    let items = decode_node_items(node)?;
+    match items.as_slice() {
+        [path, _] => {
+            // The high nibble of the first byte of a compact path is 0 or 1 for extension nodes,
+            // 2 or 3 for leaf nodes.
+            if path.list || path.payload.first().is_none_or(|byte| byte >> 4 > 3) {
+                return Err(HintError::CustomHint(Box::from("Invalid MPT node: invalid path")));
+            }
+            Ok(())
+        }
+        _ if items.len() == BRANCH_NODE_SIZE => Ok(()),
+        _ => Err(HintError::CustomHint(
+            format!(
+                "Invalid MPT node: expected {} or {} items, got {}",
+                LEAF_OR_EXTENSION_NODE_SIZE,
+                BRANCH_NODE_SIZE,
+                items.len()
+            )
+            .into(),
+        )),
+    }
+}
+
+/// Decodes the items of `node`, which must be a single RLP list.
+fn decode_node_items(node: &[u8]) -> Result<Vec<RlpItem<'_>>, HintError> {
+    let invalid_rlp =
+        |e: alloy_rlp::Error| HintError::CustomHint(format!("Invalid RLP: {}", e).into());
+    let mut buf = node;
+    let header = Header::decode(&mut buf).map_err(invalid_rlp)?;
+    if !header.list || buf.len() != header.payload_length {
+        return Err(HintError::CustomHint(Box::from("Invalid MPT node: not a single RLP list")));
+    }
+
+    // Decoding the header of an item advances the buffer to the start of its payload, except for
+    // single bytes which are their own payload.
+    let mut items = vec![];
+    while !buf.is_empty() {
+        let start = buf;
+        let item_header = Header::decode(&mut buf).map_err(invalid_rlp)?;
+        let payload = &buf[..item_header.payload_length];
+        buf = &buf[item_header.payload_length..];
+        items.push(RlpItem {
+            list: item_header.list,
+            payload,
+            encoding: &start[..start.len() - buf.len()],
+        });
+    }
+    Ok(items)
+}
+
+/// Reads `len` bytes at `ptr`, one byte per felt.
+fn read_bytes(vm: &VirtualMachine, ptr: Relocatable, len: usize) -> Result<Vec<u8>, HintError> {
+    vm.get_integer_range(ptr, len)?
+        .into_iter()
+        .map(|byte| {
+            byte.to_u8().ok_or_else(|| HintError::CustomHint(Box::from("Value is not a byte")))
+        })
+        .collect()
+}
+
+/// Reads the `ids.<len_name>` bytes at `ids.<ptr_name>`, one byte per felt. The pointer is not
+/// read if there are no bytes.
+fn read_bytes_from_var_names(
+    ptr_name: &str,
+    len_name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<Vec<u8>, HintError> {
+    let len = read_usize_from_var_name(len_name, vm, ids_data, ap_tracking)?;
+    if len == 0 {
+        return Ok(vec![]);
+    }
+    let ptr = get_ptr_from_var_name(ptr_name, vm, ids_data, ap_tracking)?;
+    read_bytes(vm, ptr, len)
+}
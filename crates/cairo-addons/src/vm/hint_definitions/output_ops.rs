@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_integer_from_var_name, get_ptr_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::vm::{hint_utils::read_usize_from_var_name, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] =
+    &[output_write_felt, output_write_u256, output_write_array, output_write_bytes32];
+
+/// Name of the execution scope variable tracking the current output pointer.
+const OUTPUT_PTR: &str = "output_ptr";
+
+/// Writes `ids.value` to the output segment.
+pub fn output_write_felt() -> Hint {
+    Hint::new(
+        String::from("output_write_felt"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let value = get_integer_from_var_name("value", vm, ids_data, ap_tracking)?;
+            write_to_output(vm, exec_scopes, ids_data, ap_tracking, &[value.into()])
+        },
+    )
+}
+
+/// Writes the `ids.low` and `ids.high` limbs of a u256 to the output segment.
+pub fn output_write_u256() -> Hint {
+    Hint::new(
+        String::from("output_write_u256"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let low = get_integer_from_var_name("low", vm, ids_data, ap_tracking)?;
+            let high = get_integer_from_var_name("high", vm, ids_data, ap_tracking)?;
+            write_to_output(vm, exec_scopes, ids_data, ap_tracking, &[low.into(), high.into()])
+        },
+    )
+}
+
+/// Copies the `ids.len` cells starting at `ids.ptr` to the output segment.
+pub fn output_write_array() -> Hint {
+    Hint::new(
+        String::from("output_write_array"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let ptr = get_ptr_from_var_name("ptr", vm, ids_data, ap_tracking)?;
+            let len = read_usize_from_var_name("len", vm, ids_data, ap_tracking)?;
+            let values = vm.get_continuous_range(ptr, len)?;
+            write_to_output(vm, exec_scopes, ids_data, ap_tracking, &values)
+        },
+    )
+}
+
+/// Writes the `ids.lo` and `ids.hi` halves of a bytes32 to the output segment.
+pub fn output_write_bytes32() -> Hint {
+    Hint::new(
+        String::from("output_write_bytes32"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let lo = get_integer_from_var_name("lo", vm, ids_data, ap_tracking)?;
+            let hi = get_integer_from_var_name("hi", vm, ids_data, ap_tracking)?;
+            write_to_output(vm, exec_scopes, ids_data, ap_tracking, &[lo.into(), hi.into()])
+        },
+    )
+}
+
+/// Writes `values` at the current output pointer and advances it.
+///
+/// The output pointer is tracked in the `output_ptr` execution scope variable, and initialized
+/// from `ids.output_ptr` on first use.
+fn write_to_output(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    values: &[MaybeRelocatable],
+) -> Result<(), HintError> {
+    let output_ptr = match exec_scopes.get::<Relocatable>(OUTPUT_PTR) {
+        Ok(output_ptr) => output_ptr,
+        Err(_) => get_ptr_from_var_name(OUTPUT_PTR, vm, ids_data, ap_tracking)?,
+    };
+    let next_output_ptr = vm.load_data(output_ptr, values)?;
+    exec_scopes.assign_or_update_variable(OUTPUT_PTR, Box::new(next_output_ptr));
+    Ok(())
+}
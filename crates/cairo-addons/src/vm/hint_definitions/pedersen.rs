@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use starknet_crypto::pedersen_hash;
+
+use crate::vm::hints::Hint;
+
+pub const HINTS: &[fn() -> Hint] = &[pedersen_hash_pair];
+
+/// Writes the Pedersen hash of `ids.x` and `ids.y` to `ids.hash`, as computed by the `pedersen`
+/// builtin.
+pub fn pedersen_hash_pair() -> Hint {
+    Hint::new(
+        String::from("pedersen_hash_pair"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let x = get_integer_from_var_name("x", vm, ids_data, ap_tracking)?;
+            let y = get_integer_from_var_name("y", vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name("hash", pedersen_hash(&x, &y), vm, ids_data, ap_tracking)
+        },
+    )
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use ark_bn254::{Fq, G1Affine};
+use ark_ff::PrimeField;
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+
+#[cfg(feature = "bls")]
+use crate::vm::hint_utils::Uint384;
+use crate::vm::{hint_utils::Uint256, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] = &[
+    is_point_on_stark_curve,
+    assert_point_on_stark_curve,
+    is_point_on_bn254,
+    assert_point_on_bn254,
+];
+
+#[cfg(feature = "bls")]
+pub const BLS_HINTS: &[fn() -> Hint] = &[is_point_on_bls12_381_g1, assert_point_on_bls12_381_g1];
+
+/// `beta` coefficient of the STARK curve `y^2 = x^3 + x + beta`.
+const STARK_CURVE_BETA: Felt252 = Felt252::from_hex_unchecked(
+    "0x6f21413efbe40de150e596d72f7a8c5609ad26c15c915c1f4cdfcb99cee9e89",
+);
+
+/// Modulus of the BLS12-381 base field.
+#[cfg(feature = "bls")]
+const BLS12_381_P: &str = "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+
+/// `b` coefficient of the BLS12-381 G1 curve `y^2 = x^3 + b`.
+#[cfg(feature = "bls")]
+const BLS12_381_B: u32 = 4;
+
+/// Writes to `ids.on_curve` whether the point `(ids.x, ids.y)` lies on the STARK curve.
+pub fn is_point_on_stark_curve() -> Hint {
+    Hint::new(
+        String::from("is_point_on_stark_curve"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let on_curve = is_on_stark_curve(vm, ids_data, ap_tracking)?;
+            write_on_curve(on_curve, false, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Same as `is_point_on_stark_curve`, but fails if the point is not on the curve.
+pub fn assert_point_on_stark_curve() -> Hint {
+    Hint::new(
+        String::from("assert_point_on_stark_curve"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let on_curve = is_on_stark_curve(vm, ids_data, ap_tracking)?;
+            write_on_curve(on_curve, true, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes to `ids.on_curve` whether the point `(ids.x, ids.y)`, with `Uint256` coordinates, lies
+/// on the BN254 curve. Coordinates that are not valid field elements are not on the curve.
+pub fn is_point_on_bn254() -> Hint {
+    Hint::new(
+        String::from("is_point_on_bn254"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let on_curve = is_on_bn254(vm, ids_data, ap_tracking)?;
+            write_on_curve(on_curve, false, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Same as `is_point_on_bn254`, but fails if the point is not on the curve.
+pub fn assert_point_on_bn254() -> Hint {
+    Hint::new(
+        String::from("assert_point_on_bn254"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let on_curve = is_on_bn254(vm, ids_data, ap_tracking)?;
+            write_on_curve(on_curve, true, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes to `ids.on_curve` whether the point `(ids.x, ids.y)`, with `Uint384` coordinates, lies
+/// on the BLS12-381 G1 curve. Coordinates that are not valid field elements are not on the curve.
+#[cfg(feature = "bls")]
+pub fn is_point_on_bls12_381_g1() -> Hint {
+    Hint::new(
+        String::from("is_point_on_bls12_381_g1"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let on_curve = is_on_bls12_381_g1(vm, ids_data, ap_tracking)?;
+            write_on_curve(on_curve, false, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Same as `is_point_on_bls12_381_g1`, but fails if the point is not on the curve.
+#[cfg(feature = "bls")]
+pub fn assert_point_on_bls12_381_g1() -> Hint {
+    Hint::new(
+        String::from("assert_point_on_bls12_381_g1"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let on_curve = is_on_bls12_381_g1(vm, ids_data, ap_tracking)?;
+            write_on_curve(on_curve, true, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes `on_curve` to `ids.on_curve`, failing instead if `assert` is set and the point is not on
+/// the curve.
+fn write_on_curve(
+    on_curve: bool,
+    assert: bool,
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    if assert && !on_curve {
+        return Err(HintError::CustomHint(Box::from("Point not on curve")));
+    }
+    insert_value_from_var_name("on_curve", Felt252::from(on_curve), vm, ids_data, ap_tracking)
+}
+
+fn is_on_stark_curve(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<bool, HintError> {
+    let x = get_integer_from_var_name("x", vm, ids_data, ap_tracking)?;
+    let y = get_integer_from_var_name("y", vm, ids_data, ap_tracking)?;
+    // The STARK curve is defined over the field of felts, with `alpha = 1`.
+    Ok(y * y == x * x * x + x + STARK_CURVE_BETA)
+}
+
+fn is_on_bn254(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<bool, HintError> {
+    let modulus = BigUint::from(Fq::MODULUS);
+    let x = Uint256::from_var_name("x", vm, ids_data, ap_tracking)?.pack();
+    let y = Uint256::from_var_name("y", vm, ids_data, ap_tracking)?.pack();
+    if x >= modulus || y >= modulus {
+        return Ok(false);
+    }
+    Ok(G1Affine::new_unchecked(Fq::from(x), Fq::from(y)).is_on_curve())
+}
+
+#[cfg(feature = "bls")]
+fn is_on_bls12_381_g1(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<bool, HintError> {
+    let modulus = BigUint::parse_bytes(BLS12_381_P.as_bytes(), 16).unwrap();
+    let x = Uint384::from_var_name("x", vm, ids_data, ap_tracking)?.pack();
+    let y = Uint384::from_var_name("y", vm, ids_data, ap_tracking)?.pack();
+    if x >= modulus || y >= modulus {
+        return Ok(false);
+    }
+    Ok((&y * &y) % &modulus == (&x * &x * &x + BLS12_381_B) % &modulus)
+}
@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use starknet_crypto::poseidon_hash_many;
+
+use crate::vm::{hint_utils::read_usize_from_var_name, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] = &[poseidon_hash_range];
+
+/// Writes the Poseidon hash of the `ids.data_len` felts at `ids.data_ptr`, as computed by
+/// `poseidon_hash_many`, to `ids.result`. `ids.data_ptr` is not read when `ids.data_len` is 0.
+pub fn poseidon_hash_range() -> Hint {
+    Hint::new(
+        String::from("poseidon_hash_range"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let data_len = read_usize_from_var_name("data_len", vm, ids_data, ap_tracking)?;
+            let data = if data_len == 0 {
+                vec![]
+            } else {
+                let data_ptr = get_ptr_from_var_name("data_ptr", vm, ids_data, ap_tracking)?;
+                vm.get_integer_range(data_ptr, data_len)?
+                    .into_iter()
+                    .map(|felt| felt.into_owned())
+                    .collect::<Vec<_>>()
+            };
+            insert_value_from_var_name(
+                "result",
+                poseidon_hash_many(&data),
+                vm,
+                ids_data,
+                ap_tracking,
+            )
+        },
+    )
+}
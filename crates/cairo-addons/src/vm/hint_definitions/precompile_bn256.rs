@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::insert_value_from_var_name,
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+use revm_precompile::bn128::{mul::ISTANBUL_MUL_GAS_COST, run_mul};
+
+#[cfg(feature = "memoize-hints")]
+use super::HintCache;
+use crate::vm::{
+    hint_utils::{deserialize_sequence, serialize_sequence},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[bn256_scalar_mul_precompile];
+
+/// Implements the ecMul precompile (0x07) as defined in EIP-196, called by `alt_bn128_mul` when
+/// dispatched from the precompile table.
+///
+/// Runs the same ecMul as `alt_bn128_mul_hint` with the Istanbul gas cost: the call `data` is
+/// right-padded with zeros (or truncated) to 96 bytes, and the 64-byte encoding of
+/// `scalar * point` is written to `output`, the point at infinity being encoded as all zeros.
+/// Invalid points set `error` to `OutOfGasError`, as in EELS.
+pub fn bn256_scalar_mul_precompile() -> Hint {
+    Hint::new(
+        String::from("bn256_scalar_mul_precompile"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let data = read_data_bytes(vm, ids_data, ap_tracking)?;
+
+            // Gas is handled in cairo before calling this hint.
+            match memoized_bn256_scalar_mul(&data, exec_scopes) {
+                Some(output) => {
+                    insert_value_from_var_name("error", 0, vm, ids_data, ap_tracking)?;
+                    let output = deserialize_sequence(output, vm)?;
+                    insert_value_from_var_name("output", output, vm, ids_data, ap_tracking)
+                }
+                None => {
+                    // Any error gets converted to OutOfGasError in EELS
+                    let error_string = "OutOfGasError";
+                    let error_bytes = error_string.as_bytes();
+                    let error_ascii = Felt252::from_bytes_be_slice(error_bytes);
+                    let error_ptr = vm.add_memory_segment();
+                    vm.insert_value(error_ptr, error_ascii)?;
+                    insert_value_from_var_name("error", error_ptr, vm, ids_data, ap_tracking)
+                }
+            }
+        },
+    )
+}
+
+/// Reads the bytes of `ids.data`, failing if a value doesn't fit in a byte.
+fn read_data_bytes(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<Vec<u8>, HintError> {
+    serialize_sequence("data", vm, ids_data, ap_tracking)?
+        .iter()
+        .map(|x| x.to_u8().ok_or_else(|| HintError::CustomHint(Box::from("Value is not a byte"))))
+        .collect()
+}
+
+/// Computes `scalar * point`, reusing the result of previous calls with the same input.
+/// Invalid inputs are not cached.
+#[cfg(feature = "memoize-hints")]
+fn memoized_bn256_scalar_mul(input: &[u8], exec_scopes: &mut ExecutionScopes) -> Option<Vec<u8>> {
+    HintCache::memoize(exec_scopes, "bn256_scalar_mul_precompile", input, || {
+        bn256_scalar_mul(input).ok_or_else(|| HintError::CustomHint("Invalid point".into()))
+    })
+    .ok()
+}
+
+#[cfg(not(feature = "memoize-hints"))]
+fn memoized_bn256_scalar_mul(input: &[u8], _exec_scopes: &mut ExecutionScopes) -> Option<Vec<u8>> {
+    bn256_scalar_mul(input)
+}
+
+/// Computes `scalar * point` from the call data, returning `None` if the point is invalid.
+fn bn256_scalar_mul(input: &[u8]) -> Option<Vec<u8>> {
+    run_mul(input, ISTANBUL_MUL_GAS_COST, u64::MAX).ok().map(|output| output.bytes.to_vec())
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+use revm_precompile::{hash::ripemd160_run, Bytes};
+
+use crate::vm::{
+    hint_utils::{read_usize_from_var_name, write_collection_to_addr},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[ripemd160_precompile];
+
+/// Implements the RIPEMD-160 precompile (0x03).
+///
+/// Reads `input_len` bytes from `input_ptr` and writes the 20-byte digest, left-padded with zeros
+/// to 32 bytes, to a new segment whose base is written to `output_ptr`. The gas cost,
+/// `600 + 120 * ceil(input_len / 32)`, is written to `gas_cost`.
+pub fn ripemd160_precompile() -> Hint {
+    Hint::new(
+        String::from("ripemd160_precompile"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let input_ptr = get_ptr_from_var_name("input_ptr", vm, ids_data, ap_tracking)?;
+            let input_len = read_usize_from_var_name("input_len", vm, ids_data, ap_tracking)?;
+            let input = vm
+                .get_integer_range(input_ptr, input_len)?
+                .into_iter()
+                .map(|byte| {
+                    byte.to_u8()
+                        .ok_or_else(|| HintError::CustomHint(Box::from("Value is not a byte")))
+                })
+                .collect::<Result<Vec<u8>, HintError>>()?;
+
+            // Gas is checked in cairo, so the precompile is run without a gas limit.
+            let output = ripemd160_run(&Bytes::from(input), u64::MAX)
+                .map_err(|e| HintError::CustomHint(format!("RIPEMD-160 failed: {:?}", e).into()))?;
+
+            let output_ptr = vm.add_memory_segment();
+            write_collection_to_addr(output_ptr, &output.bytes.to_vec(), vm)?;
+            insert_value_from_var_name("output_ptr", output_ptr, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name(
+                "gas_cost",
+                Felt252::from(output.gas_used),
+                vm,
+                ids_data,
+                ap_tracking,
+            )
+        },
+    )
+}
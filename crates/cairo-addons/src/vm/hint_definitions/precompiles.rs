@@ -1,10 +1,13 @@
 use std::collections::HashMap;
 
 use crate::vm::hint_utils::deserialize_sequence;
+use ark_bn254::{Fq, G1Affine, G1Projective};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
 use cairo_vm::{
     hint_processor::{
         builtin_hint_processor::hint_utils::{
-            get_integer_from_var_name, insert_value_from_var_name,
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
         },
         hint_processor_definition::HintReference,
     },
@@ -14,15 +17,19 @@ use cairo_vm::{
     Felt252,
 };
 use num_bigint::BigUint;
-use num_traits::{ToPrimitive, Zero};
+use num_traits::{One, ToPrimitive, Zero};
 use revm_precompile::{
     bn128::{pair, run_add, run_mul, run_pair},
+    hash::sha256_run,
     kzg_point_evaluation::run,
+    secp256k1::ec_recover_run,
     Bytes,
 };
 
+#[cfg(feature = "memoize-hints")]
+use super::HintCache;
 use crate::vm::{
-    hint_utils::{serialize_sequence, Uint256},
+    hint_utils::{read_usize_from_var_name, serialize_sequence, write_collection_to_addr, Uint256},
     hints::Hint,
 };
 
@@ -34,12 +41,18 @@ pub const HINTS: &[fn() -> Hint] = &[
     alt_bn128_mul_hint,
     point_evaluation_hint,
     bit_length_hint,
+    ecrecover,
+    modexp,
+    bn254_ec_add,
+    bn254_ec_mul,
+    sha256_precompile,
 ];
 
 const WORD_SIZE: u32 = 8;
 const MAX_EXP_LEN: u32 = 32;
 const MIN_GAS_COST: u32 = 200;
 const GAS_DIVISOR: u32 = 3;
+const ECRECOVER_WORD_SIZE: usize = 32;
 
 pub fn modexp_gas() -> Hint {
     Hint::new(
@@ -134,7 +147,7 @@ pub fn alt_bn128_pairing_check_hint() -> Hint {
     Hint::new(
         String::from("alt_bn128_pairing_check_hint"),
         |vm: &mut VirtualMachine,
-         _exec_scopes: &mut ExecutionScopes,
+         exec_scopes: &mut ExecutionScopes,
          ids_data: &HashMap<String, HintReference>,
          ap_tracking: &ApTracking,
          _constants: &HashMap<String, Felt252>|
@@ -144,16 +157,21 @@ pub fn alt_bn128_pairing_check_hint() -> Hint {
                 .filter_map(|x| x.to_u8())
                 .collect();
 
-            // Give virtually infinite gas as we checked this in cairo before.
-            match run_pair(
-                &data,
-                pair::ISTANBUL_PAIR_PER_POINT,
-                pair::ISTANBUL_PAIR_BASE,
-                2u64.pow(64) - 1,
-            ) {
+            let output = memoized(exec_scopes, "alt_bn128_pairing_check_hint", &data, || {
+                // Give virtually infinite gas as we checked this in cairo before.
+                run_pair(
+                    &data,
+                    pair::ISTANBUL_PAIR_PER_POINT,
+                    pair::ISTANBUL_PAIR_BASE,
+                    2u64.pow(64) - 1,
+                )
+                .map(|output| output.bytes.to_vec())
+                .map_err(|e| HintError::CustomHint(format!("Pairing check failed: {:?}", e).into()))
+            });
+            match output {
                 Ok(output) => {
                     insert_value_from_var_name("error", 0, vm, ids_data, ap_tracking)?;
-                    let output = deserialize_sequence(output.bytes.to_vec(), vm)?;
+                    let output = deserialize_sequence(output, vm)?;
                     insert_value_from_var_name("output", output, vm, ids_data, ap_tracking)
                 }
                 Err(_e) => {
@@ -298,3 +316,280 @@ pub fn bit_length_hint() -> Hint {
         },
     )
 }
+
+/// Recovers the Ethereum address that signed the message hash `ids.hash` with the signature
+/// `(ids.v, ids.r, ids.s)`, all read as `U256`, like the `ecrecover` precompile.
+///
+/// On success, the address is written to `ids.address` as the little-endian felt of its 20 bytes
+/// and `ids.success` is set to 1. If the signature is invalid, e.g. `v` is neither 27 nor 28, no
+/// error is raised: `ids.address` and `ids.success` are set to 0, as the precompile returns no
+/// output.
+pub fn ecrecover() -> Hint {
+    Hint::new(
+        String::from("ecrecover"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let input = ["hash", "v", "r", "s"]
+                .iter()
+                .map(|name| {
+                    let value = Uint256::from_var_name(name, vm, ids_data, ap_tracking)?.pack();
+                    let bytes = value.to_bytes_be();
+                    if bytes.len() > ECRECOVER_WORD_SIZE {
+                        return Err(HintError::CustomHint(
+                            format!("{} exceeds 256 bits", name).into(),
+                        ));
+                    }
+                    let mut word = vec![0u8; ECRECOVER_WORD_SIZE - bytes.len()];
+                    word.extend(bytes);
+                    Ok(word)
+                })
+                .collect::<Result<Vec<_>, HintError>>()?
+                .concat();
+
+            // Gas is handled in cairo before calling this hint.
+            let output = ec_recover_run(&Bytes::from(input), u64::MAX)
+                .map(|output| output.bytes)
+                .unwrap_or_default();
+            let (address, success) = if output.len() == ECRECOVER_WORD_SIZE {
+                // The address is left-padded to 32 bytes.
+                (Felt252::from_bytes_le_slice(&output[12..]), Felt252::ONE)
+            } else {
+                (Felt252::ZERO, Felt252::ZERO)
+            };
+            insert_value_from_var_name("address", address, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name("success", success, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Computes `base ** exp % modulus`, like the `modexp` precompile, for the big-endian integers of
+/// `ids.base_len`, `ids.exp_len` and `ids.mod_len` bytes at `ids.base_ptr`, `ids.exp_ptr` and
+/// `ids.mod_ptr`, one byte per felt.
+///
+/// The result is written as `ids.mod_len` big-endian bytes, left-padded with zeros, to a new
+/// segment whose base is written to `ids.result_ptr` and its length to `ids.result_len`. A zero
+/// modulus gives a zero result, and a zero exponent gives `1 % modulus`.
+pub fn modexp() -> Hint {
+    Hint::new(
+        String::from("modexp"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let base = read_bytes("base", vm, ids_data, ap_tracking)?;
+            let exp = read_bytes("exp", vm, ids_data, ap_tracking)?;
+            let modulus = read_bytes("mod", vm, ids_data, ap_tracking)?;
+
+            let base_int = BigUint::from_bytes_be(&base);
+            let exp_int = BigUint::from_bytes_be(&exp);
+            let mod_int = BigUint::from_bytes_be(&modulus);
+            let result_int = if mod_int.is_zero() {
+                BigUint::zero()
+            } else if exp_int.is_zero() {
+                BigUint::one() % &mod_int
+            } else {
+                base_int.modpow(&exp_int, &mod_int)
+            };
+
+            // The result is lower than the modulus, so it always fits in `mod_len` bytes.
+            let result_bytes = result_int.to_bytes_be();
+            let mut result = vec![Felt252::ZERO; modulus.len()];
+            if !result_int.is_zero() {
+                let padding = modulus.len() - result_bytes.len();
+                for (felt, byte) in result[padding..].iter_mut().zip(result_bytes) {
+                    *felt = Felt252::from(byte);
+                }
+            }
+
+            let result_ptr = vm.add_memory_segment();
+            write_collection_to_addr(result_ptr, &result, vm)?;
+            insert_value_from_var_name("result_ptr", result_ptr, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name(
+                "result_len",
+                Felt252::from(result.len()),
+                vm,
+                ids_data,
+                ap_tracking,
+            )
+        },
+    )
+}
+
+/// Adds the BN254 G1 points `(ids.x1, ids.y1)` and `(ids.x2, ids.y2)`, like the `ecAdd`
+/// precompile, and writes the sum to `(ids.rx, ids.ry)`. Each coordinate is a `Uint256`, as
+/// BN254 field elements don't fit in a felt.
+///
+/// The point at infinity is encoded as `(0, 0)`, both in the inputs and in the result. Fails if a
+/// coordinate is not a valid field element or if a point is not on the curve.
+pub fn bn254_ec_add() -> Hint {
+    Hint::new(
+        String::from("bn254_ec_add"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let p1 = read_bn254_point("x1", "y1", vm, ids_data, ap_tracking)?;
+            let p2 = read_bn254_point("x2", "y2", vm, ids_data, ap_tracking)?;
+            let sum = (G1Projective::from(p1) + G1Projective::from(p2)).into_affine();
+            write_bn254_point(sum, "rx", "ry", vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Multiplies the BN254 G1 point `(ids.x, ids.y)` by the scalar `ids.s`, like the `ecMul`
+/// precompile, and writes the product to `(ids.rx, ids.ry)`. The coordinates and the scalar are
+/// `Uint256`s.
+///
+/// As in `bn254_ec_add`, the point at infinity is encoded as `(0, 0)`, which is in particular the
+/// result of a multiplication by 0. Fails if the point is invalid.
+pub fn bn254_ec_mul() -> Hint {
+    Hint::new(
+        String::from("bn254_ec_mul"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let point = read_bn254_point("x", "y", vm, ids_data, ap_tracking)?;
+            let scalar = Uint256::from_var_name("s", vm, ids_data, ap_tracking)?.pack();
+            let product = point.mul_bigint(scalar.to_u64_digits()).into_affine();
+            write_bn254_point(product, "rx", "ry", vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Implements the SHA-256 precompile (0x02).
+///
+/// Reads `ids.data_len` bytes from `ids.data_ptr` and writes the 32-byte digest, one byte per felt,
+/// to a new segment whose base is written to `ids.output_ptr`.
+pub fn sha256_precompile() -> Hint {
+    Hint::new(
+        String::from("sha256_precompile"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let data = read_bytes("data", vm, ids_data, ap_tracking)?;
+
+            let output = memoized(exec_scopes, "sha256_precompile", &data, || {
+                // Gas is checked in cairo, so the precompile is run without a gas limit.
+                sha256_run(&Bytes::from(data.clone()), u64::MAX)
+                    .map(|output| output.bytes.to_vec())
+                    .map_err(|e| HintError::CustomHint(format!("SHA-256 failed: {:?}", e).into()))
+            })?;
+
+            let output_ptr = vm.add_memory_segment();
+            write_collection_to_addr(output_ptr, &output, vm)?;
+            insert_value_from_var_name("output_ptr", output_ptr, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Runs `compute`, reusing the result of a previous call of `hint_name` on the same `input`.
+/// Errors are not cached.
+#[cfg(feature = "memoize-hints")]
+fn memoized(
+    exec_scopes: &mut ExecutionScopes,
+    hint_name: &str,
+    input: &[u8],
+    compute: impl FnOnce() -> Result<Vec<u8>, HintError>,
+) -> Result<Vec<u8>, HintError> {
+    HintCache::memoize(exec_scopes, hint_name, input, compute)
+}
+
+#[cfg(not(feature = "memoize-hints"))]
+fn memoized(
+    _exec_scopes: &mut ExecutionScopes,
+    _hint_name: &str,
+    _input: &[u8],
+    compute: impl FnOnce() -> Result<Vec<u8>, HintError>,
+) -> Result<Vec<u8>, HintError> {
+    compute()
+}
+
+/// Reads the BN254 G1 point `(ids.{x}, ids.{y})` with `Uint256` coordinates, where `(0, 0)` is the
+/// point at infinity, checking it lies on the curve.
+fn read_bn254_point(
+    x: &str,
+    y: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<G1Affine, HintError> {
+    let modulus = BigUint::from(Fq::MODULUS);
+    let mut coordinates = [Fq::zero(), Fq::zero()];
+    for (coordinate, name) in coordinates.iter_mut().zip([x, y]) {
+        let value = Uint256::from_var_name(name, vm, ids_data, ap_tracking)?.pack();
+        if value >= modulus {
+            return Err(HintError::CustomHint(
+                format!("{} is not a valid field element", name).into(),
+            ));
+        }
+        *coordinate = Fq::from(value);
+    }
+
+    let [x_value, y_value] = coordinates;
+    if x_value.is_zero() && y_value.is_zero() {
+        return Ok(G1Affine::identity());
+    }
+    // G1 has a cofactor of 1, so any point on the curve is in the correct subgroup.
+    let point = G1Affine::new_unchecked(x_value, y_value);
+    if !point.is_on_curve() {
+        return Err(HintError::CustomHint(format!("({}, {}) is not on the curve", x, y).into()));
+    }
+    Ok(point)
+}
+
+/// Writes the BN254 G1 point `point` to `(ids.{x}, ids.{y})` as `Uint256` coordinates, where
+/// `(0, 0)` is the point at infinity.
+fn write_bn254_point(
+    point: G1Affine,
+    x: &str,
+    y: &str,
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let (x_value, y_value) =
+        if point.infinity { (Fq::zero(), Fq::zero()) } else { (point.x, point.y) };
+    for (name, coordinate) in [(x, x_value), (y, y_value)] {
+        Uint256::split(&coordinate.into_bigint().into())._insert_from_var_name(
+            name,
+            vm,
+            ids_data,
+            ap_tracking,
+        )?;
+    }
+    Ok(())
+}
+
+/// Reads the `ids.{name}_len` bytes at `ids.{name}_ptr`, one byte per felt.
+fn read_bytes(
+    name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<Vec<u8>, HintError> {
+    let len = read_usize_from_var_name(&format!("{}_len", name), vm, ids_data, ap_tracking)?;
+    if len == 0 {
+        return Ok(vec![]);
+    }
+    let ptr = get_ptr_from_var_name(&format!("{}_ptr", name), vm, ids_data, ap_tracking)?;
+    vm.get_integer_range(ptr, len)?
+        .into_iter()
+        .map(|byte| {
+            byte.to_u8().ok_or_else(|| HintError::CustomHint(Box::from("Value is not a byte")))
+        })
+        .collect()
+}
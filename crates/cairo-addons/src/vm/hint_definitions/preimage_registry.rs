@@ -0,0 +1,52 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::dict_manager::DictKey,
+    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+    Felt252,
+};
+
+/// Name of the execution scope variable holding the shared `PreimageRegistry`.
+const PREIMAGE_REGISTRY_SCOPE_KEY: &str = "preimage_registry";
+
+/// Stores the preimages of the hashed compound keys of all the hashdicts of a run, so that a key
+/// used by several trackers (e.g. after a dict copy) is only stored once.
+///
+/// Preimages are keyed by their hash, as computed by the hashdict hints.
+#[derive(Debug, Default)]
+pub struct PreimageRegistry {
+    preimages: HashMap<Felt252, Vec<MaybeRelocatable>>,
+}
+
+impl PreimageRegistry {
+    /// Registers `preimage` as the preimage of `hashed_key`, unless it is already known.
+    pub fn register(&mut self, hashed_key: Felt252, preimage: &[MaybeRelocatable]) {
+        self.preimages.entry(hashed_key).or_insert_with(|| preimage.to_vec());
+    }
+
+    /// Returns the compound key whose hash is `hashed_key`, if it was registered.
+    pub fn get(&self, hashed_key: &Felt252) -> Option<DictKey> {
+        self.preimages.get(hashed_key).map(|preimage| DictKey::Compound(preimage.clone()))
+    }
+
+    /// Returns an iterator over the registered `(hashed_key, preimage)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&Felt252, &Vec<MaybeRelocatable>)> {
+        self.preimages.iter()
+    }
+
+    /// Returns the registry stored in the main execution scope, creating it if it doesn't exist
+    /// yet so that it outlives the scope of the hint that first used it.
+    pub fn from_exec_scopes(exec_scopes: &mut ExecutionScopes) -> Rc<RefCell<PreimageRegistry>> {
+        if let Some(registry) = exec_scopes.data[0]
+            .get(PREIMAGE_REGISTRY_SCOPE_KEY)
+            .and_then(|registry| registry.downcast_ref::<Rc<RefCell<PreimageRegistry>>>())
+        {
+            return registry.clone();
+        }
+
+        let registry = Rc::new(RefCell::new(PreimageRegistry::default()));
+        exec_scopes.data[0]
+            .insert(PREIMAGE_REGISTRY_SCOPE_KEY.to_string(), Box::new(registry.clone()));
+        registry
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{
+        errors::math_errors::MathError, exec_scope::ExecutionScopes, relocatable::Relocatable,
+    },
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::{ToPrimitive, Zero};
+
+use crate::vm::{
+    hint_utils::{
+        deserialize_sequence,
+        mpt::{encode_list, trie_root},
+        read_bytes32, read_bytes_struct, read_usize_from_var_name, write_bytes32,
+    },
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[rlp_encode_receipt, compute_receipts_root];
+
+const BLOOM_SIZE: usize = 256;
+const ADDRESS_SIZE: usize = 20;
+
+/// Encodes a receipt as `rlp([status, cumulative_gas_used, bloom, logs])`, following EIP-658.
+///
+/// Reads `status`, `cumulative_gas_used`, `bloom` (256 bytes, one per felt) and the `logs_len`
+/// logs pointed to by `logs`, and writes the encoding to `ids.encoded_receipt` as `Bytes`.
+pub fn rlp_encode_receipt() -> Hint {
+    Hint::new(
+        String::from("rlp_encode_receipt"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let status = !get_integer_from_var_name("status", vm, ids_data, ap_tracking)?.is_zero();
+            let cumulative_gas_used =
+                get_integer_from_var_name("cumulative_gas_used", vm, ids_data, ap_tracking)?;
+            let cumulative_gas_used = cumulative_gas_used.to_u64().ok_or_else(|| {
+                HintError::CustomHint(Box::from("cumulative_gas_used does not fit in a u64"))
+            })?;
+
+            let bloom_ptr = get_ptr_from_var_name("bloom", vm, ids_data, ap_tracking)?;
+            let bloom = vm
+                .get_integer_range(bloom_ptr, BLOOM_SIZE)?
+                .iter()
+                .map(|byte| byte.to_u8())
+                .collect::<Option<Vec<u8>>>()
+                .ok_or_else(|| {
+                    HintError::CustomHint(Box::from("bloom contains a non-byte value"))
+                })?;
+
+            let logs_ptr = get_ptr_from_var_name("logs", vm, ids_data, ap_tracking)?;
+            let logs_len = read_usize_from_var_name("logs_len", vm, ids_data, ap_tracking)?;
+            let logs = (0..logs_len)
+                .map(|i| encode_log(vm, vm.get_relocatable((logs_ptr + i)?)?))
+                .collect::<Result<Vec<_>, HintError>>()?;
+
+            let encoded_receipt = encode_list(&[
+                alloy_rlp::encode(status),
+                alloy_rlp::encode(cumulative_gas_used),
+                alloy_rlp::encode(bloom.as_slice()),
+                encode_list(&logs),
+            ]);
+
+            let encoded_receipt = deserialize_sequence(encoded_receipt, vm)?;
+            insert_value_from_var_name(
+                "encoded_receipt",
+                encoded_receipt,
+                vm,
+                ids_data,
+                ap_tracking,
+            )
+        },
+    )
+}
+
+/// Computes the root of the receipts trie, keyed by `rlp(index)`, from the `receipts_len`
+/// encoded receipts pointed to by `receipts` and writes it to `ids.root` as a `Bytes32`.
+pub fn compute_receipts_root() -> Hint {
+    Hint::new(
+        String::from("compute_receipts_root"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let receipts_ptr = get_ptr_from_var_name("receipts", vm, ids_data, ap_tracking)?;
+            let receipts_len = read_usize_from_var_name("receipts_len", vm, ids_data, ap_tracking)?;
+            let entries = (0..receipts_len)
+                .map(|i| {
+                    let receipt = read_bytes_struct(vm, vm.get_relocatable((receipts_ptr + i)?)?)?;
+                    Ok((alloy_rlp::encode(i), receipt))
+                })
+                .collect::<Result<Vec<_>, HintError>>()?;
+
+            let root = write_bytes32(vm, &trie_root(&entries))?;
+            insert_value_from_var_name("root", root, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Encodes the `LogStruct` at `log_ptr` as `rlp([address, topics, data])`.
+fn encode_log(vm: &VirtualMachine, log_ptr: Relocatable) -> Result<Vec<u8>, HintError> {
+    // Addresses are stored as little-endian felts.
+    let address = vm.get_integer(log_ptr)?.to_bytes_le();
+
+    let topics_ptr = vm.get_relocatable((log_ptr + 1)?)?;
+    let topics_len = vm.get_integer((topics_ptr + 1)?)?.into_owned();
+    let topics_len: usize = topics_len
+        .try_into()
+        .map_err(|_| MathError::Felt252ToUsizeConversion(Box::new(topics_len)))?;
+    let topics_data = if topics_len == 0 { topics_ptr } else { vm.get_relocatable(topics_ptr)? };
+    let topics = (0..topics_len)
+        .map(|i| {
+            let topic = read_bytes32(vm, vm.get_relocatable((topics_data + i)?)?)?;
+            Ok(alloy_rlp::encode(topic.as_slice()))
+        })
+        .collect::<Result<Vec<_>, HintError>>()?;
+
+    let data = read_bytes_struct(vm, vm.get_relocatable((log_ptr + 2)?)?)?;
+
+    Ok(encode_list(&[
+        alloy_rlp::encode(&address[..ADDRESS_SIZE]),
+        encode_list(&topics),
+        alloy_rlp::encode(data.as_slice()),
+    ]))
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{errors::math_errors::MathError, exec_scope::ExecutionScopes},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use starknet_crypto::poseidon_hash_many;
+
+use crate::vm::hints::Hint;
+
+pub const HINTS: &[fn() -> Hint] =
+    &[poseidon_hasher_new, poseidon_hasher_update, poseidon_hasher_finalize];
+
+/// Name of the execution scope variable holding the buffers of the open hashers, by handle.
+const POSEIDON_HASHERS: &str = "poseidon_hashers";
+
+type PoseidonHashers = HashMap<u64, Vec<Felt252>>;
+
+/// Opens a new streaming Poseidon hasher and writes its handle to `ids.handle`.
+pub fn poseidon_hasher_new() -> Hint {
+    Hint::new(
+        String::from("poseidon_hasher_new"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let hashers = poseidon_hashers(exec_scopes)?;
+            let handle = hashers.keys().max().map_or(0, |handle| handle + 1);
+            hashers.insert(handle, Vec::new());
+            insert_value_from_var_name("handle", Felt252::from(handle), vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Appends `ids.element` to the buffer of the hasher `ids.handle`.
+pub fn poseidon_hasher_update() -> Hint {
+    Hint::new(
+        String::from("poseidon_hasher_update"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let handle = read_handle(vm, ids_data, ap_tracking)?;
+            let element = get_integer_from_var_name("element", vm, ids_data, ap_tracking)?;
+            poseidon_hashers(exec_scopes)?
+                .get_mut(&handle)
+                .ok_or_else(|| unknown_handle(handle))?
+                .push(element);
+            Ok(())
+        },
+    )
+}
+
+/// Closes the hasher `ids.handle` and writes `poseidon_hash_many` of its buffer to `ids.hash`.
+pub fn poseidon_hasher_finalize() -> Hint {
+    Hint::new(
+        String::from("poseidon_hasher_finalize"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let handle = read_handle(vm, ids_data, ap_tracking)?;
+            let buffer = poseidon_hashers(exec_scopes)?
+                .remove(&handle)
+                .ok_or_else(|| unknown_handle(handle))?;
+            insert_value_from_var_name(
+                "hash",
+                poseidon_hash_many(&buffer),
+                vm,
+                ids_data,
+                ap_tracking,
+            )
+        },
+    )
+}
+
+fn read_handle(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<u64, HintError> {
+    let handle = get_integer_from_var_name("handle", vm, ids_data, ap_tracking)?;
+    handle.try_into().map_err(|_| MathError::Felt252ToU64Conversion(Box::new(handle)).into())
+}
+
+fn unknown_handle(handle: u64) -> HintError {
+    HintError::CustomHint(format!("Unknown Poseidon hasher handle {}", handle).into())
+}
+
+/// Returns the open hashers of the current scope, creating the map if needed.
+fn poseidon_hashers(exec_scopes: &mut ExecutionScopes) -> Result<&mut PoseidonHashers, HintError> {
+    if exec_scopes.get_ref::<PoseidonHashers>(POSEIDON_HASHERS).is_err() {
+        exec_scopes.assign_or_update_variable(POSEIDON_HASHERS, Box::new(PoseidonHashers::new()));
+    }
+    exec_scopes.get_mut_ref::<PoseidonHashers>(POSEIDON_HASHERS)
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use alloy_rlp::Header;
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+
+use crate::vm::{
+    hint_utils::{read_usize_from_var_name, write_collection_to_addr},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[rlp_decode_item];
+
+/// Decodes the first RLP item of the `ids.rlp_len` bytes at `ids.rlp_ptr`, one byte per felt.
+///
+/// The payload of the item is written to a new segment whose base is written to
+/// `ids.output_ptr` and its length to `ids.output_len`. `ids.is_list` is set to 1 if the item is
+/// a list, in which case the payload is the concatenation of the encodings of its elements, and
+/// to 0 if it is a string. Bytes following the first item are ignored.
+pub fn rlp_decode_item() -> Hint {
+    Hint::new(
+        String::from("rlp_decode_item"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let rlp_len = read_usize_from_var_name("rlp_len", vm, ids_data, ap_tracking)?;
+            let rlp = if rlp_len == 0 {
+                vec![]
+            } else {
+                let rlp_ptr = get_ptr_from_var_name("rlp_ptr", vm, ids_data, ap_tracking)?;
+                vm.get_integer_range(rlp_ptr, rlp_len)?
+                    .into_iter()
+                    .map(|byte| {
+                        byte.to_u8()
+                            .ok_or_else(|| HintError::CustomHint(Box::from("Value is not a byte")))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+            };
+
+            // Decoding the header advances the buffer to the start of the payload, and checks that
+            // the payload fits in it.
+            let mut buf = rlp.as_slice();
+            let header = Header::decode(&mut buf)
+                .map_err(|e| HintError::CustomHint(format!("Invalid RLP: {}", e).into()))?;
+            let payload = buf[..header.payload_length]
+                .iter()
+                .map(|byte| Felt252::from(*byte))
+                .collect::<Vec<_>>();
+
+            let output_ptr = vm.add_memory_segment();
+            write_collection_to_addr(output_ptr, &payload, vm)?;
+            insert_value_from_var_name("output_ptr", output_ptr, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name(
+                "output_len",
+                Felt252::from(payload.len()),
+                vm,
+                ids_data,
+                ap_tracking,
+            )?;
+            let is_list = if header.list { Felt252::ONE } else { Felt252::ZERO };
+            insert_value_from_var_name("is_list", is_list, vm, ids_data, ap_tracking)
+        },
+    )
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::hint_processor_definition::HintReference,
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use k256::{
+    elliptic_curve::{
+        ops::Reduce,
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+    },
+    AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar, U256,
+};
+use num_bigint::BigUint;
+
+use crate::vm::{hint_utils::Uint256, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] = &[secp256k1_add, secp256k1_mul];
+
+/// Adds the secp256k1 points `(x1, y1)` and `(x2, y2)` given in affine coordinates, and writes the
+/// result to `(x3, y3)`. Each coordinate is a `Uint256`, and `(0, 0)` encodes the point at
+/// infinity.
+pub fn secp256k1_add() -> Hint {
+    Hint::new(
+        String::from("secp256k1_add"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let p1 = read_point(["x1", "y1"], vm, ids_data, ap_tracking)?;
+            let p2 = read_point(["x2", "y2"], vm, ids_data, ap_tracking)?;
+            write_point(p1 + p2, ["x3", "y3"], vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Multiplies the secp256k1 point `(x, y)` by `scalar`, reduced modulo the curve order, and writes
+/// the result to `(x_res, y_res)`. Coordinates are encoded as in `secp256k1_add`.
+pub fn secp256k1_mul() -> Hint {
+    Hint::new(
+        String::from("secp256k1_mul"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let point = read_point(["x", "y"], vm, ids_data, ap_tracking)?;
+            let scalar = Uint256::from_var_name("scalar", vm, ids_data, ap_tracking)?.pack();
+            let scalar = <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(
+                to_bytes32(&scalar).ok_or_else(|| {
+                    HintError::CustomHint(Box::from("scalar is not a valid Uint256"))
+                })?,
+            ));
+            write_point(point * scalar, ["x_res", "y_res"], vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Reads a point given as `Uint256` affine coordinates, checking it lies on the curve.
+fn read_point(
+    [x, y]: [&str; 2],
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<ProjectivePoint, HintError> {
+    let not_on_curve =
+        || HintError::CustomHint(Box::from(format!("({}, {}) is not on the curve", x, y)));
+    let x_value = Uint256::from_var_name(x, vm, ids_data, ap_tracking)?.pack();
+    let y_value = Uint256::from_var_name(y, vm, ids_data, ap_tracking)?.pack();
+    let x_bytes = to_bytes32(&x_value).ok_or_else(not_on_curve)?;
+    let y_bytes = to_bytes32(&y_value).ok_or_else(not_on_curve)?;
+    if x_bytes == [0; 32] && y_bytes == [0; 32] {
+        return Ok(ProjectivePoint::IDENTITY);
+    }
+
+    let encoded = EncodedPoint::from_affine_coordinates(
+        &FieldBytes::from(x_bytes),
+        &FieldBytes::from(y_bytes),
+        false,
+    );
+    let point: Option<AffinePoint> = AffinePoint::from_encoded_point(&encoded).into();
+    point.map(ProjectivePoint::from).ok_or_else(not_on_curve)
+}
+
+/// Writes the affine coordinates of `point` as `Uint256`, the point at infinity as `(0, 0)`.
+fn write_point(
+    point: ProjectivePoint,
+    [x, y]: [&str; 2],
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let encoded = point.to_affine().to_encoded_point(false);
+    let (x_value, y_value) = match (encoded.x(), encoded.y()) {
+        (Some(x_bytes), Some(y_bytes)) => {
+            (BigUint::from_bytes_be(x_bytes), BigUint::from_bytes_be(y_bytes))
+        }
+        _ => (BigUint::ZERO, BigUint::ZERO),
+    };
+    Uint256::split(&x_value)._insert_from_var_name(x, vm, ids_data, ap_tracking)?;
+    Uint256::split(&y_value)._insert_from_var_name(y, vm, ids_data, ap_tracking)
+}
+
+/// Returns the 32-byte big-endian encoding of `value`, or `None` if it doesn't fit.
+fn to_bytes32(value: &BigUint) -> Option<[u8; 32]> {
+    let bytes = value.to_bytes_be();
+    if bytes.len() > 32 {
+        return None;
+    }
+    let mut output = [0u8; 32];
+    output[32 - bytes.len()..].copy_from_slice(&bytes);
+    Some(output)
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use revm::primitives::keccak256;
+
+use crate::vm::{hint_utils::Uint256, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] = &[compute_mapping_slot, compute_nested_mapping_slot];
+
+const WORD_SIZE: usize = 32;
+
+/// Computes the storage slot of `mapping[key]` for a mapping stored at `base_slot`, i.e.
+/// `keccak256(abi.encode(key, base_slot))`, and writes it to `ids.slot_lo` and `ids.slot_hi`.
+pub fn compute_mapping_slot() -> Hint {
+    Hint::new(
+        String::from("compute_mapping_slot"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let key = read_word("key", vm, ids_data, ap_tracking)?;
+            let base_slot = read_word("base_slot", vm, ids_data, ap_tracking)?;
+            let slot = mapping_slot(&key, &base_slot);
+            write_slot(&slot, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Computes the storage slot of `mapping[key][inner_key]` for a nested mapping stored at
+/// `base_slot`, and writes it to `ids.slot_lo` and `ids.slot_hi`.
+pub fn compute_nested_mapping_slot() -> Hint {
+    Hint::new(
+        String::from("compute_nested_mapping_slot"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let key = read_word("key", vm, ids_data, ap_tracking)?;
+            let inner_key = read_word("inner_key", vm, ids_data, ap_tracking)?;
+            let base_slot = read_word("base_slot", vm, ids_data, ap_tracking)?;
+            let slot = mapping_slot(&inner_key, &mapping_slot(&key, &base_slot));
+            write_slot(&slot, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+fn mapping_slot(key: &[u8; WORD_SIZE], base_slot: &[u8; WORD_SIZE]) -> [u8; WORD_SIZE] {
+    keccak256([key.as_slice(), base_slot.as_slice()].concat()).0
+}
+
+/// Reads the 256-bit word split in `ids.{name}_lo` and `ids.{name}_hi` as 32 big-endian bytes.
+fn read_word(
+    name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<[u8; WORD_SIZE], HintError> {
+    let low = get_integer_from_var_name(&format!("{}_lo", name), vm, ids_data, ap_tracking)?;
+    let high = get_integer_from_var_name(&format!("{}_hi", name), vm, ids_data, ap_tracking)?;
+    if low.bits() > 128 || high.bits() > 128 {
+        return Err(HintError::CustomHint(format!("{} limbs exceed 128 bits", name).into()));
+    }
+
+    let value = Uint256::from_values(low, high).pack().to_bytes_be();
+    let mut word = [0u8; WORD_SIZE];
+    word[WORD_SIZE - value.len()..].copy_from_slice(&value);
+    Ok(word)
+}
+
+fn write_slot(
+    slot: &[u8; WORD_SIZE],
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let Uint256 { low, high } = Uint256::split(&BigUint::from_bytes_be(slot));
+    insert_value_from_var_name("slot_lo", low.into_owned(), vm, ids_data, ap_tracking)?;
+    insert_value_from_var_name("slot_hi", high.into_owned(), vm, ids_data, ap_tracking)
+}
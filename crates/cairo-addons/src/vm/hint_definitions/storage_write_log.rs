@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, get_maybe_relocatable_from_var_name,
+            insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::vm::hints::Hint;
+
+pub const HINTS: &[fn() -> Hint] =
+    &[log_storage_write, get_storage_write_log_len, flush_storage_write_log];
+
+/// Name of the execution scope variable holding the storage write log.
+const STORAGE_WRITE_LOG: &str = "storage_write_log";
+
+/// A logged storage write: `(address, key, value)`.
+type StorageWrite = (Felt252, Felt252, MaybeRelocatable);
+
+/// Appends `(ids.address, ids.key, ids.value)` to the storage write log.
+pub fn log_storage_write() -> Hint {
+    Hint::new(
+        String::from("log_storage_write"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let address = get_integer_from_var_name("address", vm, ids_data, ap_tracking)?;
+            let key = get_integer_from_var_name("key", vm, ids_data, ap_tracking)?;
+            let value = get_maybe_relocatable_from_var_name("value", vm, ids_data, ap_tracking)?;
+            storage_write_log(exec_scopes)?.push((address, key, value));
+            Ok(())
+        },
+    )
+}
+
+/// Writes the number of logged storage writes to `ids.len`.
+pub fn get_storage_write_log_len() -> Hint {
+    Hint::new(
+        String::from("get_storage_write_log_len"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let len = storage_write_log(exec_scopes)?.len();
+            insert_value_from_var_name("len", Felt252::from(len), vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Moves the storage write log to a new segment, as consecutive `(address, key, value)` triples,
+/// and clears it. The segment base is written to `ids.log_ptr` and the number of writes to
+/// `ids.log_len`.
+pub fn flush_storage_write_log() -> Hint {
+    Hint::new(
+        String::from("flush_storage_write_log"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let log = std::mem::take(storage_write_log(exec_scopes)?);
+            let data = log
+                .iter()
+                .flat_map(|(address, key, value)| {
+                    [MaybeRelocatable::from(*address), MaybeRelocatable::from(*key), value.clone()]
+                })
+                .collect::<Vec<MaybeRelocatable>>();
+
+            let log_ptr = vm.add_memory_segment();
+            vm.load_data(log_ptr, &data)?;
+            insert_value_from_var_name("log_ptr", log_ptr, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name(
+                "log_len",
+                Felt252::from(log.len()),
+                vm,
+                ids_data,
+                ap_tracking,
+            )
+        },
+    )
+}
+
+/// Returns the storage write log of the current scope, creating it if needed.
+fn storage_write_log(
+    exec_scopes: &mut ExecutionScopes,
+) -> Result<&mut Vec<StorageWrite>, HintError> {
+    if exec_scopes.get_ref::<Vec<StorageWrite>>(STORAGE_WRITE_LOG).is_err() {
+        exec_scopes
+            .assign_or_update_variable(STORAGE_WRITE_LOG, Box::new(Vec::<StorageWrite>::new()));
+    }
+    exec_scopes.get_mut_ref::<Vec<StorageWrite>>(STORAGE_WRITE_LOG)
+}
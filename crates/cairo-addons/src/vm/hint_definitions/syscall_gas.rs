@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{errors::math_errors::MathError, exec_scope::ExecutionScopes},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::vm::{
+    hint_utils::{read_short_string_from_var_name, read_usize_from_var_name},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[init_syscall_gas, charge_syscall_gas];
+
+/// Name of the execution scope variable holding the gas left for syscalls.
+const REMAINING_GAS: &str = "remaining_gas";
+
+/// Gas cost of a syscall: a fixed `base` cost, plus `per_item` for each item counted by the
+/// `ids` variable `item_count`, if any.
+struct SyscallGasCost {
+    base: u64,
+    per_item: u64,
+    item_count: Option<&'static str>,
+}
+
+/// Gas costs of the supported syscalls, keyed by their selector.
+const SYSCALL_GAS_COSTS: &[(&str, SyscallGasCost)] = &[
+    ("StorageRead", SyscallGasCost { base: 50, per_item: 0, item_count: None }),
+    ("StorageWrite", SyscallGasCost { base: 50, per_item: 0, item_count: None }),
+    ("EmitEvent", SyscallGasCost { base: 0, per_item: 100, item_count: Some("topic_count") }),
+    ("GetBlockHash", SyscallGasCost { base: 50, per_item: 0, item_count: None }),
+    ("SendMessageToL1", SyscallGasCost { base: 50, per_item: 0, item_count: None }),
+];
+
+/// Sets the gas available for syscalls to `ids.initial_gas`.
+pub fn init_syscall_gas() -> Hint {
+    Hint::new(
+        String::from("init_syscall_gas"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let initial_gas = get_integer_from_var_name("initial_gas", vm, ids_data, ap_tracking)?;
+            exec_scopes.insert_value(REMAINING_GAS, initial_gas);
+            Ok(())
+        },
+    )
+}
+
+/// Charges the gas cost of the syscall whose selector is the short string `ids.syscall_type`.
+///
+/// The cost is looked up in `SYSCALL_GAS_COSTS` and subtracted from the `remaining_gas` scope
+/// variable, then written to `ids.gas_charged`. Fails without charging anything if the remaining
+/// gas is not enough.
+pub fn charge_syscall_gas() -> Hint {
+    Hint::new(
+        String::from("charge_syscall_gas"),
+        |vm: &mut VirtualMachine,
+         exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let syscall_type =
+                read_short_string_from_var_name("syscall_type", vm, ids_data, ap_tracking)?;
+            let cost = SYSCALL_GAS_COSTS
+                .iter()
+                .find_map(|(name, cost)| (*name == syscall_type).then_some(cost))
+                .ok_or_else(|| {
+                    HintError::CustomHint(format!("Unknown syscall: {}", syscall_type).into())
+                })?;
+
+            let item_count = match cost.item_count {
+                Some(name) => read_usize_from_var_name(name, vm, ids_data, ap_tracking)? as u64,
+                None => 0,
+            };
+            let required = cost.base.saturating_add(cost.per_item.saturating_mul(item_count));
+
+            let remaining_gas = exec_scopes.get::<Felt252>(REMAINING_GAS)?;
+            let available: u64 = remaining_gas
+                .try_into()
+                .map_err(|_| MathError::Felt252ToU64Conversion(Box::new(remaining_gas)))?;
+            if required > available {
+                return Err(HintError::CustomHint(
+                    format!("Out of gas: required {}, available {}", required, available).into(),
+                ));
+            }
+
+            exec_scopes.insert_value(REMAINING_GAS, Felt252::from(available - required));
+            insert_value_from_var_name(
+                "gas_charged",
+                Felt252::from(required),
+                vm,
+                ids_data,
+                ap_tracking,
+            )
+        },
+    )
+}
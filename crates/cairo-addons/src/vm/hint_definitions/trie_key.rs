@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use revm::primitives::keccak256;
+
+use crate::vm::{
+    hint_utils::{write_collection_to_addr, Uint256},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] = &[compute_storage_trie_key];
+
+const WORD_SIZE: usize = 32;
+
+/// Computes the storage trie key of a slot and writes it to `ids.trie_key_lo` and
+/// `ids.trie_key_hi`.
+///
+/// If `ids.is_mapping` is 1, the key is `keccak256(key ++ slot)`, for the entry `ids.key` of the
+/// mapping stored at `ids.slot`. Otherwise, it is `keccak256(slot)`, for a state variable stored at
+/// `ids.slot`. The 64 nibbles of the trie key, most significant first, are written to a new
+/// segment whose base is written to `ids.trie_path`.
+pub fn compute_storage_trie_key() -> Hint {
+    Hint::new(
+        String::from("compute_storage_trie_key"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let is_mapping = get_integer_from_var_name("is_mapping", vm, ids_data, ap_tracking)?;
+            let slot = read_word("slot", vm, ids_data, ap_tracking)?;
+            let preimage = if is_mapping == Felt252::ONE {
+                [read_word("key", vm, ids_data, ap_tracking)?, slot].concat()
+            } else if is_mapping == Felt252::ZERO {
+                slot.to_vec()
+            } else {
+                return Err(HintError::CustomHint("is_mapping must be 0 or 1".into()));
+            };
+
+            let trie_key = keccak256(preimage).0;
+            let Uint256 { low, high } = Uint256::split(&BigUint::from_bytes_be(&trie_key));
+            insert_value_from_var_name("trie_key_lo", low.into_owned(), vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name(
+                "trie_key_hi",
+                high.into_owned(),
+                vm,
+                ids_data,
+                ap_tracking,
+            )?;
+
+            let nibbles = trie_key
+                .iter()
+                .flat_map(|byte| [Felt252::from(byte >> 4), Felt252::from(byte & 0x0f)])
+                .collect::<Vec<_>>();
+            let trie_path = vm.add_memory_segment();
+            write_collection_to_addr(trie_path, &nibbles, vm)?;
+            insert_value_from_var_name("trie_path", trie_path, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Reads the 256-bit word split in `ids.{name}_lo` and `ids.{name}_hi` as 32 big-endian bytes.
+fn read_word(
+    name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<[u8; WORD_SIZE], HintError> {
+    let low = get_integer_from_var_name(&format!("{}_lo", name), vm, ids_data, ap_tracking)?;
+    let high = get_integer_from_var_name(&format!("{}_hi", name), vm, ids_data, ap_tracking)?;
+    if low.bits() > 128 || high.bits() > 128 {
+        return Err(HintError::CustomHint(format!("{} limbs exceed 128 bits", name).into()));
+    }
+
+    let value = Uint256::from_values(low, high).pack().to_bytes_be();
+    let mut word = [0u8; WORD_SIZE];
+    word[WORD_SIZE - value.len()..].copy_from_slice(&value);
+    Ok(word)
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{
+        errors::math_errors::MathError,
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use num_traits::Zero;
+use revm::primitives::keccak256;
+
+use crate::vm::{
+    hint_utils::{mpt::encode_list, read_bytes32, read_bytes_struct, Uint256},
+    hints::Hint,
+};
+
+pub const HINTS: &[fn() -> Hint] =
+    &[compute_legacy_tx_hash, compute_eip2930_tx_hash, compute_eip1559_tx_hash];
+
+const ADDRESS_SIZE: usize = 20;
+const ACCESS_LIST_TX_TYPE: u8 = 1;
+const FEE_MARKET_TX_TYPE: u8 = 2;
+
+/// Computes the signing hash of the `LegacyTransaction` `ids.tx` and writes it to
+/// `ids.hash_lo` and `ids.hash_hi`.
+///
+/// The hash follows EIP-155 for the chain `ids.chain_id`, or the pre-EIP-155 format if
+/// `ids.chain_id` is 0.
+pub fn compute_legacy_tx_hash() -> Hint {
+    Hint::new(
+        String::from("compute_legacy_tx_hash"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let tx = get_ptr_from_var_name("tx", vm, ids_data, ap_tracking)?;
+            let chain_id = get_integer_from_var_name("chain_id", vm, ids_data, ap_tracking)?;
+
+            let mut fields = vec![
+                encode_u256(vm, tx)?,         // nonce
+                encode_felt(vm, (tx + 1)?)?,  // gas_price
+                encode_felt(vm, (tx + 2)?)?,  // gas
+                encode_to(vm, (tx + 3)?)?,    // to
+                encode_u256(vm, (tx + 4)?)?,  // value
+                encode_bytes(vm, (tx + 5)?)?, // data
+            ];
+            if !chain_id.is_zero() {
+                fields.push(encode_uint(&chain_id.to_biguint()));
+                fields.push(encode_uint(&BigUint::zero()));
+                fields.push(encode_uint(&BigUint::zero()));
+            }
+
+            write_hash(&encode_list(&fields), vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Computes the signing hash of the EIP-2930 `AccessListTransaction` `ids.tx` and writes it to
+/// `ids.hash_lo` and `ids.hash_hi`.
+pub fn compute_eip2930_tx_hash() -> Hint {
+    Hint::new(
+        String::from("compute_eip2930_tx_hash"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let tx = get_ptr_from_var_name("tx", vm, ids_data, ap_tracking)?;
+            let fields = [
+                encode_felt(vm, tx)?,               // chain_id
+                encode_u256(vm, (tx + 1)?)?,        // nonce
+                encode_felt(vm, (tx + 2)?)?,        // gas_price
+                encode_felt(vm, (tx + 3)?)?,        // gas
+                encode_to(vm, (tx + 4)?)?,          // to
+                encode_u256(vm, (tx + 5)?)?,        // value
+                encode_bytes(vm, (tx + 6)?)?,       // data
+                encode_access_list(vm, (tx + 7)?)?, // access_list
+            ];
+
+            let encoded_tx = [&[ACCESS_LIST_TX_TYPE][..], encode_list(&fields).as_slice()].concat();
+            write_hash(&encoded_tx, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Computes the signing hash of the EIP-1559 `FeeMarketTransaction` `ids.tx` and writes it to
+/// `ids.hash_lo` and `ids.hash_hi`.
+pub fn compute_eip1559_tx_hash() -> Hint {
+    Hint::new(
+        String::from("compute_eip1559_tx_hash"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let tx = get_ptr_from_var_name("tx", vm, ids_data, ap_tracking)?;
+            let fields = [
+                encode_felt(vm, tx)?,               // chain_id
+                encode_u256(vm, (tx + 1)?)?,        // nonce
+                encode_felt(vm, (tx + 2)?)?,        // max_priority_fee_per_gas
+                encode_felt(vm, (tx + 3)?)?,        // max_fee_per_gas
+                encode_felt(vm, (tx + 4)?)?,        // gas
+                encode_to(vm, (tx + 5)?)?,          // to
+                encode_u256(vm, (tx + 6)?)?,        // value
+                encode_bytes(vm, (tx + 7)?)?,       // data
+                encode_access_list(vm, (tx + 8)?)?, // access_list
+            ];
+
+            let encoded_tx = [&[FEE_MARKET_TX_TYPE][..], encode_list(&fields).as_slice()].concat();
+            write_hash(&encoded_tx, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes `keccak256(encoded_tx)`, as a big-endian integer, to `ids.hash_lo` and `ids.hash_hi`.
+fn write_hash(
+    encoded_tx: &[u8],
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    let hash = keccak256(encoded_tx).0;
+    let hash_lo = Felt252::from_bytes_be_slice(&hash[16..]);
+    let hash_hi = Felt252::from_bytes_be_slice(&hash[..16]);
+    insert_value_from_var_name("hash_lo", hash_lo, vm, ids_data, ap_tracking)?;
+    insert_value_from_var_name("hash_hi", hash_hi, vm, ids_data, ap_tracking)
+}
+
+/// RLP-encodes an unsigned integer as its minimal big-endian representation.
+fn encode_uint(value: &BigUint) -> Vec<u8> {
+    if value.is_zero() {
+        return alloy_rlp::encode(&b""[..]);
+    }
+    alloy_rlp::encode(value.to_bytes_be().as_slice())
+}
+
+/// Encodes the `Uint` or `U64` at `addr`.
+fn encode_felt(vm: &VirtualMachine, addr: Relocatable) -> Result<Vec<u8>, HintError> {
+    Ok(encode_uint(&vm.get_integer(addr)?.to_biguint()))
+}
+
+/// Encodes the `U256` at `addr`, a pointer to a `U256Struct`.
+fn encode_u256(vm: &VirtualMachine, addr: Relocatable) -> Result<Vec<u8>, HintError> {
+    let value = Uint256::from_base_addr(vm.get_relocatable(addr)?, "U256", vm)?.pack();
+    Ok(encode_uint(&value))
+}
+
+/// Encodes the `Bytes` at `addr`, a pointer to a `BytesStruct`.
+fn encode_bytes(vm: &VirtualMachine, addr: Relocatable) -> Result<Vec<u8>, HintError> {
+    let data = read_bytes_struct(vm, vm.get_relocatable(addr)?)?;
+    Ok(alloy_rlp::encode(data.as_slice()))
+}
+
+/// Encodes the `To` at `addr`: the address of the callee, or an empty string for contract
+/// creations.
+fn encode_to(vm: &VirtualMachine, addr: Relocatable) -> Result<Vec<u8>, HintError> {
+    let to = vm.get_relocatable(addr)?;
+    match vm.get_maybe(&(to + 1)?) {
+        Some(MaybeRelocatable::RelocatableValue(address_ptr)) => {
+            Ok(encode_address(vm.get_integer(address_ptr)?.as_ref()))
+        }
+        _ => Ok(alloy_rlp::encode(&b""[..])),
+    }
+}
+
+/// Encodes an address, stored as a little-endian felt.
+fn encode_address(address: &Felt252) -> Vec<u8> {
+    alloy_rlp::encode(&address.to_bytes_le()[..ADDRESS_SIZE])
+}
+
+/// Encodes the `TupleAccessList` at `addr` as `rlp([[address, [storage_keys...]]...])`.
+fn encode_access_list(vm: &VirtualMachine, addr: Relocatable) -> Result<Vec<u8>, HintError> {
+    let entries = read_tuple(vm, vm.get_relocatable(addr)?)?
+        .into_iter()
+        .map(|entry| {
+            let address = encode_address(vm.get_integer(entry)?.as_ref());
+            let storage_keys = read_tuple(vm, vm.get_relocatable((entry + 1)?)?)?
+                .into_iter()
+                .map(|key| Ok(alloy_rlp::encode(read_bytes32(vm, key)?.as_slice())))
+                .collect::<Result<Vec<_>, HintError>>()?;
+            Ok(encode_list(&[address, encode_list(&storage_keys)]))
+        })
+        .collect::<Result<Vec<_>, HintError>>()?;
+    Ok(encode_list(&entries))
+}
+
+/// Reads the pointers stored in the `(data, len)` tuple struct at `tuple_ptr`.
+fn read_tuple(vm: &VirtualMachine, tuple_ptr: Relocatable) -> Result<Vec<Relocatable>, HintError> {
+    let len = vm.get_integer((tuple_ptr + 1)?)?.into_owned();
+    let len: usize =
+        len.try_into().map_err(|_| MathError::Felt252ToUsizeConversion(Box::new(len)))?;
+    if len == 0 {
+        return Ok(vec![]);
+    }
+    let data = vm.get_relocatable(tuple_ptr)?;
+    (0..len).map(|i| Ok(vm.get_relocatable((data + i)?)?)).collect()
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+
+use crate::vm::hints::Hint;
+
+pub const HINTS: &[fn() -> Hint] = &[u8_add_with_carry, u8_xor, u8_and, u8_or, u8_rotate_right];
+
+/// Writes `(ids.a + ids.b) % 256` to `ids.result` and the carry of the addition to `ids.carry`.
+pub fn u8_add_with_carry() -> Hint {
+    Hint::new(
+        String::from("u8_add_with_carry"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let (a, b) = read_operands(vm, ids_data, ap_tracking)?;
+            let (result, carry) = a.overflowing_add(b);
+            let carry = if carry { Felt252::ONE } else { Felt252::ZERO };
+            insert_value_from_var_name("carry", carry, vm, ids_data, ap_tracking)?;
+            write_result(result, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes `ids.a ^ ids.b` to `ids.result`.
+pub fn u8_xor() -> Hint {
+    Hint::new(
+        String::from("u8_xor"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let (a, b) = read_operands(vm, ids_data, ap_tracking)?;
+            write_result(a ^ b, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes `ids.a & ids.b` to `ids.result`.
+pub fn u8_and() -> Hint {
+    Hint::new(
+        String::from("u8_and"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let (a, b) = read_operands(vm, ids_data, ap_tracking)?;
+            write_result(a & b, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes `ids.a | ids.b` to `ids.result`.
+pub fn u8_or() -> Hint {
+    Hint::new(
+        String::from("u8_or"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let (a, b) = read_operands(vm, ids_data, ap_tracking)?;
+            write_result(a | b, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Writes `ids.a` rotated right by `ids.b` bits to `ids.result`. Rotations are taken modulo 8.
+pub fn u8_rotate_right() -> Hint {
+    Hint::new(
+        String::from("u8_rotate_right"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let (a, b) = read_operands(vm, ids_data, ap_tracking)?;
+            write_result(a.rotate_right(u32::from(b)), vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Reads `ids.a` and `ids.b`, checking that both are bytes.
+fn read_operands(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(u8, u8), HintError> {
+    let read_u8 = |name: &str| -> Result<u8, HintError> {
+        let value = get_integer_from_var_name(name, vm, ids_data, ap_tracking)?;
+        value.to_u8().ok_or_else(|| {
+            HintError::CustomHint(format!("{} out of range: {}", name, value).into())
+        })
+    };
+    Ok((read_u8("a")?, read_u8("b")?))
+}
+
+fn write_result(
+    result: u8,
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), HintError> {
+    insert_value_from_var_name("result", Felt252::from(result), vm, ids_data, ap_tracking)
+}
@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+
+use crate::vm::{hint_utils::Uint256, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] = &[uint256_add, uint256_mul, uint256_divmod];
+
+/// Adds the 256-bit integers `(ids.a_low, ids.a_high)` and `(ids.b_low, ids.b_high)`, whose limbs
+/// must fit in 128 bits, and writes the sum modulo `2**256` to `(ids.res_low, ids.res_high)` and
+/// the carry to `ids.carry`.
+pub fn uint256_add() -> Hint {
+    Hint::new(
+        String::from("uint256_add"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let a_low = read_u128("a_low", vm, ids_data, ap_tracking)?;
+            let a_high = read_u128("a_high", vm, ids_data, ap_tracking)?;
+            let b_low = read_u128("b_low", vm, ids_data, ap_tracking)?;
+            let b_high = read_u128("b_high", vm, ids_data, ap_tracking)?;
+
+            let (res_low, carry_low) = a_low.overflowing_add(b_low);
+            let (res_high, carry_high) = a_high.overflowing_add(b_high);
+            let (res_high, carry_low_into_high) = res_high.overflowing_add(u128::from(carry_low));
+            let carry = carry_high || carry_low_into_high;
+
+            insert_value_from_var_name(
+                "res_low",
+                Felt252::from(res_low),
+                vm,
+                ids_data,
+                ap_tracking,
+            )?;
+            insert_value_from_var_name(
+                "res_high",
+                Felt252::from(res_high),
+                vm,
+                ids_data,
+                ap_tracking,
+            )?;
+            insert_value_from_var_name("carry", Felt252::from(carry), vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Multiplies the 256-bit integers `(ids.a_low, ids.a_high)` and `(ids.b_low, ids.b_high)`, whose
+/// limbs must fit in 128 bits, and writes the 512-bit product to the 128-bit limbs `ids.res_0` to
+/// `ids.res_3`, least significant first.
+pub fn uint256_mul() -> Hint {
+    Hint::new(
+        String::from("uint256_mul"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let a_low = read_u128("a_low", vm, ids_data, ap_tracking)?;
+            let a_high = read_u128("a_high", vm, ids_data, ap_tracking)?;
+            let b_low = read_u128("b_low", vm, ids_data, ap_tracking)?;
+            let b_high = read_u128("b_high", vm, ids_data, ap_tracking)?;
+
+            // Schoolbook multiplication on 128-bit limbs, propagating the carries of each column.
+            let (p00_low, p00_high) = widening_mul(a_low, b_low);
+            let (p01_low, p01_high) = widening_mul(a_low, b_high);
+            let (p10_low, p10_high) = widening_mul(a_high, b_low);
+            let (p11_low, p11_high) = widening_mul(a_high, b_high);
+
+            let res_0 = p00_low;
+            let (res_1, carry_1) = add_with_carry(&[p00_high, p01_low, p10_low]);
+            let (res_2, carry_2) = add_with_carry(&[p01_high, p10_high, p11_low, carry_1]);
+            // The product fits in 512 bits, so the last column can't overflow.
+            let res_3 = p11_high + carry_2;
+
+            for (name, limb) in
+                [("res_0", res_0), ("res_1", res_1), ("res_2", res_2), ("res_3", res_3)]
+            {
+                insert_value_from_var_name(name, Felt252::from(limb), vm, ids_data, ap_tracking)?;
+            }
+            Ok(())
+        },
+    )
+}
+
+/// Divides the 256-bit integer `(ids.dividend_low, ids.dividend_high)` by `(ids.divisor_low,
+/// ids.divisor_high)`, whose limbs must fit in 128 bits, and writes the truncated quotient to
+/// `(ids.quotient_low, ids.quotient_high)` and the remainder to `(ids.remainder_low,
+/// ids.remainder_high)`. Fails on division by zero.
+pub fn uint256_divmod() -> Hint {
+    Hint::new(
+        String::from("uint256_divmod"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let dividend = read_uint256("dividend", vm, ids_data, ap_tracking)?;
+            let divisor = read_uint256("divisor", vm, ids_data, ap_tracking)?;
+            if divisor.is_zero() {
+                return Err(HintError::CustomHint(
+                    format!("Division by zero: cannot divide {} by 0", dividend).into(),
+                ));
+            }
+
+            for (name, value) in
+                [("quotient", &dividend / &divisor), ("remainder", &dividend % &divisor)]
+            {
+                let Uint256 { low, high } = Uint256::split(&value);
+                insert_value_from_var_name(
+                    &format!("{}_low", name),
+                    low.into_owned(),
+                    vm,
+                    ids_data,
+                    ap_tracking,
+                )?;
+                insert_value_from_var_name(
+                    &format!("{}_high", name),
+                    high.into_owned(),
+                    vm,
+                    ids_data,
+                    ap_tracking,
+                )?;
+            }
+            Ok(())
+        },
+    )
+}
+
+/// Returns the low and high 128 bits of the 256-bit product `a * b`.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_low, a_high) = (a & MASK, a >> 64);
+    let (b_low, b_high) = (b & MASK, b >> 64);
+
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+
+    let middle = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+    let low = (low_low & MASK) | (middle << 64);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+    (low, high)
+}
+
+/// Returns the sum of `values` modulo `2**128` and the number of times it overflowed.
+fn add_with_carry(values: &[u128]) -> (u128, u128) {
+    values.iter().fold((0, 0), |(sum, carry), value| {
+        let (sum, overflow) = sum.overflowing_add(*value);
+        (sum, carry + u128::from(overflow))
+    })
+}
+
+/// Reads the 256-bit integer `(ids.{name}_low, ids.{name}_high)`.
+fn read_uint256(
+    name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<BigUint, HintError> {
+    let low = read_u128(&format!("{}_low", name), vm, ids_data, ap_tracking)?;
+    let high = read_u128(&format!("{}_high", name), vm, ids_data, ap_tracking)?;
+    Ok((BigUint::from(high) << 128) + low)
+}
+
+fn read_u128(
+    name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<u128, HintError> {
+    let value = get_integer_from_var_name(name, vm, ids_data, ap_tracking)?;
+    value
+        .to_u128()
+        .ok_or_else(|| HintError::CustomHint(format!("{} exceeds 128 bits", name).into()))
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+        hint_processor_definition::HintReference,
+    },
+    serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+
+use crate::vm::{hint_utils::write_collection_to_addr, hints::Hint};
+
+pub const HINTS: &[fn() -> Hint] =
+    &[reverse_bytes32, reverse_bytes16, reverse_bytes8, swap_bytes32_ptr];
+
+/// Number of bytes in an EVM word.
+const WORD_SIZE: usize = 32;
+
+/// Reverses the byte order of the 32-byte word `ids.input_hi * 2**128 + ids.input_lo` and writes
+/// the result to `ids.output_lo` and `ids.output_hi`.
+pub fn reverse_bytes32() -> Hint {
+    Hint::new(
+        String::from("reverse_bytes32"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let input_lo = read_u128("input_lo", vm, ids_data, ap_tracking)?;
+            let input_hi = read_u128("input_hi", vm, ids_data, ap_tracking)?;
+            // Reversing the whole word swaps the halves and reverses each of them.
+            let output_lo = Felt252::from(input_hi.swap_bytes());
+            let output_hi = Felt252::from(input_lo.swap_bytes());
+            insert_value_from_var_name("output_lo", output_lo, vm, ids_data, ap_tracking)?;
+            insert_value_from_var_name("output_hi", output_hi, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Reverses the byte order of the 16-byte word `ids.input` and writes the result to
+/// `ids.output`.
+pub fn reverse_bytes16() -> Hint {
+    Hint::new(
+        String::from("reverse_bytes16"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let input = read_u128("input", vm, ids_data, ap_tracking)?;
+            let output = Felt252::from(input.swap_bytes());
+            insert_value_from_var_name("output", output, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Reverses the byte order of the 8-byte word `ids.input` and writes the result to `ids.output`.
+pub fn reverse_bytes8() -> Hint {
+    Hint::new(
+        String::from("reverse_bytes8"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let input = get_integer_from_var_name("input", vm, ids_data, ap_tracking)?
+                .to_u64()
+                .ok_or_else(|| HintError::CustomHint("input exceeds 64 bits".into()))?;
+            let output = Felt252::from(input.swap_bytes());
+            insert_value_from_var_name("output", output, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+/// Copies the 32 felts at `ids.input_ptr` in reverse order to a new segment, whose base is
+/// written to `ids.output_ptr`.
+pub fn swap_bytes32_ptr() -> Hint {
+    Hint::new(
+        String::from("swap_bytes32_ptr"),
+        |vm: &mut VirtualMachine,
+         _exec_scopes: &mut ExecutionScopes,
+         ids_data: &HashMap<String, HintReference>,
+         ap_tracking: &ApTracking,
+         _constants: &HashMap<String, Felt252>|
+         -> Result<(), HintError> {
+            let input_ptr = get_ptr_from_var_name("input_ptr", vm, ids_data, ap_tracking)?;
+            let mut bytes = vm
+                .get_integer_range(input_ptr, WORD_SIZE)?
+                .into_iter()
+                .map(|byte| byte.into_owned())
+                .collect::<Vec<_>>();
+            bytes.reverse();
+
+            let output_ptr = vm.add_memory_segment();
+            write_collection_to_addr(output_ptr, &bytes, vm)?;
+            insert_value_from_var_name("output_ptr", output_ptr, vm, ids_data, ap_tracking)
+        },
+    )
+}
+
+fn read_u128(
+    name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<u128, HintError> {
+    get_integer_from_var_name(name, vm, ids_data, ap_tracking)?
+        .to_u128()
+        .ok_or_else(|| HintError::CustomHint(format!("{} exceeds 128 bits", name).into()))
+}
@@ -1,3 +1,4 @@
+use cairo_vm::vm::errors::hint_errors::HintError;
 use pyo3::{prelude::*, types::PyDict};
 use std::collections::HashMap;
 
@@ -15,3 +16,27 @@ pub fn load_python_hints() -> PyResult<HashMap<String, String>> {
         Ok(hints)
     })
 }
+
+/// Handles a hint unknown to the hint processor when no dynamic Python hint executor is set.
+///
+/// With the `dynamic-hints` feature, dynamic hints were disabled at runtime and the hint is
+/// silently ignored.
+#[cfg(feature = "dynamic-hints")]
+pub fn handle_unknown_hint(_hint_code: &str) -> Result<(), HintError> {
+    Ok(())
+}
+
+/// Handles a hint unknown to the hint processor when no dynamic Python hint executor is set.
+///
+/// Without the `dynamic-hints` feature, hints can't be executed as Python code, so the hint fails
+/// with an error pointing to the missing feature.
+#[cfg(not(feature = "dynamic-hints"))]
+pub fn handle_unknown_hint(hint_code: &str) -> Result<(), HintError> {
+    Err(HintError::CustomHint(
+        format!(
+            "Unknown hint '{}': dynamic hints not enabled; rebuild with --features dynamic-hints",
+            hint_code
+        )
+        .into(),
+    ))
+}
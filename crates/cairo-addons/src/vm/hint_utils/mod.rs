@@ -1,7 +1,11 @@
+pub(crate) mod mpt;
+#[cfg(feature = "hint-profiling")]
+pub(crate) mod profiler;
+
 use cairo_vm::{
     hint_processor::{
         builtin_hint_processor::hint_utils::{
-            get_ptr_from_var_name, get_relocatable_from_var_name,
+            get_integer_from_var_name, get_ptr_from_var_name, get_relocatable_from_var_name,
         },
         hint_processor_definition::HintReference,
     },
@@ -15,7 +19,8 @@ use cairo_vm::{
     Felt252,
 };
 use num_bigint::{BigInt, BigUint};
-use num_traits::One;
+use num_traits::{One, ToPrimitive};
+use starknet_crypto::pedersen_hash;
 
 use std::{borrow::Cow, collections::HashMap};
 pub fn serialize_sequence(
@@ -294,3 +299,75 @@ pub(crate) fn write_collection_from_var_name(
     };
     write_collection_to_addr(addr, collection, vm)
 }
+
+/// Reads the integer variable `name` as a usize.
+pub(crate) fn read_usize_from_var_name(
+    name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<usize, HintError> {
+    let value = get_integer_from_var_name(name, vm, ids_data, ap_tracking)?;
+    value.try_into().map_err(|_| MathError::Felt252ToUsizeConversion(Box::new(value)).into())
+}
+
+/// Reads the integer variable `name` as a Cairo short string.
+pub(crate) fn read_short_string_from_var_name(
+    name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<String, HintError> {
+    let bytes = get_integer_from_var_name(name, vm, ids_data, ap_tracking)?.to_bytes_be();
+    let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(bytes.len());
+    String::from_utf8(bytes[start..].to_vec())
+        .map_err(|_| HintError::CustomHint(format!("{} is not a valid short string", name).into()))
+}
+
+/// Reads the bytes of a `BytesStruct`, i.e. a `(data, len)` sequence with one byte per felt.
+pub(crate) fn read_bytes_struct(
+    vm: &VirtualMachine,
+    ptr: Relocatable,
+) -> Result<Vec<u8>, HintError> {
+    let len_felt = vm.get_integer((ptr + 1)?)?.into_owned();
+    let len: usize =
+        len_felt.try_into().map_err(|_| MathError::Felt252ToUsizeConversion(Box::new(len_felt)))?;
+    if len == 0 {
+        return Ok(vec![]);
+    }
+    let data = vm.get_relocatable(ptr)?;
+    vm.get_integer_range(data, len)?
+        .into_iter()
+        .map(|byte| {
+            byte.to_u8().ok_or_else(|| HintError::CustomHint(Box::from("Value is not a byte")))
+        })
+        .collect()
+}
+
+/// Reads a `Bytes32Struct`, stored as two little-endian 128-bit limbs `(low, high)`.
+pub(crate) fn read_bytes32(vm: &VirtualMachine, ptr: Relocatable) -> Result<[u8; 32], HintError> {
+    let uint256 = Uint256::from_base_addr(ptr, "bytes32", vm)?;
+    let mut bytes = [0u8; 32];
+    bytes[..16].copy_from_slice(&uint256.low.to_bytes_le()[..16]);
+    bytes[16..].copy_from_slice(&uint256.high.to_bytes_le()[..16]);
+    Ok(bytes)
+}
+
+/// Writes `bytes` as a `Bytes32Struct` in a new segment and returns its base.
+pub(crate) fn write_bytes32(
+    vm: &mut VirtualMachine,
+    bytes: &[u8; 32],
+) -> Result<Relocatable, HintError> {
+    let base = vm.add_memory_segment();
+    let low = Felt252::from_bytes_le_slice(&bytes[..16]);
+    let high = Felt252::from_bytes_le_slice(&bytes[16..]);
+    write_collection_to_addr(base, &[low, high], vm)?;
+    Ok(base)
+}
+
+/// Chains Pedersen hashes over `elements`, starting from 0, and hashes in their count, as
+/// `compute_hash_on_elements` of the StarkNet hash state.
+pub(crate) fn compute_hash_on_elements(elements: &[Felt252]) -> Felt252 {
+    let hash = elements.iter().fold(Felt252::ZERO, |acc, element| pedersen_hash(&acc, element));
+    pedersen_hash(&hash, &Felt252::from(elements.len()))
+}
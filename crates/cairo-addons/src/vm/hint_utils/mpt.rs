@@ -0,0 +1,118 @@
+//! Merkle Patricia Trie helpers, following the `patricialize` algorithm of the execution specs.
+
+use alloy_rlp::Header;
+use revm::primitives::keccak256;
+
+/// Root of an empty trie, i.e. `keccak256(rlp(b""))`.
+pub(crate) const EMPTY_TRIE_ROOT: [u8; 32] = [
+    0x56, 0xe8, 0x1f, 0x17, 0x1b, 0xcc, 0x55, 0xa6, 0xff, 0x83, 0x45, 0xe6, 0x92, 0xc0, 0xf8, 0x6e,
+    0x5b, 0x48, 0xe0, 0x1b, 0x99, 0x6c, 0xad, 0xc0, 0x01, 0x62, 0x2f, 0xb5, 0xe3, 0x63, 0xb4, 0x21,
+];
+
+/// Computes the root of the trie holding the given `(key, value)` entries.
+/// Keys are used as-is, callers are responsible for hashing them for secure tries.
+pub(crate) fn trie_root(entries: &[(Vec<u8>, Vec<u8>)]) -> [u8; 32] {
+    if entries.is_empty() {
+        return EMPTY_TRIE_ROOT;
+    }
+    let mut items = entries
+        .iter()
+        .map(|(key, value)| (bytes_to_nibbles(key), value.as_slice()))
+        .collect::<Vec<_>>();
+    items.sort_by(|a, b| a.0.cmp(&b.0));
+    keccak256(encode_internal_node(&items, 0)).0
+}
+
+/// Returns the reference to a node as embedded in its parent: the node itself if its encoding is
+/// shorter than 32 bytes, the RLP encoding of its hash otherwise.
+pub(crate) fn node_reference(encoded_node: Vec<u8>) -> Vec<u8> {
+    if encoded_node.len() < 32 {
+        encoded_node
+    } else {
+        alloy_rlp::encode(keccak256(&encoded_node).as_slice())
+    }
+}
+
+/// Encodes already RLP-encoded items as an RLP list.
+pub(crate) fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload_length = items.iter().map(Vec::len).sum();
+    let mut out = Vec::with_capacity(payload_length + 9);
+    Header { list: true, payload_length }.encode(&mut out);
+    items.iter().for_each(|item| out.extend_from_slice(item));
+    out
+}
+
+/// Compact (hex-prefix) encoding of a nibble path, as defined in the Yellow Paper appendix C.
+pub(crate) fn nibble_list_to_compact(nibbles: &[u8], is_leaf: bool) -> Vec<u8> {
+    let flag = if is_leaf { 2 } else { 0 };
+    let mut compact = Vec::with_capacity(nibbles.len() / 2 + 1);
+    let rest = if nibbles.len() % 2 == 0 {
+        compact.push(flag << 4);
+        nibbles
+    } else {
+        compact.push(((flag + 1) << 4) | nibbles[0]);
+        &nibbles[1..]
+    };
+    compact.extend(rest.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+    compact
+}
+
+/// Decodes a compact (hex-prefix) encoded path into its nibbles and whether it is a leaf path.
+/// Returns `None` if the prefix is invalid.
+pub(crate) fn compact_to_nibble_list(compact: &[u8]) -> Option<(Vec<u8>, bool)> {
+    let (&first, rest) = compact.split_first()?;
+    let flag = first >> 4;
+    let mut nibbles = match flag {
+        0 | 2 if first & 0x0f == 0 => vec![],
+        1 | 3 => vec![first & 0x0f],
+        _ => return None,
+    };
+    nibbles.extend(bytes_to_nibbles(rest));
+    Some((nibbles, flag >= 2))
+}
+
+pub(crate) fn bytes_to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]).collect()
+}
+
+/// RLP-encodes the node holding `items`, whose keys all share their first `level` nibbles.
+fn encode_internal_node(items: &[(Vec<u8>, &[u8])], level: usize) -> Vec<u8> {
+    match items {
+        [] => alloy_rlp::encode(&b""[..]),
+        [(key, value)] => encode_list(&[
+            alloy_rlp::encode(nibble_list_to_compact(&key[level..], true).as_slice()),
+            alloy_rlp::encode(*value),
+        ]),
+        _ => {
+            let first = &items[0].0;
+            let prefix_length = items.iter().fold(first.len() - level, |length, (key, _)| {
+                first[level..]
+                    .iter()
+                    .zip(&key[level..])
+                    .take(length)
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            });
+
+            if prefix_length > 0 {
+                let path = nibble_list_to_compact(&first[level..level + prefix_length], false);
+                let child = encode_internal_node(items, level + prefix_length);
+                return encode_list(&[alloy_rlp::encode(path.as_slice()), node_reference(child)]);
+            }
+
+            let mut branches = (0..16u8)
+                .map(|nibble| {
+                    let children = items
+                        .iter()
+                        .filter(|(key, _)| key.len() > level && key[level] == nibble)
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    node_reference(encode_internal_node(&children, level + 1))
+                })
+                .collect::<Vec<_>>();
+            let value = items.iter().find(|(key, _)| key.len() == level).map_or(&b""[..], |i| i.1);
+            branches.push(alloy_rlp::encode(value));
+            encode_list(&branches)
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Hint execution profiling, enabled with the `hint-profiling` feature.
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use pyo3::prelude::*;
+
+/// The executions of a hint recorded by a `HintProfiler`.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct HintProfile {
+    call_count: u64,
+    total_time: Duration,
+}
+
+/// Records the number of executions and the total execution time of each hint, identified by its
+/// code.
+#[derive(Debug, Default)]
+pub(crate) struct HintProfiler {
+    profiles: HashMap<String, HintProfile>,
+}
+
+impl HintProfiler {
+    /// Records an execution of the hint `hint_code` that started at `start` and ended at `end`.
+    pub(crate) fn record(&mut self, hint_code: &str, start: Instant, end: Instant) {
+        let profile = self.profiles.entry(hint_code.to_string()).or_default();
+        profile.call_count += 1;
+        profile.total_time += end.duration_since(start);
+    }
+
+    /// Returns the `(hint_code, call_count, total_ns)` of each executed hint, by decreasing total
+    /// execution time.
+    pub(crate) fn report(&self) -> Vec<(String, u64, u128)> {
+        let mut report = self
+            .profiles
+            .iter()
+            .map(|(hint_code, profile)| {
+                (hint_code.clone(), profile.call_count, profile.total_time.as_nanos())
+            })
+            .collect::<Vec<_>>();
+        report.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+        report
+    }
+}
+
+/// The hint profiler of a `CairoRunner`, shared with the hint processors of its runs.
+#[pyclass(name = "HintProfiler", unsendable)]
+pub struct PyHintProfiler {
+    pub(crate) inner: Rc<RefCell<HintProfiler>>,
+}
+
+#[pymethods]
+impl PyHintProfiler {
+    /// Returns the `(hint_name, call_count, total_ns)` of each hint executed by the runs of the
+    /// runner, by decreasing total execution time. Hints are named by their code.
+    fn report(&self) -> Vec<(String, u64, u128)> {
+        self.inner.borrow().report()
+    }
+
+    /// Drops the recorded executions.
+    fn reset(&self) {
+        self.inner.borrow_mut().profiles.clear();
+    }
+}
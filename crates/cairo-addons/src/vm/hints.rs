@@ -20,14 +20,35 @@ use std::{collections::HashMap, fmt, rc::Rc};
 
 use super::{
     hint_definitions::{
-        BYTES_HINTS, CIRCUITS_HINTS, CURVE_HINTS, DICT_HINTS, ETHEREUM_HINTS, HASHDICT_HINTS,
-        MATHS_HINTS, PRECOMPILES_HINTS, UTILS_HINTS,
+        BASEFEE_HINTS, BATCH_VERIFY_HINTS, BITWISE_DECOMPOSE_HINTS, BLOOM_HINTS, BYTES31_HINTS,
+        BYTES_HINTS, CALLDATA_HINTS, CHUNK_HASH_HINTS, CIRCUITS_HINTS, COMMITMENT_TREE_HINTS,
+        CURVE_HINTS, DICT_HINTS, ECADD_PROJECTIVE_HINTS, ECDSA_HASH_HINTS, ETHEREUM_HINTS,
+        EVENT_HINTS, EVM_HINTS, FELT252_ARRAY_OPS_HINTS, FELT252_DICT_ENTRY_HINTS,
+        FELT252_DICT_INIT_HINTS, FELT_COMPARISON_HINTS, GAS_TABLE_HINTS, HARDFORK_HINTS,
+        HASHDICT_HINTS, KECCAK_HINTS, L1_HANDLER_HINTS, MATHS_HINTS, MEMORY_DIFF_HINTS, MPT_HINTS,
+        OUTPUT_OPS_HINTS, PEDERSEN_HINTS, POINT_ON_CURVE_HINTS, POSEIDON_HINTS, PRECOMPILES_HINTS,
+        PRECOMPILE_BN256_HINTS, PRECOMPILE_RIPEMD_HINTS, RECEIPT_HINTS, RECURSIVE_HASHER_HINTS,
+        RLP_HINTS, SECP256K1_HINTS, SLOT_HASH_HINTS, STORAGE_WRITE_LOG_HINTS, SYSCALL_GAS_HINTS,
+        TRIE_KEY_HINTS, TX_HASH_HINTS, U8_ARITHMETIC_HINTS, UINT256_HINTS, UTILS_HINTS,
+        WORD_REVERSE_HINTS,
     },
-    hint_loader::load_python_hints,
+    hint_loader::{handle_unknown_hint, load_python_hints},
 };
 
+#[cfg(feature = "nested-runner")]
+use super::hint_definitions::CAIRO_RUN_INNER_HINTS;
+#[cfg(feature = "kv-cache")]
+use super::hint_definitions::KV_CACHE_HINTS;
+#[cfg(feature = "bls")]
+use super::hint_definitions::{HASH_TO_CURVE_HINTS, POINT_ON_CURVE_BLS_HINTS};
+
+#[cfg(feature = "hint-profiling")]
+use super::hint_utils::profiler::HintProfiler;
+#[cfg(feature = "dynamic-hints")]
 use super::pythonic_hint::generic_python_hint;
 use cairo_vm::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
+#[cfg(feature = "hint-profiling")]
+use std::{cell::RefCell, time::Instant};
 
 /// A struct representing a hint.
 pub struct Hint {
@@ -65,6 +86,9 @@ pub struct HintProcessor {
     /// Whether to enable execution of hints containing log traces.
     /// Enabling this considerably slows down the execution speed.
     enable_traces: bool,
+    /// The profiler recording the execution time of each hint, if any.
+    #[cfg(feature = "hint-profiling")]
+    profiler: Option<Rc<RefCell<HintProfiler>>>,
 }
 
 impl HintProcessor {
@@ -78,6 +102,8 @@ impl HintProcessor {
             python_hints,
             pythonic_hint_executor: None,
             enable_traces: false,
+            #[cfg(feature = "hint-profiling")]
+            profiler: None,
         }
     }
 
@@ -106,17 +132,31 @@ impl HintProcessor {
             python_hints: self.python_hints,
             pythonic_hint_executor: self.pythonic_hint_executor,
             enable_traces: self.enable_traces,
+            #[cfg(feature = "hint-profiling")]
+            profiler: self.profiler,
         }
     }
 
+    /// Records the execution time of each hint in `profiler`.
+    #[cfg(feature = "hint-profiling")]
+    #[must_use]
+    pub fn with_profiler(mut self, profiler: Rc<RefCell<HintProfiler>>) -> Self {
+        self.profiler = Some(profiler);
+        self
+    }
+
     /// Add support for dynamic Python hints
     /// If enable_traces is true, the hint processor will be able to execute hints with log-specific
     /// context, like `ids` data, `serialize`, and `logger.trace`.
+    /// Without the `dynamic-hints` feature, this is a no-op and unknown hints fail.
     #[must_use]
     pub fn with_dynamic_python_hints(mut self, enable_traces: bool) -> Self {
         // Store the generic Python hint executor for fallback
         self.enable_traces = enable_traces;
-        self.pythonic_hint_executor = Some(generic_python_hint().func.clone());
+        #[cfg(feature = "dynamic-hints")]
+        {
+            self.pythonic_hint_executor = Some(generic_python_hint().func.clone());
+        }
         self
     }
 
@@ -127,15 +167,18 @@ impl HintProcessor {
             python_hints: self.python_hints,
             pythonic_hint_executor: self.pythonic_hint_executor,
             enable_traces: self.enable_traces,
+            #[cfg(feature = "hint-profiling")]
+            profiler: self.profiler,
         }
     }
-}
 
-impl HintProcessorLogic for HintProcessor {
     /// Executes a hint. If the hint is not found and dynamic hints are enabled, it will try to
     /// execute the hint as Python code. If dynamic hints are disabled, it will silently ignore
-    /// unknown hints.
-    fn execute_hint(
+    /// unknown hints, or fail if the crate was built without the `dynamic-hints` feature.
+    // Takes the hint data as `HintProcessorLogic::execute_hint`, to forward it to the inner
+    // processor.
+    #[allow(clippy::borrowed_box)]
+    fn execute_unprofiled_hint(
         &mut self,
         vm: &mut VirtualMachine,
         exec_scopes: &mut ExecutionScopes,
@@ -147,7 +190,7 @@ impl HintProcessorLogic for HintProcessor {
 
         match result {
             Ok(_) => Ok(()),
-            Err(HintError::UnknownHint(_hint_str)) => {
+            Err(HintError::UnknownHint(hint_str)) => {
                 // If the hint is unknown and we have a dynamic hint executor, try it
                 if let Some(pythonic_hint_func) = &self.pythonic_hint_executor {
                     // Extract the hint code from the hint_data
@@ -185,8 +228,9 @@ impl HintProcessorLogic for HintProcessor {
                         )))
                     })
                 } else {
-                    // If dynamic hints are disabled, silently ignore unknown hints
-                    Ok(())
+                    // If dynamic hints are disabled, unknown hints are ignored, or fail if the
+                    // crate was built without the `dynamic-hints` feature
+                    handle_unknown_hint(&hint_str)
                 }
             }
             Err(err) => Err(err),
@@ -194,6 +238,29 @@ impl HintProcessorLogic for HintProcessor {
     }
 }
 
+impl HintProcessorLogic for HintProcessor {
+    /// Executes a hint, recording its execution time if a profiler is set.
+    fn execute_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn std::any::Any>,
+        constants: &HashMap<String, Felt252>,
+    ) -> Result<(), HintError> {
+        #[cfg(feature = "hint-profiling")]
+        if let Some(profiler) = self.profiler.clone() {
+            let start = Instant::now();
+            let result = self.execute_unprofiled_hint(vm, exec_scopes, hint_data, constants);
+            let end = Instant::now();
+            if let Some(hint_data) = hint_data.downcast_ref::<HintProcessorData>() {
+                profiler.borrow_mut().record(&hint_data.code, start, end);
+            }
+            return result;
+        }
+        self.execute_unprofiled_hint(vm, exec_scopes, hint_data, constants)
+    }
+}
+
 impl ResourceTracker for HintProcessor {
     fn consumed(&self) -> bool {
         self.inner.consumed()
@@ -221,6 +288,54 @@ impl Default for HintProcessor {
         hints.extend_from_slice(CURVE_HINTS);
         hints.extend_from_slice(CIRCUITS_HINTS);
         hints.extend_from_slice(PRECOMPILES_HINTS);
+        hints.extend_from_slice(BASEFEE_HINTS);
+        hints.extend_from_slice(PRECOMPILE_BN256_HINTS);
+        hints.extend_from_slice(FELT252_DICT_INIT_HINTS);
+        hints.extend_from_slice(RECEIPT_HINTS);
+        hints.extend_from_slice(CALLDATA_HINTS);
+        hints.extend_from_slice(OUTPUT_OPS_HINTS);
+        hints.extend_from_slice(ECADD_PROJECTIVE_HINTS);
+        hints.extend_from_slice(FELT252_ARRAY_OPS_HINTS);
+        hints.extend_from_slice(PRECOMPILE_RIPEMD_HINTS);
+        hints.extend_from_slice(STORAGE_WRITE_LOG_HINTS);
+        hints.extend_from_slice(SLOT_HASH_HINTS);
+        hints.extend_from_slice(MEMORY_DIFF_HINTS);
+        hints.extend_from_slice(BITWISE_DECOMPOSE_HINTS);
+        hints.extend_from_slice(FELT252_DICT_ENTRY_HINTS);
+        hints.extend_from_slice(RECURSIVE_HASHER_HINTS);
+        hints.extend_from_slice(WORD_REVERSE_HINTS);
+        hints.extend_from_slice(SYSCALL_GAS_HINTS);
+        hints.extend_from_slice(CHUNK_HASH_HINTS);
+        hints.extend_from_slice(ECDSA_HASH_HINTS);
+        hints.extend_from_slice(TX_HASH_HINTS);
+        hints.extend_from_slice(POINT_ON_CURVE_HINTS);
+        hints.extend_from_slice(TRIE_KEY_HINTS);
+        hints.extend_from_slice(EVENT_HINTS);
+        hints.extend_from_slice(L1_HANDLER_HINTS);
+        hints.extend_from_slice(BYTES31_HINTS);
+        hints.extend_from_slice(U8_ARITHMETIC_HINTS);
+        hints.extend_from_slice(FELT_COMPARISON_HINTS);
+        hints.extend_from_slice(BATCH_VERIFY_HINTS);
+        hints.extend_from_slice(GAS_TABLE_HINTS);
+        hints.extend_from_slice(COMMITMENT_TREE_HINTS);
+        hints.extend_from_slice(HARDFORK_HINTS);
+        hints.extend_from_slice(KECCAK_HINTS);
+        hints.extend_from_slice(RLP_HINTS);
+        hints.extend_from_slice(UINT256_HINTS);
+        hints.extend_from_slice(PEDERSEN_HINTS);
+        hints.extend_from_slice(POSEIDON_HINTS);
+        hints.extend_from_slice(MPT_HINTS);
+        hints.extend_from_slice(BLOOM_HINTS);
+        hints.extend_from_slice(EVM_HINTS);
+        hints.extend_from_slice(SECP256K1_HINTS);
+        #[cfg(feature = "bls")]
+        hints.extend_from_slice(HASH_TO_CURVE_HINTS);
+        #[cfg(feature = "bls")]
+        hints.extend_from_slice(POINT_ON_CURVE_BLS_HINTS);
+        #[cfg(feature = "nested-runner")]
+        hints.extend_from_slice(CAIRO_RUN_INNER_HINTS);
+        #[cfg(feature = "kv-cache")]
+        hints.extend_from_slice(KV_CACHE_HINTS);
         Self::new(RunResources::default()).with_hints(hints)
     }
 }
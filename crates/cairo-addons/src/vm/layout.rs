@@ -1,29 +1,102 @@
+use std::str::FromStr;
+
 use cairo_vm::types::layout_name::LayoutName;
 use pyo3::{prelude::*, FromPyObject};
 
+/// The layouts of the Cairo AIR a `CairoRunner` can be created with.
+#[pyclass(name = "Layout", eq, eq_int)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum PyLayout {
+    plain,
+    small,
+    dex,
+    recursive,
+    starknet,
+    starknet_with_keccak,
+    recursive_large_output,
+    recursive_with_poseidon,
+    all_cairo,
+    all_solidity,
+    dynamic,
+}
+
+#[pymethods]
+impl PyLayout {
+    /// Returns the layout of the given name, raising a `ValueError` for unknown names.
+    #[staticmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(name: &str) -> PyResult<Self> {
+        name.parse()
+    }
+
+    fn __str__(&self) -> &'static str {
+        LayoutName::from(*self).to_str()
+    }
+}
+
+impl FromStr for PyLayout {
+    type Err = PyErr;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "plain" => Ok(PyLayout::plain),
+            "small" => Ok(PyLayout::small),
+            "dex" => Ok(PyLayout::dex),
+            "recursive" => Ok(PyLayout::recursive),
+            "starknet" => Ok(PyLayout::starknet),
+            "starknet_with_keccak" => Ok(PyLayout::starknet_with_keccak),
+            "recursive_large_output" => Ok(PyLayout::recursive_large_output),
+            "recursive_with_poseidon" => Ok(PyLayout::recursive_with_poseidon),
+            "all_cairo" => Ok(PyLayout::all_cairo),
+            "all_solidity" => Ok(PyLayout::all_solidity),
+            "dynamic" => Ok(PyLayout::dynamic),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid layout name: {}",
+                name
+            ))),
+        }
+    }
+}
+
+impl From<PyLayout> for LayoutName {
+    fn from(layout: PyLayout) -> Self {
+        match layout {
+            PyLayout::plain => LayoutName::plain,
+            PyLayout::small => LayoutName::small,
+            PyLayout::dex => LayoutName::dex,
+            PyLayout::recursive => LayoutName::recursive,
+            PyLayout::starknet => LayoutName::starknet,
+            PyLayout::starknet_with_keccak => LayoutName::starknet_with_keccak,
+            PyLayout::recursive_large_output => LayoutName::recursive_large_output,
+            PyLayout::recursive_with_poseidon => LayoutName::recursive_with_poseidon,
+            PyLayout::all_cairo => LayoutName::all_cairo,
+            PyLayout::all_solidity => LayoutName::all_solidity,
+            PyLayout::dynamic => LayoutName::dynamic,
+        }
+    }
+}
+
+/// A layout passed from Python, either as a `Layout` or by its name for backward compatibility.
 #[derive(FromPyObject)]
-pub struct PyLayout(pub String);
+pub enum PyLayoutArg {
+    #[pyo3(transparent)]
+    Layout(PyLayout),
+    #[pyo3(transparent)]
+    Name(String),
+}
 
-impl Default for PyLayout {
+impl Default for PyLayoutArg {
     fn default() -> Self {
-        Self("plain".to_string())
+        Self::Layout(PyLayout::plain)
     }
 }
 
-impl PyLayout {
+impl PyLayoutArg {
     pub fn into_layout_name(self) -> PyResult<LayoutName> {
-        match self.0.as_str() {
-            "plain" => Ok(LayoutName::plain),
-            "small" => Ok(LayoutName::small),
-            "dex" => Ok(LayoutName::dex),
-            "recursive" => Ok(LayoutName::recursive),
-            "starknet" => Ok(LayoutName::starknet),
-            "starknet_with_keccak" => Ok(LayoutName::starknet_with_keccak),
-            "recursive_large_output" => Ok(LayoutName::recursive_large_output),
-            "recursive_with_poseidon" => Ok(LayoutName::recursive_with_poseidon),
-            "all_cairo" => Ok(LayoutName::all_cairo),
-            "all_solidity" => Ok(LayoutName::all_solidity),
-            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("Invalid layout name")),
+        match self {
+            PyLayoutArg::Layout(layout) => Ok(layout.into()),
+            PyLayoutArg::Name(name) => Ok(name.parse::<PyLayout>()?.into()),
         }
     }
 }
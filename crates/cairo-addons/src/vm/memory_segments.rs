@@ -1,7 +1,8 @@
 use cairo_vm::{
-    types::relocatable::MaybeRelocatable, vm::vm_core::VirtualMachine as RustVirtualMachine,
+    types::relocatable::{MaybeRelocatable, Relocatable},
+    vm::vm_core::VirtualMachine as RustVirtualMachine,
 };
-use pyo3::prelude::*;
+use pyo3::{prelude::*, IntoPyObjectExt};
 
 use crate::vm::{maybe_relocatable::PyMaybeRelocatable, relocatable::PyRelocatable};
 
@@ -86,6 +87,65 @@ impl PyMemorySegmentManager {
         Ok(result.into())
     }
 
+    /// Writes each `(address, value)` pair of `pairs` to memory, stopping at the first pair that
+    /// can't be written. All values are converted before any is written.
+    fn bulk_write(
+        &mut self,
+        pairs: Vec<(PyRelocatable, PyObject)>,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        let pairs = pairs
+            .into_iter()
+            .enumerate()
+            .map(|(index, (address, value))| {
+                let value = value.extract::<PyMaybeRelocatable>(py).map_err(|_| {
+                    PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                        "Invalid value at index {}",
+                        index
+                    ))
+                })?;
+                Ok((address.inner, MaybeRelocatable::from(value)))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let vm = unsafe { &mut *self.vm };
+        for (address, value) in pairs {
+            vm.insert_value(address, value).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                    "Failed to write at address {}: {}",
+                    address, e
+                ))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Returns the cells of the segment `segment_index`, from offset 0 to its used size, as ints
+    /// or relocatables, with `None` for the cells that were never written.
+    fn read_segment(&self, segment_index: usize, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        let vm = unsafe { &mut *self.vm };
+
+        // Computing the segment sizes caches them in the VM, which must not happen mid-run.
+        let used_sizes = vm.segments.segment_used_sizes.take();
+        let segment_size = vm.segments.compute_effective_sizes().get(segment_index).copied();
+        vm.segments.segment_used_sizes = used_sizes;
+        let segment_size = segment_size.ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyIndexError, _>(format!(
+                "Segment index out of range: {}",
+                segment_index
+            ))
+        })?;
+
+        (0..segment_size)
+            .map(|offset| {
+                match vm.get_maybe(&Relocatable::from((segment_index as isize, offset))) {
+                    Some(value) => PyMaybeRelocatable::from(value).into_py_any(py),
+                    None => Ok(py.None()),
+                }
+            })
+            .collect()
+    }
+
     fn get_segment_used_size(&self, segment_index: usize) -> Option<usize> {
         unsafe { (*self.vm).segments.get_segment_used_size(segment_index) }
     }
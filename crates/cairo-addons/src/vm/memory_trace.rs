@@ -0,0 +1,98 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use cairo_vm::{
+    hint_processor::hint_processor_definition::HintProcessor,
+    types::{
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{errors::vm_errors::VirtualMachineError, hooks::Hooks, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+
+/// A memory access: `(address, is_write, value)`.
+pub(crate) type MemoryAccess = (Relocatable, bool, MaybeRelocatable);
+
+/// The memory accesses of the instructions run while the memory trace is enabled, in access
+/// order.
+///
+/// Each instruction accesses, in order, its encoding at `pc`, then `dst`, `op0` and `op1`. An
+/// access is a write if the cell was deduced by the instruction, i.e. it was unknown before the
+/// instruction ran. Cells written by hints are read by the instructions using them.
+#[derive(Debug, Default)]
+pub(crate) struct MemoryTrace {
+    /// The addresses accessed by the running instruction, and whether they were known before it
+    /// ran.
+    pending: Vec<(Relocatable, bool)>,
+    accesses: Vec<MemoryAccess>,
+}
+
+impl MemoryTrace {
+    pub(crate) fn accesses(&self) -> &[MemoryAccess] {
+        &self.accesses
+    }
+}
+
+/// Returns the VM hooks recording the memory accesses of each instruction in `trace`.
+pub(crate) fn memory_trace_hooks(trace: Arc<Mutex<MemoryTrace>>) -> Hooks {
+    let pre_step_trace = trace.clone();
+    let pre_step = move |vm: &mut VirtualMachine,
+                         _hint_processor: &mut dyn HintProcessor,
+                         _exec_scopes: &mut ExecutionScopes,
+                         _hint_data: &[Box<dyn Any>],
+                         _constants: &HashMap<String, Felt252>|
+          -> Result<(), VirtualMachineError> {
+        let mut trace = pre_step_trace.lock().unwrap();
+        trace.pending = instruction_addresses(vm)
+            .into_iter()
+            .map(|address| (address, vm.get_maybe(&address).is_some()))
+            .collect();
+        Ok(())
+    };
+    let post_step = move |vm: &mut VirtualMachine,
+                          _hint_processor: &mut dyn HintProcessor,
+                          _exec_scopes: &mut ExecutionScopes,
+                          _hint_data: &[Box<dyn Any>],
+                          _constants: &HashMap<String, Felt252>|
+          -> Result<(), VirtualMachineError> {
+        let mut trace = trace.lock().unwrap();
+        let pending = std::mem::take(&mut trace.pending);
+        trace.accesses.extend(pending.into_iter().filter_map(|(address, was_known)| {
+            vm.get_maybe(&address).map(|value| (address, !was_known, value))
+        }));
+        Ok(())
+    };
+    Hooks::new(None, Some(Arc::new(pre_step)), Some(Arc::new(post_step)))
+}
+
+/// Returns the addresses accessed by the instruction at `pc`: `[pc, dst, op0, op1]`.
+///
+/// Addresses that can't be computed, e.g. for an invalid instruction, are skipped: the VM raises
+/// on such instructions anyway.
+fn instruction_addresses(vm: &VirtualMachine) -> Vec<Relocatable> {
+    let pc = vm.get_pc();
+    let Some(encoding) = vm.get_integer(pc).ok().and_then(|felt| felt.to_u64()) else {
+        return vec![pc];
+    };
+    let offset = |index: u32| ((encoding >> (16 * index)) & 0xFFFF) as i32 - (1 << 15);
+    let flags = encoding >> 48;
+    let register = |flag: u64| if flags & flag == 0 { vm.get_ap() } else { vm.get_fp() };
+
+    let dst = (register(1) + offset(0)).ok();
+    let op0 = (register(1 << 1) + offset(1)).ok();
+    let op1_base = match (flags >> 2) & 0b111 {
+        0 => op0.and_then(|op0| vm.get_relocatable(op0).ok()),
+        1 => Some(pc),
+        2 => Some(vm.get_fp()),
+        4 => Some(vm.get_ap()),
+        _ => None,
+    };
+    let op1 = op1_base.and_then(|base| (base + offset(2)).ok());
+
+    std::iter::once(pc).chain(dst).chain(op0).chain(op1).collect()
+}
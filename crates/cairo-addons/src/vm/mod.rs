@@ -9,18 +9,24 @@ mod hints;
 mod layout;
 mod maybe_relocatable;
 mod memory_segments;
+mod memory_trace;
 mod program;
+// Only `DynamicHintError` is used without the `dynamic-hints` feature.
+#[cfg_attr(not(feature = "dynamic-hints"), allow(dead_code))]
 mod pythonic_hint;
 mod relocatable;
 mod relocated_trace;
 mod run_resources;
 mod runner;
+mod runner_state;
 mod stripped_program;
 mod vm_consts;
 
 // Re-export the dynamic hint functionality
 
-use dict_manager::{PyDictManager, PyDictTracker};
+use dict_manager::{PyDictManager, PyDictTracker, PyDictTrackerSnapshot};
+use hint_definitions::PyHardfork;
+use layout::PyLayout;
 use memory_segments::PyMemorySegmentManager;
 use program::PyProgram;
 use relocatable::PyRelocatable;
@@ -30,6 +36,9 @@ use runner::PyCairoRunner;
 use stripped_program::PyStrippedProgram;
 use vm_consts::{PyVmConst, PyVmConstsDict};
 
+#[cfg(feature = "hint-profiling")]
+use hint_utils::profiler::PyHintProfiler;
+
 #[pymodule]
 fn vm(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<PyProgram>()?;
@@ -41,8 +50,13 @@ fn vm(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<PyStrippedProgram>()?;
     module.add_class::<PyDictManager>()?;
     module.add_class::<PyDictTracker>()?;
+    module.add_class::<PyDictTrackerSnapshot>()?;
+    module.add_class::<PyHardfork>()?;
+    module.add_class::<PyLayout>()?;
     module.add_function(wrap_pyfunction!(runner::run_proof_mode, module)?).unwrap();
     module.add_class::<PyVmConst>()?;
     module.add_class::<PyVmConstsDict>()?;
+    #[cfg(feature = "hint-profiling")]
+    module.add_class::<PyHintProfiler>()?;
     Ok(())
 }
@@ -1,9 +1,9 @@
 use cairo_vm::{
     serde::deserialize_program::{deserialize_program_json, parse_program_json, ProgramJson},
-    types::program::Program as RustProgram,
+    types::{program::Program as RustProgram, relocatable::MaybeRelocatable},
     Felt252,
 };
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::PyDict, IntoPyObjectExt};
 
 use crate::vm::builtins::PyBuiltinList;
 
@@ -17,15 +17,26 @@ impl PyProgram {
     #[staticmethod]
     #[pyo3(signature = (program_bytes, entrypoint=None))]
     fn from_bytes(program_bytes: &[u8], entrypoint: Option<&str>) -> PyResult<Self> {
-        let mut program_json: ProgramJson = deserialize_program_json(program_bytes)
+        Ok(Self { inner: parse_program(program_bytes, entrypoint)? })
+    }
+
+    /// Loads a program serialized with `to_json`.
+    #[staticmethod]
+    #[pyo3(signature = (s, entrypoint=None))]
+    fn from_json(s: &str, entrypoint: Option<&str>) -> PyResult<Self> {
+        let inner = RustProgram::deserialize(s.as_bytes(), entrypoint)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-        // Manually add proof-mode instructions jmp rel 0 to be able to loop in proof mode and avoid
-        // the proof-mode at compile time
-        program_json.data.push(Felt252::from(0x10780017FFF7FFF_u64).into());
-        program_json.data.push(Felt252::from(0).into());
-        let program = parse_program_json(program_json, entrypoint)
+        Ok(Self { inner })
+    }
+
+    /// Serializes the program to JSON, e.g. to cache it on disk and reload it with `from_json`.
+    fn to_json(&self) -> PyResult<String> {
+        let bytes = self
+            .inner
+            .serialize()
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-        Ok(Self { inner: program })
+        String::from_utf8(bytes)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
     }
 
     #[getter]
@@ -44,4 +55,54 @@ impl PyProgram {
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
         Ok(())
     }
+
+    /// Returns the constants of the program as a `dict` mapping their full names, e.g.
+    /// `__main__.MY_CONST`, to their values as felts.
+    #[getter]
+    fn constants(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let constants = PyDict::new(py);
+        for (name, identifier) in self.inner.iter_identifiers() {
+            if identifier.type_.as_deref() != Some("const") {
+                continue;
+            }
+            if let Some(value) = identifier.value {
+                constants.set_item(name, value.to_biguint())?;
+            }
+        }
+        constants.into_py_any(py)
+    }
+
+    /// Returns the functions of the program as a `dict` mapping their full names, e.g.
+    /// `__main__.main`, to the pc of their first instruction.
+    fn entry_points(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let entry_points = PyDict::new(py);
+        for (name, identifier) in self.inner.iter_identifiers() {
+            if identifier.type_.as_deref() != Some("function") {
+                continue;
+            }
+            if let Some(pc) = identifier.pc {
+                entry_points.set_item(name, pc)?;
+            }
+        }
+        entry_points.into_py_any(py)
+    }
+}
+
+/// Parses a compiled Cairo program from its JSON bytes.
+pub(crate) fn parse_program(
+    program_bytes: &[u8],
+    entrypoint: Option<&str>,
+) -> PyResult<RustProgram> {
+    let mut program_json: ProgramJson = deserialize_program_json(program_bytes)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    append_jmp_rel_0(&mut program_json.data);
+    parse_program_json(program_json, entrypoint)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+}
+
+/// Manually adds the proof-mode instruction jmp rel 0 at the end of the program `data`, to be
+/// able to loop in proof mode and avoid the proof-mode at compile time.
+pub(crate) fn append_jmp_rel_0(data: &mut Vec<MaybeRelocatable>) {
+    data.push(Felt252::from(0x10780017FFF7FFF_u64).into());
+    data.push(Felt252::from(0).into());
 }
@@ -2,6 +2,7 @@ use cairo_vm::types::relocatable::{
     MaybeRelocatable as RustMaybeRelocatable, Relocatable as RustRelocatable,
 };
 use pyo3::prelude::*;
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 use super::maybe_relocatable::PyMaybeRelocatable;
 
@@ -16,7 +17,7 @@ enum PyRelocatableSub {
     #[pyo3(transparent)]
     Relocatable(PyRelocatable),
     #[pyo3(transparent)]
-    Int(usize),
+    Int(i64),
 }
 
 #[pymethods]
@@ -36,22 +37,33 @@ impl PyRelocatable {
         self.inner.offset
     }
 
-    fn __add__(&self, other: usize) -> PyResult<Self> {
-        let result = (self.inner + other)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
-        Ok(Self { inner: result })
+    /// Returns the relocatable as a `(segment_index, offset)` tuple.
+    fn as_tuple(&self) -> (isize, usize) {
+        (self.inner.segment_index, self.inner.offset)
     }
 
+    fn __add__(&self, other: i64) -> PyResult<Self> {
+        self.offset_by(other)
+    }
+
+    fn __radd__(&self, other: i64) -> PyResult<Self> {
+        self.offset_by(other)
+    }
+
+    /// Subtracting an int returns a relocatable, subtracting a relocatable of the same segment
+    /// returns the distance between both as an int.
     fn __sub__(&self, other: PyRelocatableSub) -> PyResult<PyMaybeRelocatable> {
         match other {
             PyRelocatableSub::Int(x) => {
-                let result = (self.inner - x)
-                    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
-                Ok(PyMaybeRelocatable::from(RustMaybeRelocatable::from(result)))
+                let offset = x.checked_neg().ok_or_else(|| overflow_error(x))?;
+                Ok(PyMaybeRelocatable::from(RustMaybeRelocatable::from(
+                    self.offset_by(offset)?.inner,
+                )))
             }
             PyRelocatableSub::Relocatable(x) => {
-                let result = (self.inner - x.inner)
-                    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+                let result = (self.inner - x.inner).map_err(|e| {
+                    PyErr::new::<pyo3::exceptions::PyOverflowError, _>(e.to_string())
+                })?;
                 Ok(PyMaybeRelocatable::from(RustMaybeRelocatable::from(result)))
             }
         }
@@ -65,11 +77,9 @@ impl PyRelocatable {
         format!("{}:{}", self.inner.segment_index, self.inner.offset)
     }
 
+    /// Same as `__str__`, the format used by cairo-lang when printing relocatable values.
     fn __repr__(&self) -> String {
-        format!(
-            "Relocatable(segment_index={}, offset={})",
-            self.inner.segment_index, self.inner.offset
-        )
+        self.__str__()
     }
 
     fn __lt__(&self, other: &PyRelocatable) -> bool {
@@ -89,10 +99,29 @@ impl PyRelocatable {
     }
 
     fn __hash__(&self) -> PyResult<isize> {
-        Ok(self.inner.segment_index ^ (self.inner.offset as isize))
+        let mut hasher = DefaultHasher::new();
+        self.inner.hash(&mut hasher);
+        Ok(hasher.finish() as isize)
     }
 }
 
+impl PyRelocatable {
+    /// Returns `self + offset`, raising an `OverflowError` if the resulting offset is negative or
+    /// doesn't fit in a `usize`.
+    fn offset_by(&self, offset: i64) -> PyResult<Self> {
+        let magnitude =
+            usize::try_from(offset.unsigned_abs()).map_err(|_| overflow_error(offset))?;
+        let result = if offset >= 0 { self.inner + magnitude } else { self.inner - magnitude };
+        let inner = result
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyOverflowError, _>(e.to_string()))?;
+        Ok(Self { inner })
+    }
+}
+
+fn overflow_error(offset: i64) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyOverflowError, _>(format!("Offset out of range: {}", offset))
+}
+
 impl From<RustRelocatable> for PyRelocatable {
     fn from(rel: RustRelocatable) -> Self {
         Self { inner: rel }
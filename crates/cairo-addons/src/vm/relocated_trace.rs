@@ -1,5 +1,6 @@
 use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
 use pyo3::prelude::*;
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 #[pyclass(name = "RelocatedTraceEntry")]
 pub struct PyRelocatedTraceEntry {
@@ -8,6 +9,11 @@ pub struct PyRelocatedTraceEntry {
 
 #[pymethods]
 impl PyRelocatedTraceEntry {
+    #[new]
+    fn new(pc: usize, ap: usize, fp: usize) -> Self {
+        Self { inner: RelocatedTraceEntry { pc, ap, fp } }
+    }
+
     #[getter]
     fn pc(&self) -> usize {
         self.inner.pc
@@ -20,6 +26,34 @@ impl PyRelocatedTraceEntry {
     fn fp(&self) -> usize {
         self.inner.fp
     }
+
+    fn __eq__(&self, other: &PyRelocatedTraceEntry) -> bool {
+        self.registers() == other.registers()
+    }
+
+    /// Entries are ordered by `pc`, then `ap`, then `fp`.
+    fn __lt__(&self, other: &PyRelocatedTraceEntry) -> bool {
+        self.registers() < other.registers()
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut hasher = DefaultHasher::new();
+        self.registers().hash(&mut hasher);
+        hasher.finish() as isize
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RelocatedTraceEntry(pc={}, ap={}, fp={})",
+            self.inner.pc, self.inner.ap, self.inner.fp
+        )
+    }
+}
+
+impl PyRelocatedTraceEntry {
+    fn registers(&self) -> (usize, usize, usize) {
+        (self.inner.pc, self.inner.ap, self.inner.fp)
+    }
 }
 
 impl From<RelocatedTraceEntry> for PyRelocatedTraceEntry {
@@ -5,6 +5,8 @@ use pyo3::prelude::*;
 #[derive(Clone)]
 pub struct PyRunResources {
     pub(crate) inner: RunResources,
+    /// The step limit the resources were created with, `inner` only tracking the remaining steps.
+    n_steps_limit: Option<usize>,
 }
 
 #[pymethods]
@@ -13,8 +15,8 @@ impl PyRunResources {
     #[pyo3(signature = (n_steps=None))]
     fn new(n_steps: Option<usize>) -> PyResult<Self> {
         match n_steps {
-            Some(n_steps) => Ok(Self { inner: RunResources::new(n_steps) }),
-            None => Ok(Self { inner: RunResources::default() }),
+            Some(n_steps) => Ok(Self::from(RunResources::new(n_steps))),
+            None => Ok(Self::from(RunResources::default())),
         }
     }
 
@@ -22,10 +24,51 @@ impl PyRunResources {
     fn n_steps(&self) -> Option<usize> {
         self.inner.get_n_steps()
     }
+
+    /// The number of steps consumed by the runs using these resources, or `None` without a step
+    /// limit.
+    #[getter]
+    fn steps_used(&self) -> PyResult<Option<usize>> {
+        let (Some(limit), Some(remaining)) = (self.n_steps_limit, self.inner.get_n_steps()) else {
+            return Ok(None);
+        };
+        limit.checked_sub(remaining).map(Some).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Remaining steps {} exceed the step limit {}",
+                remaining, limit
+            ))
+        })
+    }
+
+    /// The number of steps left before the step limit is reached, or `None` without a step limit.
+    #[getter]
+    fn steps_remaining(&self) -> PyResult<Option<usize>> {
+        Ok(self.inner.get_n_steps())
+    }
+
+    /// Whether all the steps have been consumed. Always `false` without a step limit.
+    #[getter]
+    fn is_exhausted(&self) -> bool {
+        self.inner.consumed()
+    }
+}
+
+impl PyRunResources {
+    /// Returns the step limit and the remaining steps, both `None` without a step limit.
+    pub(crate) fn to_parts(&self) -> (Option<usize>, Option<usize>) {
+        (self.n_steps_limit, self.inner.get_n_steps())
+    }
+
+    /// Rebuilds resources from the parts returned by `to_parts`.
+    pub(crate) fn from_parts((n_steps_limit, n_steps): (Option<usize>, Option<usize>)) -> Self {
+        let inner = n_steps.map(RunResources::new).unwrap_or_default();
+        Self { inner, n_steps_limit }
+    }
 }
 
 impl From<RunResources> for PyRunResources {
     fn from(inner: RunResources) -> Self {
-        Self { inner }
+        let n_steps_limit = inner.get_n_steps();
+        Self { inner, n_steps_limit }
     }
 }
@@ -1,9 +1,20 @@
 use super::{
-    dict_manager::PyDictManager, hints::HintProcessor, memory_segments::PyMemorySegmentManager,
+    dict_manager::PyDictManager,
+    hint_definitions::PreimageRegistry,
+    hints::HintProcessor,
+    memory_segments::PyMemorySegmentManager,
+    memory_trace::{memory_trace_hooks, MemoryTrace},
 };
+#[cfg(feature = "hint-profiling")]
+use crate::vm::hint_utils::profiler::{HintProfiler, PyHintProfiler};
 use crate::vm::{
-    layout::PyLayout, maybe_relocatable::PyMaybeRelocatable, program::PyProgram,
-    relocatable::PyRelocatable, run_resources::PyRunResources,
+    layout::{PyLayout, PyLayoutArg},
+    maybe_relocatable::PyMaybeRelocatable,
+    program::{append_jmp_rel_0, parse_program, PyProgram},
+    relocatable::PyRelocatable,
+    run_resources::PyRunResources,
+    runner_state::{RunnerState, SerializedDictManager, SerializedValue},
+    stripped_program::{parse_stripped_program, stripped_program_to_program, PyStrippedProgram},
 };
 use bincode::enc::write::Writer;
 use cairo_vm::{
@@ -18,25 +29,36 @@ use cairo_vm::{
         relocatable::{MaybeRelocatable, Relocatable},
     },
     vm::{
-        errors::vm_exception::VmException,
-        runners::{builtin_runner::BuiltinRunner, cairo_runner::CairoRunner as RustCairoRunner},
+        errors::{vm_errors::VirtualMachineError, vm_exception::VmException},
+        hooks::Hooks,
+        runners::{
+            builtin_runner::BuiltinRunner,
+            cairo_pie::StrippedProgram,
+            cairo_runner::{CairoRunner as RustCairoRunner, ResourceTracker, RunResources},
+        },
         security::verify_secure_runner,
+        trace::trace_entry::TraceEntry,
     },
+    Felt252,
 };
+use num_bigint::{BigInt, BigUint, Sign};
 use num_traits::Zero;
 use polars::prelude::*;
 use pyo3::{
     prelude::*,
-    types::{IntoPyDict, PyDict},
+    types::{IntoPyDict, PyBytes, PyDict, PyTuple, PyType},
+    IntoPyObjectExt,
 };
 use pyo3_polars::PyDataFrame;
 use std::{
+    any::Any,
     cell::RefCell,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     ffi::CString,
     io::{self, Write},
     path::PathBuf,
     rc::Rc,
+    sync::{Arc, Mutex},
 };
 use stwo_cairo_adapter::ExecutionResources as ProverExecutionResources;
 use stwo_cairo_prover::{
@@ -56,6 +78,24 @@ pub struct PyCairoRunner {
     ordered_builtins: Vec<BuiltinName>,
     /// Whether to enable execution of hints containing logger.
     enable_traces: bool,
+    /// The relocation rules added to the VM memory, from temporary segment bases to their
+    /// destination. Cleared once the rules are applied on relocation.
+    relocation_rules: HashMap<Relocatable, Relocatable>,
+    /// The memory cells pre-authorized with `mark_as_safe`, that are guaranteed to be written
+    /// later in the run.
+    safe_addresses: HashSet<Relocatable>,
+    /// The memory accesses recorded while running, if enabled with `enable_memory_trace`.
+    memory_trace: Option<Arc<Mutex<MemoryTrace>>>,
+    /// The layout and proof mode the runner was created with, used to rebuild it when loading a
+    /// new program.
+    layout: LayoutName,
+    proof_mode: bool,
+    /// The resources left after the last `run_until_pc`, kept in serialized states so that a
+    /// restored run can be resumed with the same step budget.
+    run_resources: PyRunResources,
+    /// The execution time of the hints of the runs, shared with their hint processors.
+    #[cfg(feature = "hint-profiling")]
+    hint_profiler: Rc<RefCell<HintProfiler>>,
 }
 
 #[pymethods]
@@ -65,7 +105,8 @@ impl PyCairoRunner {
     /// * `program` - The _rust_ program to run.
     /// * `py_identifiers` - The _pythonic_ identifiers for this program. Only used when
     ///   enable_traces is true.
-    /// * `layout` - The layout to use for the runner.
+    /// * `layout` - The layout to use for the runner, as a `Layout` or by name. Defaults to
+    ///   `plain`.
     /// * `proof_mode` - Whether to run in proof mode.
     /// * `allow_missing_builtins` - Whether to allow missing builtins.
     /// * `enable_traces` - Whether to enable execution of hints containing log traces. When false,
@@ -76,7 +117,7 @@ impl PyCairoRunner {
     fn new(
         program: &PyProgram,
         py_identifiers: Option<PyObject>,
-        layout: Option<PyLayout>,
+        layout: Option<PyLayoutArg>,
         proof_mode: bool,
         allow_missing_builtins: bool,
         enable_traces: bool,
@@ -89,71 +130,15 @@ impl PyCairoRunner {
             .map(|name| BuiltinName::from_str(name.strip_suffix("_ptr").unwrap()).unwrap())
             .collect();
 
-        let mut inner = RustCairoRunner::new(
+        Self::create(
             &program.inner,
+            py_identifiers,
             layout,
-            None, // dynamic_layout_params
             proof_mode,
-            true,       // trace_enabled
-            proof_mode, // disable_trace_padding can only be used in proof_mode
-        )
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-
-        let dict_manager = DictManager::new();
-        inner.exec_scopes.insert_value("dict_manager", Rc::new(RefCell::new(dict_manager)));
-
-        // Initialize a python context object that will be accessible throughout the execution of
-        // all hints, but only load identifiers if logger is enabled
-        Python::with_gil(|py| {
-            let context = PyDict::new(py);
-
-            let identifiers = program
-                .inner
-                .iter_identifiers()
-                .map(|(name, identifier)| (name.to_string(), identifier.clone()))
-                .collect::<HashMap<String, Identifier>>();
-
-            // Insert the _rust_ program_identifiers in the exec_scopes, so that we're able to
-            // pull identifier data when executing hints to build VmConsts.
-            inner.exec_scopes.insert_value("__program_identifiers__", identifiers);
-
-            if let Some(py_identifiers) = py_identifiers {
-                // Store the Python identifiers directly in the context
-                context.set_item("py_identifiers", py_identifiers).map_err(|e| {
-                    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string())
-                })?;
-            }
-
-            // Import and run the initialization code from the injected module
-            let setup_code = r#"
-try:
-    from cairo_addons.hints.injected import prepare_context
-    prepare_context(lambda: globals())
-except Exception as e:
-    print(f"Warning: Error during initialization: {e}")
-"#;
-
-            // Run the initialization code
-            py.run(&CString::new(setup_code)?, Some(&context), None).map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                    "Failed to initialize Python globals: {}",
-                    e
-                ))
-            })?;
-
-            // Store the context object in the exec_scopes regardless of logger status
-            // This ensures the pythonic hint executor has a context to work with
-            let unbounded_context: Py<PyDict> = context.into_py_dict(py)?.into();
-            inner.exec_scopes.insert_value("__context__", unbounded_context);
-            Ok::<(), PyErr>(())
-        })?;
-
-        Ok(Self {
-            inner,
             allow_missing_builtins,
-            ordered_builtins: ordered_builtin_names,
             enable_traces,
-        })
+            ordered_builtin_names,
+        )
     }
 
     /// Initializes the runner's segments, including program_base, execution_base, and all builtins.
@@ -169,6 +154,19 @@ except Exception as e:
         Ok(())
     }
 
+    /// Initializes the stack and registers of the main entrypoint, and returns the address the
+    /// run ends at. In proof mode, this matches cairo-lang's `initialize_main_entrypoint`: the
+    /// execution segment starts with the fake return fp and pc cells, `fp = ap = 2` and the run
+    /// starts at `__start__`. Must be called between `initialize_segments` and `initialize_vm`.
+    pub fn initialize_main_entrypoint(&mut self) -> PyResult<PyRelocatable> {
+        let end = self
+            .inner
+            .initialize_main_entrypoint()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+        Ok(PyRelocatable { inner: end })
+    }
+
     /// Initializes the VM, preparing it for execution.
     /// Sets up the zero segment for modulo operations and initializes the VM's internal state.
     pub fn initialize_vm(&mut self) -> PyResult<()> {
@@ -184,6 +182,12 @@ except Exception as e:
         Ok(())
     }
 
+    /// Whether the runner was created in proof mode, see `initialize_main_entrypoint`.
+    #[getter]
+    fn proof_mode(&self) -> bool {
+        self.proof_mode
+    }
+
     #[getter]
     fn initial_pc(&self) -> Option<PyRelocatable> {
         self.inner.initial_pc.map(|x| PyRelocatable { inner: x })
@@ -332,11 +336,28 @@ except Exception as e:
         })
     }
 
+    /// Returns a dict mapping the name of each builtin to the number of memory cells used in its
+    /// segment so far, i.e. its number of used instances times its `cells_per_instance`.
+    fn builtin_usage(&mut self, py: Python<'_>) -> PyResult<PyObject> {
+        // Computing the segment sizes caches them in the VM, which must not happen mid-run.
+        let used_sizes = self.inner.vm.segments.segment_used_sizes.take();
+        let segment_sizes = self.inner.vm.segments.compute_effective_sizes().clone();
+        self.inner.vm.segments.segment_used_sizes = used_sizes;
+
+        let dict = PyDict::new(py);
+        for builtin_runner in self.inner.vm.get_builtin_runners() {
+            let used_cells = segment_sizes.get(builtin_runner.base()).copied().unwrap_or_default();
+            dict.set_item(builtin_runner.name().to_string(), used_cells)?;
+        }
+        Ok(dict.into())
+    }
+
     /// Runs the VM until the program counter reaches the specified address.
     ///
     /// # Arguments
     /// * `address` - The target address to run until
-    /// * `resources` - Resources limiting the execution (e.g., max steps)
+    /// * `resources` - Resources limiting the execution (e.g., max steps), updated with the steps
+    ///   consumed by the run
     ///
     /// Uses our own hint processor to handle Cairo hints during execution.
     /// This hint processor always supports pythonic hints execution, but will
@@ -346,13 +367,19 @@ except Exception as e:
     /// Ends the run after reaching the target address. If in proof mode this will loop on `jmp rel
     /// 0` until the steps is a power of 2.
     #[pyo3(signature = (address, resources))]
-    fn run_until_pc(&mut self, address: PyRelocatable, resources: PyRunResources) -> PyResult<()> {
-        let mut hint_processor = HintProcessor::default()
-            .with_run_resources(resources.inner)
-            .with_dynamic_python_hints(self.enable_traces)
-            .build();
-        self.inner
-            .run_until_pc(address.inner, &mut hint_processor)
+    fn run_until_pc(
+        &mut self,
+        address: PyRelocatable,
+        mut resources: PyRefMut<'_, PyRunResources>,
+    ) -> PyResult<()> {
+        let mut hint_processor = self.hint_processor(resources.inner.clone());
+        if let Some(memory_trace) = &self.memory_trace {
+            self.inner.vm.hooks = memory_trace_hooks(memory_trace.clone());
+        }
+        let result = self.inner.run_until_pc(address.inner, &mut hint_processor);
+        resources.inner = hint_processor.run_resources().clone();
+        self.run_resources = (*resources).clone();
+        result
             .map_err(|e| VmException::from_vm_error(&self.inner, e))
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
         self.inner
@@ -361,6 +388,28 @@ except Exception as e:
         Ok(())
     }
 
+    /// Runs at most `n` steps and returns the number of steps actually run, which is less than `n`
+    /// if the program reaches the `jmp rel 0` instruction ending it before.
+    ///
+    /// Unlike `run_until_pc`, the run is not ended, so that it can be resumed with further calls,
+    /// e.g. to inspect the memory between steps.
+    fn step_n(&mut self, n: usize) -> PyResult<usize> {
+        let end = self.program_end()?;
+        let mut hint_processor = self.hint_processor(RunResources::new(n));
+        if let Some(memory_trace) = &self.memory_trace {
+            self.inner.vm.hooks = memory_trace_hooks(memory_trace.clone());
+        }
+        let initial_step = self.inner.vm.get_current_step();
+        match self.inner.run_until_pc(end, &mut hint_processor) {
+            Ok(()) | Err(VirtualMachineError::UnfinishedExecution) => {}
+            Err(e) => {
+                let e = VmException::from_vm_error(&self.inner, e);
+                return Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()));
+            }
+        }
+        Ok(self.inner.vm.get_current_step() - initial_step)
+    }
+
     fn verify_auto_deductions(&mut self) -> PyResult<()> {
         self.inner
             .vm
@@ -369,6 +418,20 @@ except Exception as e:
         Ok(())
     }
 
+    /// Validates all the memory cells written so far against the validation rules of the
+    /// builtins, e.g. that range check cells are in range, raising a `RuntimeError` describing the
+    /// first invalid cell otherwise.
+    ///
+    /// Conflicting writes to a same cell are already rejected when running.
+    fn validate_existing_memory(&mut self) -> PyResult<()> {
+        self.inner.vm.segments.memory.validate_existing_memory().map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Memory validation failed: {}",
+                e
+            ))
+        })
+    }
+
     /// Reads return values from the stack, starting at the specified offset from ap.
     /// Processes builtin pointers in reverse order to construct the final return value.
     fn read_return_values(&mut self, offset: usize) -> PyResult<PyRelocatable> {
@@ -376,6 +439,24 @@ except Exception as e:
         Ok(PyRelocatable { inner: pointer })
     }
 
+    /// Returns the `n` values below `ap`, i.e. the return values of the function that just ended,
+    /// as ints or relocatables, with `None` for the cells that were never written.
+    fn get_return_values(&self, n: usize, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        let ap = self.inner.vm.get_ap();
+        let start = (ap - n).map_err(|_| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Cannot read {} return values below ap: {}",
+                n, ap
+            ))
+        })?;
+        (0..n)
+            .map(|i| match self.inner.vm.get_maybe(&(start + i).unwrap()) {
+                Some(value) => PyMaybeRelocatable::from(value).into_py_any(py),
+                None => Ok(py.None()),
+            })
+            .collect()
+    }
+
     fn verify_secure_runner(&mut self) -> PyResult<()> {
         verify_secure_runner(&self.inner, true, None)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
@@ -388,9 +469,221 @@ except Exception as e:
         self.inner
             .relocate(true)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+        self.relocation_rules.clear();
+        Ok(())
+    }
+
+    /// Replaces the program to run with the compiled Cairo program serialized in `json`.
+    /// Execution scopes are kept. Raises if the runner was already initialized.
+    /// The Python identifiers of the program are only loaded if traces are enabled.
+    fn load_program_from_string(&mut self, json: &str) -> PyResult<()> {
+        let program = parse_program(json.as_bytes(), None)?;
+        let py_identifiers = if self.enable_traces {
+            Python::with_gil(|py| -> PyResult<Option<PyObject>> {
+                let program = py
+                    .import("starkware.cairo.lang.compiler.program")?
+                    .getattr("Program")?
+                    .call_method0("Schema")?
+                    .call_method1("loads", (json,))?;
+                Ok(Some(program.getattr("identifiers")?.unbind()))
+            })?
+        } else {
+            None
+        };
+        self.replace_program(program, py_identifiers)
+    }
+
+    /// Replaces the program to run with the stripped program serialized in `json`.
+    /// Execution scopes are kept. Raises if the runner was already initialized.
+    /// As for compiled programs, `jmp rel 0` is appended to the program data.
+    fn load_stripped_program_from_string(&mut self, json: &str) -> PyResult<()> {
+        let mut stripped_program = parse_stripped_program(json)?;
+        append_jmp_rel_0(&mut stripped_program.data);
+        let program = stripped_program_to_program(&stripped_program)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        self.replace_program(program, None)
+    }
+
+    /// Adds a rule relocating the temporary segment starting at `src` to `dst`.
+    /// The rules are applied when the memory is relocated.
+    fn add_relocation_rule(&mut self, src: PyRelocatable, dst: PyRelocatable) -> PyResult<()> {
+        self.inner
+            .vm
+            .add_relocation_rule(src.inner, dst.inner)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        self.relocation_rules.insert(src.inner, dst.inner);
+        Ok(())
+    }
+
+    /// Returns the relocation rules that have not been applied yet, as a dict mapping the base of
+    /// each temporary segment to its destination.
+    fn get_relocation_table(&self) -> HashMap<PyRelocatable, PyRelocatable> {
+        self.relocation_rules
+            .iter()
+            .map(|(src, dst)| (PyRelocatable { inner: *src }, PyRelocatable { inner: *dst }))
+            .collect()
+    }
+
+    /// Pre-authorizes accesses to the memory cell at `address` without writing it, for hint
+    /// strategies that defer the write of a cell to a later point of the run.
+    ///
+    /// The cell stays unwritten: this only affects the result of `check_safe_access`.
+    fn mark_as_safe(&mut self, address: PyRelocatable) -> PyResult<()> {
+        self.safe_addresses.insert(address.inner);
         Ok(())
     }
 
+    /// Returns whether the memory cell at `address` is either written or was marked as safe with
+    /// `mark_as_safe`.
+    fn check_safe_access(&self, address: PyRelocatable) -> PyResult<bool> {
+        Ok(self.inner.vm.get_maybe(&address.inner).is_some() ||
+            self.safe_addresses.contains(&address.inner))
+    }
+
+    /// Enables or disables the recording of the memory accesses of the instructions run with
+    /// `run_until_pc`, for proof systems requiring a memory access log. Disabled by default, as
+    /// recording roughly doubles the memory used by the run. Disabling drops the recorded trace.
+    fn enable_memory_trace(&mut self, enabled: bool) {
+        if !enabled {
+            self.memory_trace = None;
+            self.inner.vm.hooks = Hooks::new(None, None, None);
+        } else if self.memory_trace.is_none() {
+            self.memory_trace = Some(Arc::new(Mutex::new(MemoryTrace::default())));
+        }
+    }
+
+    /// The profiler recording the number of executions and the execution time of each hint run
+    /// by `run_until_pc` and `step_n`.
+    #[cfg(feature = "hint-profiling")]
+    #[getter]
+    fn hint_profiler(&self) -> PyHintProfiler {
+        PyHintProfiler { inner: self.hint_profiler.clone() }
+    }
+
+    /// Returns the recorded memory accesses as `(address, is_write, value)` tuples, in access
+    /// order. Each instruction accesses its encoding at `pc`, then its `dst`, `op0` and `op1`
+    /// operands; an access is a write if the instruction deduced the cell.
+    /// Raises if the memory trace is not enabled.
+    fn get_memory_trace(&self) -> PyResult<Vec<(PyRelocatable, bool, PyMaybeRelocatable)>> {
+        let memory_trace = self.memory_trace.as_ref().ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Memory trace is not enabled")
+        })?;
+        let memory_trace = memory_trace.lock().unwrap();
+        Ok(memory_trace
+            .accesses()
+            .iter()
+            .map(|(address, is_write, value)| {
+                (
+                    PyRelocatable { inner: *address },
+                    *is_write,
+                    PyMaybeRelocatable::from(value.clone()),
+                )
+            })
+            .collect())
+    }
+
+    /// Serializes the runner state with bincode, so that execution can be resumed later from the
+    /// same point with `deserialize_state`.
+    ///
+    /// The snapshot holds the program, the memory, the registers, the step count, the trace of the
+    /// steps already run and the resources left after the last `run_until_pc`, plus the dict
+    /// trackers and preimages of the dict manager if `include_dict_managers` is set. Other
+    /// execution scope variables are not included.
+    /// Raises if the memory has temporary segments.
+    #[pyo3(signature = (include_dict_managers=true))]
+    fn serialize_state(&mut self, include_dict_managers: bool) -> PyResult<Py<PyBytes>> {
+        if self.inner.vm.segments.num_temp_segments() > 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+                "Cannot serialize a runner with temporary segments",
+            ));
+        }
+
+        // Computing the segment sizes caches them in the VM, which must not happen mid-run.
+        let used_sizes = self.inner.vm.segments.segment_used_sizes.take();
+        let segment_sizes = self.inner.vm.segments.compute_effective_sizes().clone();
+        self.inner.vm.segments.segment_used_sizes = used_sizes;
+
+        let segments = segment_sizes
+            .iter()
+            .enumerate()
+            .map(|(segment_index, size)| {
+                (0..*size)
+                    .map(|offset| {
+                        self.inner
+                            .vm
+                            .get_maybe(&Relocatable::from((segment_index as isize, offset)))
+                            .as_ref()
+                            .map(SerializedValue::from)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let dict_manager = if include_dict_managers {
+            let dict_manager =
+                self.inner.exec_scopes.get_dict_manager().map_err(|e| {
+                    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string())
+                })?;
+            let mut dict_manager = SerializedDictManager::from(&*dict_manager.borrow());
+            // Preimages registered by the hashdict hints are restored in the dict manager.
+            let registry = PreimageRegistry::from_exec_scopes(&mut self.inner.exec_scopes);
+            dict_manager.extend_preimages(registry.borrow().iter());
+            Some(dict_manager)
+        } else {
+            None
+        };
+
+        let program = self
+            .inner
+            .get_program()
+            .serialize()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+        let to_tuple = |x: Relocatable| (x.segment_index, x.offset);
+        let pc = self.inner.vm.get_pc();
+        let state = RunnerState {
+            program,
+            layout: self.layout.to_str().to_string(),
+            proof_mode: self.proof_mode,
+            allow_missing_builtins: self.allow_missing_builtins,
+            enable_traces: self.enable_traces,
+            ordered_builtins: self
+                .ordered_builtins
+                .iter()
+                .map(|b| b.to_str().to_string())
+                .collect(),
+            program_base: self.inner.program_base.map(to_tuple),
+            execution_base: self.inner.execution_base.map(to_tuple),
+            initial_pc: self.inner.initial_pc.map(to_tuple),
+            initial_ap: self.inner.initial_ap.map(to_tuple),
+            initial_fp: self.inner.initial_fp.map(to_tuple),
+            execution_public_memory: self.inner.execution_public_memory.clone(),
+            segments,
+            pc: to_tuple(pc),
+            ap: self.inner.vm.get_ap().offset,
+            fp: self.inner.vm.get_fp().offset,
+            current_step: self.inner.vm.get_current_step(),
+            trace: self.inner.vm.trace.as_ref().map(|trace| {
+                trace.iter().map(|entry| (to_tuple(entry.pc), entry.ap, entry.fp)).collect()
+            }),
+            run_resources: self.run_resources.to_parts(),
+            dict_manager,
+        };
+
+        let bytes = bincode::serde::encode_to_vec(&state, bincode::config::standard())
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+        Python::with_gil(|py| Ok(PyBytes::new(py, &bytes).into()))
+    }
+
+    /// Rebuilds a runner from a state serialized with `serialize_state`. The runner continues
+    /// execution from the point the state was serialized at.
+    #[classmethod]
+    fn deserialize_state(_cls: &Bound<'_, PyType>, data: &[u8]) -> PyResult<Self> {
+        let (state, _): (RunnerState, usize) =
+            bincode::serde::decode_from_slice(data, bincode::config::standard())
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Self::restore_state(state)
+    }
+
     /// Returns the execution trace as a Polars DataFrame.
     /// The DataFrame contains columns for pc, ap, and fp values at each step.
     #[getter]
@@ -427,6 +720,18 @@ except Exception as e:
         PyRelocatable { inner: self.inner.vm.get_pc() }
     }
 
+    /// The number of steps run so far.
+    #[getter]
+    fn current_step(&self) -> usize {
+        self.inner.vm.get_current_step()
+    }
+
+    /// A copy of the resources left after the last `run_until_pc`, unlimited if it never ran.
+    #[getter]
+    fn run_resources(&self) -> PyRunResources {
+        self.run_resources.clone()
+    }
+
     /// Updates the execution public memory with return data offsets.
     ///
     /// # Arguments
@@ -496,6 +801,23 @@ except Exception as e:
         Ok(())
     }
 
+    /// Returns the relocated memory as bytes, each cell being written as a 32-byte little-endian
+    /// felt, so that the cell at relocated address `i` starts at byte `32 * i`. Cells that were
+    /// never written, including the unused address 0, are written as zeros.
+    /// Relocates the memory first if it wasn't already.
+    fn export_memory_as_bytes(&mut self, py: Python<'_>) -> PyResult<Py<PyBytes>> {
+        if self.inner.relocated_memory.is_empty() {
+            self.relocate()?;
+        }
+        let bytes = self
+            .inner
+            .relocated_memory
+            .iter()
+            .flat_map(|cell| cell.unwrap_or(Felt252::ZERO).to_bytes_le())
+            .collect::<Vec<u8>>();
+        Ok(PyBytes::new(py, &bytes).into())
+    }
+
     /// Writes the AIR public input to a JSON file.
     /// Contains public information needed for proof verification.
     fn write_binary_air_public_input(&self, file_path: String) -> PyResult<()> {
@@ -547,9 +869,370 @@ except Exception as e:
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
         Ok(())
     }
+
+    /// Returns the AIR private input of the run as a Python dict, mapping each builtin to the
+    /// inputs of its instances, like the `get_air_private_input` of the Python runner.
+    ///
+    /// The `trace_path` and `memory_path` entries of the prover input format are left to the
+    /// caller, see `write_binary_air_private_input` to write the full input to a file.
+    fn get_air_private_input(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let json = self
+            .inner
+            .get_air_private_input()
+            .to_serializable(String::new(), String::new())
+            .serialize_json()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+        let private_input = py.import("json")?.call_method1("loads", (json,))?;
+        let private_input = private_input.downcast::<PyDict>()?;
+        private_input.del_item("trace_path")?;
+        private_input.del_item("memory_path")?;
+        Ok(private_input.clone().unbind().into_any())
+    }
+
+    /// Returns the variables of all execution scopes as a Python dict.
+    ///
+    /// Variables of inner scopes shadow the ones of outer scopes. Runner-internal variables,
+    /// prefixed with `__`, are omitted.
+    fn get_execution_scopes(&self) -> PyResult<PyObject> {
+        Python::with_gil(|py| {
+            let dict = PyDict::new(py);
+            for scope in self.inner.exec_scopes.data.iter() {
+                for (name, value) in scope.iter().filter(|(name, _)| !name.starts_with("__")) {
+                    dict.set_item(name, scope_variable_to_py(py, name, &**value)?)?;
+                }
+            }
+            Ok(dict.into())
+        })
+    }
+
+    /// Sets a variable in the current execution scope, so that it can be read by hints.
+    ///
+    /// Supported values are `int`, `Relocatable`, `DictManager`, `StrippedProgram`, `dict` of ints
+    /// or relocatables, and `list` of ints or relocatables. A list of `(key, value)` pairs is
+    /// stored as a `Vec<(Felt252, MaybeRelocatable)>`.
+    fn set_execution_scope_variable(&mut self, name: String, value: PyObject) -> PyResult<()> {
+        let value = Python::with_gil(|py| py_to_scope_variable(value.bind(py)))?;
+        self.inner.exec_scopes.assign_or_update_variable(&name, value);
+        Ok(())
+    }
+
+    /// Returns a variable of the current execution scope.
+    fn get_execution_scope_variable(&self, name: String) -> PyResult<PyObject> {
+        let variables = self
+            .inner
+            .exec_scopes
+            .get_local_variables()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+        let value = variables.get(&name).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyKeyError, _>(format!(
+                "Variable {} not found in execution scope",
+                name
+            ))
+        })?;
+        Python::with_gil(|py| scope_variable_to_py(py, &name, &**value))
+    }
+
+    /// Enters a new execution scope holding `scope_vars`.
+    ///
+    /// Supported values are the same as for `set_execution_scope_variable`.
+    fn enter_scope(&mut self, scope_vars: HashMap<String, PyObject>) -> PyResult<()> {
+        let scope_vars = Python::with_gil(|py| {
+            scope_vars
+                .into_iter()
+                .map(|(name, value)| Ok((name, py_to_scope_variable(value.bind(py))?)))
+                .collect::<PyResult<HashMap<_, _>>>()
+        })?;
+        self.inner.exec_scopes.enter_scope(scope_vars);
+        Ok(())
+    }
+
+    /// Exits the current execution scope, dropping its variables.
+    ///
+    /// Fails if the current scope is the main scope.
+    fn exit_scope(&mut self) -> PyResult<()> {
+        self.inner
+            .exec_scopes
+            .exit_scope()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    }
+
+    /// Runs all the post-execution steps required to prove a finished run, in one call.
+    ///
+    /// # Arguments
+    /// * `prover_config` - A dict with the following keys:
+    ///   * `output_dir` (required) - Directory where the prover inputs are written.
+    ///   * `format` - Either `"dict"` (default) to return the public input as a Python dict, or
+    ///     `"json"` to return it as a raw JSON string.
+    ///   * `memory_capacity` - Buffer capacity used when writing the memory file.
+    ///   * `pie_path` - Path of the Cairo PIE zip file, defaults to `output_dir/cairo_pie.zip`.
+    ///
+    /// The run is verified, relocated if needed, and the trace, memory, AIR public input, AIR
+    /// private input and Cairo PIE are written to disk.
+    ///
+    /// Returns a dict with keys `public_input`, `private_input_path` and `pie_path`.
+    fn generate_proof_data(
+        &mut self,
+        prover_config: &Bound<'_, PyDict>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let output_dir: PathBuf = prover_config
+            .get_item("output_dir")?
+            .ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyKeyError, _>(
+                    "prover_config is missing 'output_dir'",
+                )
+            })?
+            .extract()?;
+        let format: String = match prover_config.get_item("format")? {
+            Some(format) => format.extract()?,
+            None => "dict".to_string(),
+        };
+        if format != "dict" && format != "json" {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unsupported public input format: {}",
+                format
+            )));
+        }
+        let memory_capacity: usize = match prover_config.get_item("memory_capacity")? {
+            Some(capacity) => capacity.extract()?,
+            None => 5 * 1024 * 1024,
+        };
+        let pie_path: PathBuf = match prover_config.get_item("pie_path")? {
+            Some(path) => path.extract()?,
+            None => output_dir.join("cairo_pie.zip"),
+        };
+
+        self.verify_secure_runner()?;
+        if self.inner.relocated_trace.is_none() {
+            self.relocate()?;
+        }
+
+        std::fs::create_dir_all(&output_dir)?;
+        let trace_path = output_dir.join("trace.bin");
+        let memory_path = output_dir.join("memory.bin");
+        let private_input_path = output_dir.join("air_private_input.json");
+
+        self.write_binary_trace(trace_path.to_string_lossy().to_string())?;
+        self.write_binary_memory(memory_path.to_string_lossy().to_string(), memory_capacity)?;
+        self.write_binary_air_private_input(
+            trace_path,
+            memory_path,
+            private_input_path.to_string_lossy().to_string(),
+        )?;
+
+        let public_input = self
+            .inner
+            .get_air_public_input()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?
+            .serialize_json()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+        std::fs::write(output_dir.join("air_public_input.json"), &public_input)?;
+
+        self.inner
+            .get_cairo_pie()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?
+            .write_zip_file(&pie_path, false)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+
+        let result = PyDict::new(py);
+        if format == "json" {
+            result.set_item("public_input", public_input)?;
+        } else {
+            let public_input = py.import("json")?.call_method1("loads", (public_input,))?;
+            result.set_item("public_input", public_input)?;
+        }
+        result.set_item("private_input_path", private_input_path)?;
+        result.set_item("pie_path", pie_path)?;
+        Ok(result.into())
+    }
 }
 
 impl PyCairoRunner {
+    /// Creates a runner for `program`, with a fresh dict manager and Python hint context.
+    fn create(
+        program: &Program,
+        py_identifiers: Option<PyObject>,
+        layout: LayoutName,
+        proof_mode: bool,
+        allow_missing_builtins: bool,
+        enable_traces: bool,
+        ordered_builtins: Vec<BuiltinName>,
+    ) -> PyResult<Self> {
+        // No dynamic layout params, trace enabled, and disable_trace_padding can only be used in
+        // proof_mode.
+        let mut inner =
+            RustCairoRunner::new(program, layout, None, proof_mode, true, proof_mode)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+        let dict_manager = DictManager::new();
+        inner.exec_scopes.insert_value("dict_manager", Rc::new(RefCell::new(dict_manager)));
+
+        // Insert the _rust_ program_identifiers in the exec_scopes, so that we're able to pull
+        // identifier data when executing hints to build VmConsts.
+        inner.exec_scopes.insert_value("__program_identifiers__", program_identifiers(program));
+
+        // Store the context object in the exec_scopes regardless of logger status
+        // This ensures the pythonic hint executor has a context to work with
+        inner.exec_scopes.insert_value("__context__", python_context(py_identifiers)?);
+
+        Ok(Self {
+            inner,
+            allow_missing_builtins,
+            ordered_builtins,
+            enable_traces,
+            relocation_rules: HashMap::new(),
+            safe_addresses: HashSet::new(),
+            memory_trace: None,
+            layout,
+            proof_mode,
+            run_resources: PyRunResources::from(RunResources::default()),
+            #[cfg(feature = "hint-profiling")]
+            hint_profiler: Rc::new(RefCell::new(HintProfiler::default())),
+        })
+    }
+
+    /// Builds a runner from a deserialized `RunnerState`, replaying the initialization steps
+    /// before restoring the memory and registers.
+    fn restore_state(state: RunnerState) -> PyResult<Self> {
+        let program = Program::deserialize(&state.program, None)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        let layout = state.layout.parse::<PyLayout>()?.into();
+        let ordered_builtins = state
+            .ordered_builtins
+            .iter()
+            .map(|name| {
+                BuiltinName::from_str(name).ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Invalid builtin name: {}",
+                        name
+                    ))
+                })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let mut runner = Self::create(
+            &program,
+            None,
+            layout,
+            state.proof_mode,
+            state.allow_missing_builtins,
+            state.enable_traces,
+            ordered_builtins,
+        )?;
+
+        if state.program_base.is_some() {
+            runner.initialize_segments()?;
+        }
+        runner.inner.program_base = state.program_base.map(Relocatable::from);
+        runner.inner.execution_base = state.execution_base.map(Relocatable::from);
+        runner.inner.initial_pc = state.initial_pc.map(Relocatable::from);
+        runner.inner.initial_ap = state.initial_ap.map(Relocatable::from);
+        runner.inner.initial_fp = state.initial_fp.map(Relocatable::from);
+        runner.inner.execution_public_memory = state.execution_public_memory;
+        if state.initial_pc.is_some() && state.initial_ap.is_some() && state.initial_fp.is_some() {
+            runner.initialize_vm()?;
+        }
+
+        while runner.inner.vm.segments.num_segments() < state.segments.len() {
+            runner.inner.vm.add_memory_segment();
+        }
+        for (segment_index, segment) in state.segments.into_iter().enumerate() {
+            for (offset, value) in segment.into_iter().enumerate() {
+                let Some(value) = value else { continue };
+                runner
+                    .inner
+                    .vm
+                    .insert_value(
+                        Relocatable::from((segment_index as isize, offset)),
+                        MaybeRelocatable::from(value),
+                    )
+                    .map_err(|e| {
+                        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string())
+                    })?;
+            }
+        }
+
+        runner.inner.vm.set_pc(Relocatable::from(state.pc));
+        runner.inner.vm.set_ap(state.ap);
+        runner.inner.vm.set_fp(state.fp);
+        runner.inner.vm.current_step = state.current_step;
+        if let Some(trace) = state.trace {
+            runner.inner.vm.trace = Some(
+                trace
+                    .into_iter()
+                    .map(|(pc, ap, fp)| TraceEntry { pc: Relocatable::from(pc), ap, fp })
+                    .collect(),
+            );
+        }
+        runner.run_resources = PyRunResources::from_parts(state.run_resources);
+
+        if let Some(dict_manager) = state.dict_manager {
+            let dict_manager = DictManager::from(dict_manager);
+            runner
+                .inner
+                .exec_scopes
+                .insert_value("dict_manager", Rc::new(RefCell::new(dict_manager)));
+        }
+
+        Ok(runner)
+    }
+
+    /// Rebuilds the inner runner with `program`, moving over the execution scopes.
+    ///
+    /// The builtins, identifiers and Python hint context of the previous program are replaced by
+    /// the ones of `program`, with its _pythonic_ `py_identifiers` if given.
+    fn replace_program(
+        &mut self,
+        program: Program,
+        py_identifiers: Option<PyObject>,
+    ) -> PyResult<()> {
+        if self.inner.program_base.is_some() {
+            return Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+                "Cannot load a program once the runner is initialized",
+            ));
+        }
+
+        let mut inner = RustCairoRunner::new(
+            &program,
+            self.layout,
+            None, // dynamic_layout_params
+            self.proof_mode,
+            true,            // trace_enabled
+            self.proof_mode, // disable_trace_padding can only be used in proof_mode
+        )
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+        inner.exec_scopes = std::mem::replace(&mut self.inner.exec_scopes, ExecutionScopes::new());
+        inner.exec_scopes.data[0]
+            .insert("__program_identifiers__".to_string(), Box::new(program_identifiers(&program)));
+        inner.exec_scopes.data[0]
+            .insert("__context__".to_string(), Box::new(python_context(py_identifiers)?));
+
+        self.ordered_builtins = program.iter_builtins().copied().collect();
+        self.inner = inner;
+        Ok(())
+    }
+
+    /// Returns the hint processor of the runs of the runner, limited by `run_resources`.
+    fn hint_processor(&self, run_resources: RunResources) -> HintProcessor {
+        let hint_processor = HintProcessor::default()
+            .with_run_resources(run_resources)
+            .with_dynamic_python_hints(self.enable_traces);
+        #[cfg(feature = "hint-profiling")]
+        let hint_processor = hint_processor.with_profiler(self.hint_profiler.clone());
+        hint_processor.build()
+    }
+
+    /// Returns the address of the `jmp rel 0` instruction ending the program.
+    fn program_end(&self) -> PyResult<Relocatable> {
+        let program_base = self.inner.program_base.ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Runner is not initialized")
+        })?;
+        let program_len = self.inner.get_program().shared_program_data.data.len();
+        (program_base + program_len.saturating_sub(2))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    }
+
     /// Internal implementation of read_return_values with additional checks.
     /// Processes builtin pointers in reverse order and handles missing builtins.
     fn _read_return_values(&mut self, offset: usize) -> PyResult<Relocatable> {
@@ -587,6 +1270,190 @@ impl PyCairoRunner {
     }
 }
 
+/// Returns the _rust_ identifiers of `program`, by name.
+fn program_identifiers(program: &Program) -> HashMap<String, Identifier> {
+    program
+        .iter_identifiers()
+        .map(|(name, identifier)| (name.to_string(), identifier.clone()))
+        .collect()
+}
+
+/// Initializes a python context object that will be accessible throughout the execution of all
+/// hints, holding the _pythonic_ `py_identifiers` of the program if given.
+fn python_context(py_identifiers: Option<PyObject>) -> PyResult<Py<PyDict>> {
+    Python::with_gil(|py| {
+        let context = PyDict::new(py);
+
+        if let Some(py_identifiers) = py_identifiers {
+            // Store the Python identifiers directly in the context
+            context.set_item("py_identifiers", py_identifiers)?;
+        }
+
+        // Import and run the initialization code from the injected module
+        let setup_code = r#"
+try:
+    from cairo_addons.hints.injected import prepare_context
+    prepare_context(lambda: globals())
+except Exception as e:
+    print(f"Warning: Error during initialization: {e}")
+"#;
+
+        // Run the initialization code
+        py.run(&CString::new(setup_code)?, Some(&context), None).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Failed to initialize Python globals: {}",
+                e
+            ))
+        })?;
+
+        Ok(context.into_py_dict(py)?.into())
+    })
+}
+
+/// Converts an execution scope variable to a Python object.
+/// Rust-native values are converted to Python primitives, and the dict manager to its wrapper.
+fn scope_variable_to_py(py: Python<'_>, name: &str, value: &dyn Any) -> PyResult<PyObject> {
+    if let Some(felt) = value.downcast_ref::<Felt252>() {
+        return felt.to_biguint().into_py_any(py);
+    }
+    if let Some(relocatable) = value.downcast_ref::<Relocatable>() {
+        return PyRelocatable { inner: *relocatable }.into_py_any(py);
+    }
+    if let Some(maybe_relocatable) = value.downcast_ref::<MaybeRelocatable>() {
+        return PyMaybeRelocatable::from(maybe_relocatable.clone()).into_py_any(py);
+    }
+    if let Some(felts) = value.downcast_ref::<Vec<Felt252>>() {
+        return felts.iter().map(Felt252::to_biguint).collect::<Vec<_>>().into_py_any(py);
+    }
+    if let Some(entries) = value.downcast_ref::<Vec<(Felt252, MaybeRelocatable)>>() {
+        return entries
+            .iter()
+            .map(|(key, value)| (key.to_biguint(), PyMaybeRelocatable::from(value.clone())))
+            .collect::<Vec<_>>()
+            .into_py_any(py);
+    }
+    if let Some(entries) = value.downcast_ref::<HashMap<MaybeRelocatable, MaybeRelocatable>>() {
+        return entries
+            .iter()
+            .map(|(key, value)| {
+                (PyMaybeRelocatable::from(key.clone()), PyMaybeRelocatable::from(value.clone()))
+            })
+            .collect::<HashMap<_, _>>()
+            .into_py_any(py);
+    }
+    if let Some(values) = value.downcast_ref::<Vec<MaybeRelocatable>>() {
+        return values
+            .iter()
+            .cloned()
+            .map(PyMaybeRelocatable::from)
+            .collect::<Vec<_>>()
+            .into_py_any(py);
+    }
+    if let Some(value) = value.downcast_ref::<usize>() {
+        return value.into_py_any(py);
+    }
+    if let Some(value) = value.downcast_ref::<u64>() {
+        return value.into_py_any(py);
+    }
+    if let Some(value) = value.downcast_ref::<i64>() {
+        return value.into_py_any(py);
+    }
+    if let Some(value) = value.downcast_ref::<bool>() {
+        return value.into_py_any(py);
+    }
+    if let Some(value) = value.downcast_ref::<String>() {
+        return value.into_py_any(py);
+    }
+    if let Some(value) = value.downcast_ref::<BigUint>() {
+        return value.into_py_any(py);
+    }
+    if let Some(value) = value.downcast_ref::<BigInt>() {
+        return value.into_py_any(py);
+    }
+    if let Some(dict_manager) = value.downcast_ref::<Rc<RefCell<DictManager>>>() {
+        return PyDictManager { inner: dict_manager.clone() }.into_py_any(py);
+    }
+    if let Some(program) = value.downcast_ref::<StrippedProgram>() {
+        return PyStrippedProgram { inner: program.clone() }.into_py_any(py);
+    }
+    if let Some(object) = value.downcast_ref::<PyObject>() {
+        return Ok(object.clone_ref(py));
+    }
+    if let Some(dict) = value.downcast_ref::<Py<PyDict>>() {
+        return dict.clone_ref(py).into_py_any(py);
+    }
+    if let Some(registry) = value.downcast_ref::<Rc<RefCell<PreimageRegistry>>>() {
+        let dict = PyDict::new(py);
+        for (hashed_key, preimage) in registry.borrow().iter() {
+            let preimage = preimage.iter().cloned().map(PyMaybeRelocatable::from);
+            dict.set_item(hashed_key.to_biguint(), PyTuple::new(py, preimage)?)?;
+        }
+        return dict.into_py_any(py);
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+        "Execution scope variable {} cannot be converted to a Python object",
+        name
+    )))
+}
+
+/// Converts a Python object to an execution scope variable.
+fn py_to_scope_variable(value: &Bound<'_, PyAny>) -> PyResult<Box<dyn Any>> {
+    if let Ok(dict_manager) = value.extract::<PyRef<'_, PyDictManager>>() {
+        return Ok(Box::new(dict_manager.inner.clone()));
+    }
+    if let Ok(relocatable) = value.extract::<PyRelocatable>() {
+        return Ok(Box::new(relocatable.inner));
+    }
+    if let Ok(program) = value.extract::<PyRef<'_, PyStrippedProgram>>() {
+        return Ok(Box::new(program.inner.clone()));
+    }
+    if let Ok(int) = value.extract::<BigInt>() {
+        return Ok(Box::new(felt_from_int(&int)?));
+    }
+    if let Ok(dict) = value.extract::<HashMap<PyMaybeRelocatable, PyMaybeRelocatable>>() {
+        return Ok(Box::new(
+            dict.into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect::<HashMap<MaybeRelocatable, MaybeRelocatable>>(),
+        ));
+    }
+    if let Ok(entries) = value.extract::<Vec<(BigInt, PyMaybeRelocatable)>>() {
+        return Ok(Box::new(
+            entries
+                .into_iter()
+                .map(|(key, value)| Ok((felt_from_int(&key)?, value.into())))
+                .collect::<PyResult<Vec<(Felt252, MaybeRelocatable)>>>()?,
+        ));
+    }
+    if let Ok(values) = value.extract::<Vec<PyMaybeRelocatable>>() {
+        return Ok(Box::new(
+            values.into_iter().map(MaybeRelocatable::from).collect::<Vec<MaybeRelocatable>>(),
+        ));
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+        "Unsupported execution scope variable type: {}",
+        value.get_type()
+    )))
+}
+
+/// Converts a Python int to a felt, raising a `ValueError` if it is negative or not below the
+/// field prime instead of reducing it.
+fn felt_from_int(int: &BigInt) -> PyResult<Felt252> {
+    if int.sign() == Sign::Minus {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Negative int {} cannot be converted to a felt",
+            int
+        )));
+    }
+    if int.magnitude() > &Felt252::MAX.to_biguint() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Int {} is not below the field prime",
+            int
+        )));
+    }
+    Ok(Felt252::from(int.magnitude()))
+}
+
 /// Runs the Cairo program in proof mode with public and private inputs.
 /// Mimics the behavior of the `run` function from cairo-vm-cli.
 #[allow(clippy::too_many_arguments)]
@@ -0,0 +1,172 @@
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::dict_manager::{DictKey, DictManager, DictTracker},
+    types::relocatable::{MaybeRelocatable, Relocatable},
+    Felt252,
+};
+use serde::{Deserialize, Serialize};
+
+/// A `(segment_index, offset)` pair.
+pub(crate) type SerializedRelocatable = (isize, usize);
+
+/// Snapshot of a `CairoRunner`, serialized with bincode by `CairoRunner.serialize_state`.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct RunnerState {
+    /// The program, serialized with `Program::serialize`.
+    pub(crate) program: Vec<u8>,
+    pub(crate) layout: String,
+    pub(crate) proof_mode: bool,
+    pub(crate) allow_missing_builtins: bool,
+    pub(crate) enable_traces: bool,
+    pub(crate) ordered_builtins: Vec<String>,
+    pub(crate) program_base: Option<SerializedRelocatable>,
+    pub(crate) execution_base: Option<SerializedRelocatable>,
+    pub(crate) initial_pc: Option<SerializedRelocatable>,
+    pub(crate) initial_ap: Option<SerializedRelocatable>,
+    pub(crate) initial_fp: Option<SerializedRelocatable>,
+    pub(crate) execution_public_memory: Option<Vec<usize>>,
+    /// The cells of every memory segment, with `None` for holes.
+    pub(crate) segments: Vec<Vec<Option<SerializedValue>>>,
+    pub(crate) pc: SerializedRelocatable,
+    pub(crate) ap: usize,
+    pub(crate) fp: usize,
+    pub(crate) current_step: usize,
+    /// The `(pc, ap, fp)` entries of the steps already run, if the trace is enabled.
+    pub(crate) trace: Option<Vec<(SerializedRelocatable, usize, usize)>>,
+    /// The step limit and remaining steps of the resources of the last `run_until_pc`.
+    pub(crate) run_resources: (Option<usize>, Option<usize>),
+    pub(crate) dict_manager: Option<SerializedDictManager>,
+}
+
+/// A memory value, with felts stored as 32 big-endian bytes.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) enum SerializedValue {
+    Int([u8; 32]),
+    Relocatable(SerializedRelocatable),
+}
+
+impl From<&MaybeRelocatable> for SerializedValue {
+    fn from(value: &MaybeRelocatable) -> Self {
+        match value {
+            MaybeRelocatable::Int(felt) => Self::Int(felt.to_bytes_be()),
+            MaybeRelocatable::RelocatableValue(relocatable) => {
+                Self::Relocatable((relocatable.segment_index, relocatable.offset))
+            }
+        }
+    }
+}
+
+impl From<SerializedValue> for MaybeRelocatable {
+    fn from(value: SerializedValue) -> Self {
+        match value {
+            SerializedValue::Int(bytes) => Self::Int(Felt252::from_bytes_be(&bytes)),
+            SerializedValue::Relocatable(relocatable) => {
+                Self::RelocatableValue(Relocatable::from(relocatable))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) enum SerializedDictKey {
+    Simple(SerializedValue),
+    Compound(Vec<SerializedValue>),
+}
+
+impl From<&DictKey> for SerializedDictKey {
+    fn from(key: &DictKey) -> Self {
+        match key {
+            DictKey::Simple(value) => Self::Simple(value.into()),
+            DictKey::Compound(values) => Self::Compound(values.iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<SerializedDictKey> for DictKey {
+    fn from(key: SerializedDictKey) -> Self {
+        match key {
+            SerializedDictKey::Simple(value) => Self::Simple(value.into()),
+            SerializedDictKey::Compound(values) => {
+                Self::Compound(values.into_iter().map(Into::into).collect())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct SerializedDictTracker {
+    segment_index: isize,
+    current_ptr: SerializedRelocatable,
+    default_value: Option<SerializedValue>,
+    data: Vec<(SerializedDictKey, SerializedValue)>,
+}
+
+/// The trackers and key preimages of a `DictManager`.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct SerializedDictManager {
+    trackers: Vec<SerializedDictTracker>,
+    preimages: Vec<(SerializedValue, SerializedDictKey)>,
+}
+
+impl From<&DictManager> for SerializedDictManager {
+    fn from(dict_manager: &DictManager) -> Self {
+        let trackers = dict_manager
+            .trackers
+            .iter()
+            .map(|(segment_index, tracker)| SerializedDictTracker {
+                segment_index: *segment_index,
+                current_ptr: (tracker.current_ptr.segment_index, tracker.current_ptr.offset),
+                default_value: tracker.get_default_value().map(Into::into),
+                data: tracker
+                    .get_dictionary_ref()
+                    .iter()
+                    .map(|(key, value)| (key.into(), value.into()))
+                    .collect(),
+            })
+            .collect();
+        let preimages =
+            dict_manager.preimages.iter().map(|(key, value)| (key.into(), value.into())).collect();
+        Self { trackers, preimages }
+    }
+}
+
+impl SerializedDictManager {
+    /// Adds the `(hashed_key, preimage)` pairs of compound keys to the serialized preimages.
+    pub(crate) fn extend_preimages<'a>(
+        &mut self,
+        preimages: impl Iterator<Item = (&'a Felt252, &'a Vec<MaybeRelocatable>)>,
+    ) {
+        self.preimages.extend(preimages.map(|(hashed_key, preimage)| {
+            (
+                (&MaybeRelocatable::from(*hashed_key)).into(),
+                (&DictKey::Compound(preimage.clone())).into(),
+            )
+        }));
+    }
+}
+
+impl From<SerializedDictManager> for DictManager {
+    fn from(serialized: SerializedDictManager) -> Self {
+        let mut dict_manager = Self::new();
+        for SerializedDictTracker { segment_index, current_ptr, default_value, data } in
+            serialized.trackers
+        {
+            let current_ptr = Relocatable::from(current_ptr);
+            let data = data.into_iter().map(|(key, value)| (key.into(), value.into()));
+            let tracker = match default_value {
+                Some(default_value) => DictTracker::new_default_dict(
+                    current_ptr,
+                    &default_value.into(),
+                    Some(data.collect()),
+                ),
+                None => DictTracker::new_with_initial(current_ptr, data.collect()),
+            };
+            dict_manager.trackers.insert(segment_index, tracker);
+        }
+        dict_manager.preimages = serialized
+            .preimages
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+        dict_manager
+    }
+}
@@ -1,6 +1,17 @@
 use crate::vm::{builtins::PyBuiltinList, maybe_relocatable::PyMaybeRelocatable};
-use cairo_vm::vm::runners::cairo_pie::StrippedProgram;
-use pyo3::prelude::*;
+use cairo_vm::{
+    serde::deserialize_program::ReferenceManager,
+    types::{errors::program_errors::ProgramError, program::Program},
+    vm::runners::cairo_pie::StrippedProgram,
+    Felt252,
+};
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use pyo3::{prelude::*, IntoPyObjectExt};
+use std::collections::HashMap;
+
+/// Version of the bootloader program encoding produced by `to_felt_list`.
+const BOOTLOADER_VERSION: u64 = 0;
 
 #[pyclass(name = "StrippedProgram")]
 #[derive(Clone)]
@@ -47,4 +58,125 @@ impl PyStrippedProgram {
     fn set_main(&mut self, main: usize) {
         self.inner.main = main;
     }
+
+    /// Encodes the program as the list of felts hashed by the bootloader to compute the program
+    /// hash: `[bootloader_version, main, n_builtins, *builtins, *data]`, where each builtin is the
+    /// big-endian felt of its ASCII name.
+    fn to_felt_list(&self, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        let header = [
+            Felt252::from(BOOTLOADER_VERSION),
+            Felt252::from(self.inner.main),
+            Felt252::from(self.inner.builtins.len()),
+        ];
+        let builtins = self
+            .inner
+            .builtins
+            .iter()
+            .map(|builtin| Felt252::from_bytes_be_slice(builtin.to_str().as_bytes()));
+        let data = self
+            .inner
+            .data
+            .iter()
+            .map(|value| {
+                value.get_int().ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>("Program data must be felts")
+                })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        header
+            .into_iter()
+            .chain(builtins)
+            .chain(data)
+            .map(|felt| felt.to_biguint().into_py_any(py))
+            .collect()
+    }
+
+    /// Decodes a program encoded with `to_felt_list`.
+    #[staticmethod]
+    fn from_felt_list(felts: Vec<BigUint>) -> PyResult<Self> {
+        let invalid = |reason: &str| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid program encoding: {}",
+                reason
+            ))
+        };
+        let felts = felts.iter().map(Felt252::from).collect::<Vec<_>>();
+        let [version, main, n_builtins, rest @ ..] = felts.as_slice() else {
+            return Err(invalid("missing header"));
+        };
+        if *version != Felt252::from(BOOTLOADER_VERSION) {
+            return Err(invalid(&format!("unsupported bootloader version {}", version)));
+        }
+        let main = main.to_usize().ok_or_else(|| invalid("main is out of range"))?;
+        let n_builtins = n_builtins
+            .to_usize()
+            .filter(|n_builtins| *n_builtins <= rest.len())
+            .ok_or_else(|| invalid("missing builtins"))?;
+        let (builtins, data) = rest.split_at(n_builtins);
+
+        let builtins = builtins
+            .iter()
+            .map(|builtin| {
+                let bytes = builtin.to_bytes_be();
+                let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(bytes.len());
+                String::from_utf8(bytes[start..].to_vec())
+                    .map_err(|_| invalid("builtins must be ASCII names"))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        let builtins = PyBuiltinList::from(builtins).into_builtin_names()?;
+        let data = data.iter().map(|felt| (*felt).into()).collect();
+
+        Ok(Self { inner: StrippedProgram { data, builtins, main, prime: () } })
+    }
+}
+
+/// Builds a runnable program from a stripped program, without hints nor identifiers.
+pub(crate) fn stripped_program_to_program(
+    program: &StrippedProgram,
+) -> Result<Program, ProgramError> {
+    Program::new(
+        program.builtins.clone(),
+        program.data.clone(),
+        Some(program.main),
+        HashMap::new(),
+        ReferenceManager { references: Vec::new() },
+        HashMap::new(),
+        Vec::new(),
+        None,
+    )
+}
+
+/// Parses a stripped program from its JSON representation, i.e. an object with `data` (a list
+/// of hex-encoded felts), `builtins` and `main`.
+pub(crate) fn parse_stripped_program(json: &str) -> PyResult<StrippedProgram> {
+    let invalid = |reason: &str| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid stripped program: {}",
+            reason
+        ))
+    };
+    let value: serde_json::Value = serde_json::from_str(json)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    let data = value["data"]
+        .as_array()
+        .ok_or_else(|| invalid("missing data"))?
+        .iter()
+        .map(|x| {
+            x.as_str()
+                .and_then(|x| Felt252::from_hex(x).ok())
+                .map(Into::into)
+                .ok_or_else(|| invalid("data must be hex-encoded felts"))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let builtins = value["builtins"]
+        .as_array()
+        .ok_or_else(|| invalid("missing builtins"))?
+        .iter()
+        .map(|x| x.as_str().map(String::from).ok_or_else(|| invalid("builtins must be strings")))
+        .collect::<PyResult<Vec<_>>>()?;
+    let builtins = PyBuiltinList::from(builtins).into_builtin_names()?;
+    let main = value["main"].as_u64().ok_or_else(|| invalid("missing main"))? as usize;
+
+    Ok(StrippedProgram { data, builtins, main, prime: () })
 }
@@ -56,10 +56,11 @@ use cairo_vm::{
     types::relocatable::{MaybeRelocatable, Relocatable},
     vm::vm_core::VirtualMachine,
 };
+use num_bigint::BigInt;
 use pyo3::{
     exceptions::{PyAttributeError, PyRuntimeError, PyTypeError},
     prelude::*,
-    types::PyList,
+    types::{PyIterator, PyList},
     IntoPyObjectExt, PyResult,
 };
 
@@ -327,6 +328,17 @@ impl PyVmConst {
             _ => Ok(self.var.address),
         }
     }
+
+    /// Gets the value of the variable as an integer, for felt values only.
+    fn int_value(&self) -> PyResult<BigInt> {
+        match &self.var.value {
+            Some(MaybeRelocatable::Int(felt)) => Ok(felt.to_biguint().into()),
+            _ => Err(PyTypeError::new_err(format!(
+                "Cannot convert '{}' to an int: not a felt value",
+                self.var.name
+            ))),
+        }
+    }
 }
 
 #[pymethods]
@@ -533,6 +545,34 @@ impl PyVmConst {
             Err(PyTypeError::new_err(format!("Cannot dereference non-pointer '{}'", self.var.name)))
         }
     }
+
+    /// Converts a felt variable to a Python int.
+    pub fn __int__(&self) -> PyResult<BigInt> {
+        self.int_value()
+    }
+
+    /// Adds an int to a felt variable, returning a Python int.
+    pub fn __add__(&self, other: BigInt) -> PyResult<BigInt> {
+        Ok(self.int_value()? + other)
+    }
+
+    pub fn __radd__(&self, other: BigInt) -> PyResult<BigInt> {
+        self.__add__(other)
+    }
+
+    /// Subtracts an int from a felt variable, returning a Python int.
+    pub fn __sub__(&self, other: BigInt) -> PyResult<BigInt> {
+        Ok(self.int_value()? - other)
+    }
+
+    /// Multiplies a felt variable by an int, returning a Python int.
+    pub fn __mul__(&self, other: BigInt) -> PyResult<BigInt> {
+        Ok(self.int_value()? * other)
+    }
+
+    pub fn __rmul__(&self, other: BigInt) -> PyResult<BigInt> {
+        self.__mul__(other)
+    }
 }
 
 /// A dictionary that stores Cairo variables and allows access by name
@@ -646,6 +686,27 @@ impl PyVmConstsDict {
         self.keys(py)
     }
 
+    /// Lists all `(name, value)` pairs.
+    pub fn items(&self, py: Python<'_>) -> PyResult<Vec<(String, PyObject)>> {
+        self.items.keys().map(|name| Ok((name.clone(), self.__getattr__(name, py)?))).collect()
+    }
+
+    /// Iterates over the variable names, like a Python dict.
+    pub fn __iter__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let keys = self.keys(py)?;
+        Ok(PyIterator::from_object(keys.bind(py))?.into_any().unbind())
+    }
+
+    /// Number of variables.
+    pub fn __len__(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Supports Python's `in` operator on variable names.
+    pub fn __contains__(&self, name: &str) -> bool {
+        self.items.contains_key(name)
+    }
+
     /// String representation.
     pub fn __str__(&self) -> String {
         format!("VmConstsDict with {} items", self.items.len())
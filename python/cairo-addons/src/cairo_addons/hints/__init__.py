@@ -1,15 +1,58 @@
 # ruff: noqa: F403
+from cairo_addons.hints.basefee import *
+from cairo_addons.hints.batch_verify import *
+from cairo_addons.hints.bitwise_decompose import *
+from cairo_addons.hints.bloom import *
+from cairo_addons.hints.bytes31 import *
 from cairo_addons.hints.bytes_hints import *
+from cairo_addons.hints.cairo_run_inner import *
+from cairo_addons.hints.calldata import *
+from cairo_addons.hints.chunk_hash import *
 from cairo_addons.hints.circuits import *
+from cairo_addons.hints.commitment_tree import *
 from cairo_addons.hints.curve import *
 from cairo_addons.hints.decorator import implementations, register_hint
 from cairo_addons.hints.dict import *
+from cairo_addons.hints.ecadd_projective import *
+from cairo_addons.hints.ecdsa_hash import *
+from cairo_addons.hints.event import *
 from cairo_addons.hints.ethereum import *
+from cairo_addons.hints.evm import *
+from cairo_addons.hints.felt252_array_ops import *
+from cairo_addons.hints.felt252_dict_entry import *
+from cairo_addons.hints.felt252_dict_init import *
+from cairo_addons.hints.felt_comparison import *
+from cairo_addons.hints.gas_table import *
+from cairo_addons.hints.hardfork import *
+from cairo_addons.hints.hash_to_curve import *
 from cairo_addons.hints.hashdict import *
+from cairo_addons.hints.keccak import *
+from cairo_addons.hints.kv_cache import *
+from cairo_addons.hints.l1_handler import *
 from cairo_addons.hints.maths import *
+from cairo_addons.hints.memory_diff import *
+from cairo_addons.hints.mpt import *
 from cairo_addons.hints.os import *
+from cairo_addons.hints.output_ops import *
+from cairo_addons.hints.pedersen import *
+from cairo_addons.hints.point_on_curve import *
+from cairo_addons.hints.poseidon import *
+from cairo_addons.hints.precompile_bn256 import *
+from cairo_addons.hints.precompile_ripemd import *
 from cairo_addons.hints.precompiles import *
+from cairo_addons.hints.receipt import *
+from cairo_addons.hints.recursive_hasher import *
+from cairo_addons.hints.rlp import *
+from cairo_addons.hints.secp256k1 import *
+from cairo_addons.hints.slot_hash import *
+from cairo_addons.hints.storage_write_log import *
+from cairo_addons.hints.syscall_gas import *
+from cairo_addons.hints.trie_key import *
+from cairo_addons.hints.tx_hash import *
+from cairo_addons.hints.u8_arithmetic import *
+from cairo_addons.hints.uint256 import *
 from cairo_addons.hints.utils import *
+from cairo_addons.hints.word_reverse import *
 
 __all__ = [
     "register_hint",
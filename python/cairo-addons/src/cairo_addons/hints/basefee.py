@@ -0,0 +1,27 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def compute_next_base_fee(ids: VmConsts):
+    from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+
+    BASE_FEE_MAX_CHANGE_DENOMINATOR = 8
+
+    parent_base_fee = ids.parent_base_fee
+    parent_gas_used = ids.parent_gas_used
+    parent_gas_target = ids.parent_gas_target
+    assert parent_gas_target != 0, "parent_gas_target is zero"
+
+    denominator = parent_gas_target * BASE_FEE_MAX_CHANGE_DENOMINATOR
+    if parent_gas_used == parent_gas_target:
+        next_base_fee = parent_base_fee
+    elif parent_gas_used > parent_gas_target:
+        delta = parent_base_fee * (parent_gas_used - parent_gas_target) // denominator
+        next_base_fee = parent_base_fee + max(delta, 1)
+    else:
+        delta = parent_base_fee * (parent_gas_target - parent_gas_used) // denominator
+        next_base_fee = parent_base_fee - delta
+    assert next_base_fee < DEFAULT_PRIME, "next_base_fee exceeds the field"
+    ids.next_base_fee = next_base_fee
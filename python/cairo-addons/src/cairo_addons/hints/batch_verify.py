@@ -0,0 +1,32 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def batch_verify_stark_ecdsa(
+    ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager
+):
+    from starkware.crypto.signature.signature import verify
+
+    def is_valid_signature(public_key, msg_hash, r, s):
+        try:
+            return verify(msg_hash, r, s, public_key)
+        except Exception:
+            return False
+
+    results = []
+    for i in range(ids.n_signatures):
+        public_key, msg_hash, r, s = [
+            memory[ids.signatures + 4 * i + j] for j in range(4)
+        ]
+        results.append(is_valid_signature(public_key, msg_hash, r, s))
+
+    ids.bitmap = segments.gen_arg(
+        [
+            sum(int(is_valid) << j for j, is_valid in enumerate(results[i : i + 128]))
+            for i in range(0, len(results), 128)
+        ]
+    )
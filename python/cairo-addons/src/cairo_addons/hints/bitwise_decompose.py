@@ -0,0 +1,20 @@
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def felt_to_bits_le(ids: VmConsts, segments: MemorySegmentManager):
+    if ids.value.bit_length() > ids.n_bits:
+        raise ValueError("Value exceeds n_bits")
+    ids.bits_ptr = segments.gen_arg([(ids.value >> i) & 1 for i in range(ids.n_bits)])
+
+
+@register_hint
+def felt_to_bits_be(ids: VmConsts, segments: MemorySegmentManager):
+    if ids.value.bit_length() > ids.n_bits:
+        raise ValueError("Value exceeds n_bits")
+    ids.bits_ptr = segments.gen_arg(
+        [(ids.value >> i) & 1 for i in reversed(range(ids.n_bits))]
+    )
@@ -0,0 +1,19 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def bloom_filter_check(ids: VmConsts, memory: MemoryDict):
+    from ethereum.cancun.bloom import add_to_bloom
+
+    bloom = [memory[ids.bloom_ptr + i] for i in range(256)]
+    topic = [memory[ids.topic_ptr + i] for i in range(32)]
+    if any(not 0 <= byte <= 255 for byte in bloom + topic):
+        raise ValueError("Value is not a byte")
+
+    # The bits of the topic are the ones it sets in an empty filter.
+    topic_bits = bytearray(256)
+    add_to_bloom(topic_bits, bytes(topic))
+    ids.present = int(all(byte & bits == bits for byte, bits in zip(bloom, topic_bits)))
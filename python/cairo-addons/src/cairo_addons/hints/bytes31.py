@@ -0,0 +1,36 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def bytes31_from_byte_array(ids: VmConsts, memory: MemoryDict):
+    values = [memory[ids.bytes + i] for i in range(31)]
+    for i, byte in enumerate(values):
+        if not 0 <= byte <= 255:
+            raise ValueError(f"Byte at index {i} out of range: {byte}")
+    ids.value = int.from_bytes(bytes(values), "big")
+
+
+@register_hint
+def bytes31_to_byte_array(ids: VmConsts, memory: MemoryDict):
+    from cairo_addons.hints.bytes31 import read_bytes31
+
+    for i, byte in enumerate(read_bytes31(ids.value)):
+        memory[ids.bytes + i] = byte
+
+
+@register_hint
+def bytes31_at_index(ids: VmConsts):
+    from cairo_addons.hints.bytes31 import read_bytes31
+
+    if not 0 <= ids.index < 31:
+        raise ValueError(f"Index out of range: {ids.index}")
+    ids.byte = read_bytes31(ids.value)[ids.index]
+
+
+def read_bytes31(value: int) -> bytes:
+    if value.bit_length() > 248:
+        raise ValueError(f"Value does not fit in bytes31: {value}")
+    return value.to_bytes(31, "big")
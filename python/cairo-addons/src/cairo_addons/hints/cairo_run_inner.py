@@ -0,0 +1,52 @@
+from typing import Callable
+
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def cairo_run_inner(
+    ids: VmConsts,
+    memory: MemoryDict,
+    segments: MemorySegmentManager,
+    vm_enter_scope: Callable,
+):
+    from starkware.cairo.lang.vm.cairo_runner import CairoRunner
+
+    program_id = ids.program_id.to_bytes(31, "big").lstrip(b"\x00").decode()
+    # `vm_enter_scope` is bound to the VM running the hint, whose last execution scope
+    # holds the variables of the current scope.
+    exec_scope = vm_enter_scope.__self__.exec_scopes[-1]
+    if program_id not in exec_scope:
+        raise KeyError(f"Variable {program_id} not present in current execution scope")
+    program = exec_scope[program_id]
+    args = [memory[ids.args_ptr + i] for i in range(ids.args_len)]
+
+    inner_runner = CairoRunner(program=program, layout="all_cairo")
+    inner_runner.initialize_segments()
+    stack = [
+        value
+        for builtin in program.builtins
+        for value in inner_runner.builtin_runners[f"{builtin}_builtin"].initial_stack()
+    ]
+    end = inner_runner.initialize_function_entrypoint(program.main, stack + args)
+    inner_runner.initialize_vm(hint_locals={})
+    try:
+        inner_runner.run_until_pc(end)
+    except Exception as e:
+        raise Exception(f"Inner program {program_id} failed: {e}") from e
+
+    output = []
+    output_runner = inner_runner.builtin_runners.get("output_builtin")
+    if output_runner is not None:
+        inner_runner.segments.compute_effective_sizes()
+        output_size = inner_runner.segments.get_segment_used_size(
+            output_runner.base.segment_index
+        )
+        output = [
+            inner_runner.vm_memory[output_runner.base + i] for i in range(output_size)
+        ]
+    ids.output_ptr = segments.gen_arg(output)
@@ -0,0 +1,53 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.relocatable import RelocatableValue
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def abi_decode_uint256(ids: VmConsts, memory: MemoryDict):
+    from cairo_addons.hints.calldata import read_word
+    from cairo_addons.utils.uint256 import int_to_uint256
+
+    value = read_word(memory, ids.calldata_ptr, ids.calldata_len, ids.word_offset * 32)
+    ids.low, ids.high = int_to_uint256(value)
+
+
+@register_hint
+def abi_decode_address(ids: VmConsts, memory: MemoryDict):
+    from cairo_addons.hints.calldata import read_word
+
+    value = read_word(memory, ids.calldata_ptr, ids.calldata_len, ids.word_offset * 32)
+    address = value.to_bytes(32, "big")[12:]
+    ids.address = int.from_bytes(address, "little")
+
+
+@register_hint
+def abi_decode_bytes_dynamic(ids: VmConsts, memory: MemoryDict):
+    from cairo_addons.hints.calldata import read_word
+
+    data_offset = read_word(
+        memory, ids.calldata_ptr, ids.calldata_len, ids.word_offset * 32
+    )
+    data_len = read_word(memory, ids.calldata_ptr, ids.calldata_len, data_offset)
+    assert data_offset + 32 + data_len <= ids.calldata_len, (
+        f"Bytes of length {data_len} at offset {data_offset} "
+        f"exceed calldata length {ids.calldata_len}"
+    )
+    ids.data_ptr = ids.calldata_ptr + data_offset + 32
+    ids.data_len = data_len
+
+
+def read_word(
+    memory: MemoryDict,
+    calldata_ptr: RelocatableValue,
+    calldata_len: int,
+    byte_offset: int,
+) -> int:
+    assert (
+        byte_offset + 32 <= calldata_len
+    ), f"Word at offset {byte_offset} exceeds calldata length {calldata_len}"
+    return int.from_bytes(
+        bytes([memory[calldata_ptr + byte_offset + i] for i in range(32)]), "big"
+    )
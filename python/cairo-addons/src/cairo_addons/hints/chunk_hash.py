@@ -0,0 +1,18 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def poseidon_chunk_hash(ids: VmConsts, memory: MemoryDict):
+    from starkware.cairo.common.poseidon_hash import poseidon_hash_many
+
+    chunk_size = 1024
+    data = [memory[ids.data_ptr + i] for i in range(ids.data_len)]
+    while len(data) > chunk_size:
+        data = [
+            poseidon_hash_many(data[i : i + chunk_size])
+            for i in range(0, len(data), chunk_size)
+        ]
+    ids.root_hash = poseidon_hash_many(data)
@@ -0,0 +1,66 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def merkle_tree_build(
+    ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager
+):
+    from cairo_addons.hints.commitment_tree import build_tree
+
+    n_leaves = ids.n_leaves
+    if n_leaves <= 0 or n_leaves & (n_leaves - 1) != 0:
+        raise ValueError(f"Number of leaves is not a power of two: {n_leaves}")
+    leaves = [memory[ids.leaves_ptr + i] for i in range(n_leaves)]
+
+    tree = build_tree(leaves)
+    ids.tree_ptr = segments.gen_arg(tree)
+    ids.tree_len = len(tree)
+    ids.root = tree[-1]
+
+
+@register_hint
+def merkle_tree_get_proof(
+    ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager
+):
+    from cairo_addons.hints.commitment_tree import merkle_proof
+
+    tree_len = ids.tree_len
+    n_leaves = (tree_len + 1) // 2
+    if n_leaves <= 0 or n_leaves & (n_leaves - 1) != 0 or tree_len != 2 * n_leaves - 1:
+        raise ValueError(f"Invalid Merkle tree length: {tree_len}")
+    if ids.leaf_index >= n_leaves:
+        raise ValueError(f"Leaf index out of range: {ids.leaf_index}")
+    tree = [memory[ids.tree_ptr + i] for i in range(tree_len)]
+
+    ids.proof = segments.gen_arg(merkle_proof(tree, n_leaves, ids.leaf_index))
+
+
+def build_tree(leaves):
+    from starkware.cairo.common.poseidon_hash import poseidon_hash
+
+    tree = list(leaves)
+    level_start, level_len = 0, len(tree)
+    while level_len > 1:
+        level = tree[level_start : level_start + level_len]
+        parents = [
+            poseidon_hash(level[i], level[i + 1]) for i in range(0, level_len, 2)
+        ]
+        level_start += level_len
+        level_len = len(parents)
+        tree.extend(parents)
+    return tree
+
+
+def merkle_proof(tree, n_leaves, leaf_index):
+    proof = []
+    level_start, level_len = 0, n_leaves
+    while level_len > 1:
+        proof.append(tree[level_start + (leaf_index ^ 1)])
+        leaf_index //= 2
+        level_start += level_len
+        level_len //= 2
+    return proof
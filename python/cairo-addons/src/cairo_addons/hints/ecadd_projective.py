@@ -0,0 +1,84 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def bn254_g1_projective_add(ids: VmConsts):
+    from ethereum.crypto.alt_bn128 import ALT_BN128_PRIME as p
+
+    from cairo_addons.hints.ecadd_projective import read_projective
+    from cairo_addons.utils.uint256 import int_to_uint256
+
+    x1, y1, z1 = read_projective(ids.X1, ids.Y1, ids.Z1)
+    x2, y2, z2 = read_projective(ids.X2, ids.Y2, ids.Z2)
+
+    # Jacobian addition (add-2007-bl) and doubling (dbl-2009-l), as in arkworks
+    if z1 == 0:
+        x3, y3, z3 = x2, y2, z2
+    elif z2 == 0:
+        x3, y3, z3 = x1, y1, z1
+    else:
+        z1z1 = z1 * z1 % p
+        z2z2 = z2 * z2 % p
+        u1 = x1 * z2z2 % p
+        u2 = x2 * z1z1 % p
+        s1 = y1 * z2 * z2z2 % p
+        s2 = y2 * z1 * z1z1 % p
+        if u1 == u2 and s1 == s2:
+            a = x1 * x1 % p
+            b = y1 * y1 % p
+            c = b * b % p
+            d = 2 * ((x1 + b) ** 2 - a - c) % p
+            e = 3 * a % p
+            x3 = (e * e - 2 * d) % p
+            y3 = (e * (d - x3) - 8 * c) % p
+            z3 = 2 * y1 * z1 % p
+        else:
+            h = (u2 - u1) % p
+            i = (2 * h) ** 2 % p
+            j = h * i % p
+            r = 2 * (s2 - s1) % p
+            v = u1 * i % p
+            x3 = (r * r - j - 2 * v) % p
+            y3 = (r * (v - x3) - 2 * s1 * j) % p
+            z3 = ((z1 + z2) ** 2 - z1z1 - z2z2) * h % p
+
+    ids.X3.low, ids.X3.high = int_to_uint256(x3)
+    ids.Y3.low, ids.Y3.high = int_to_uint256(y3)
+    ids.Z3.low, ids.Z3.high = int_to_uint256(z3)
+
+
+@register_hint
+def bn254_g1_projective_to_affine(ids: VmConsts):
+    from ethereum.crypto.alt_bn128 import ALT_BN128_PRIME as p
+
+    from cairo_addons.hints.ecadd_projective import read_projective
+    from cairo_addons.utils.uint256 import int_to_uint256
+
+    x, y, z = read_projective(ids.X, ids.Y, ids.Z)
+    if z == 0:
+        x, y = 0, 0
+    else:
+        z_inv = pow(z, -1, p)
+        x = x * z_inv**2 % p
+        y = y * z_inv**3 % p
+
+    ids.x.low, ids.x.high = int_to_uint256(x)
+    ids.y.low, ids.y.high = int_to_uint256(y)
+
+
+def read_projective(x, y, z):
+    from ethereum.crypto.alt_bn128 import ALT_BN128_PRIME as p
+
+    from cairo_addons.utils.uint256 import uint256_to_int
+
+    coordinates = tuple(uint256_to_int(c.low, c.high) for c in (x, y, z))
+    assert all(c < p for c in coordinates), "Coordinate is not a valid field element"
+    x, y, z = coordinates
+    if z != 0:
+        z2 = z * z % p
+        assert (
+            y * y - x * x * x - 3 * z2 * z2 * z2
+        ) % p == 0, "Point is not on the curve"
+    return coordinates
@@ -0,0 +1,23 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def compute_stark_tx_hash(ids: VmConsts, memory: MemoryDict):
+    from starkware.cairo.common.hash_state import compute_hash_on_elements
+
+    calldata = [memory[ids.calldata_ptr + i] for i in range(ids.calldata_len)]
+    ids.tx_hash = compute_hash_on_elements(
+        [
+            int.from_bytes(b"invoke", "big"),
+            1,
+            ids.contract_address,
+            ids.selector,
+            compute_hash_on_elements(calldata),
+            ids.max_fee,
+            ids.chain_id,
+            ids.nonce,
+        ]
+    )
@@ -0,0 +1,30 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def hash_event_content(ids: VmConsts, memory: MemoryDict):
+    from starkware.cairo.common.poseidon_hash import poseidon_hash_many
+
+    keys = [memory[ids.keys_ptr + i] for i in range(ids.keys_len)]
+    data = [memory[ids.data_ptr + i] for i in range(ids.data_len)]
+    ids.keys_hash = poseidon_hash_many(keys)
+    ids.data_hash = poseidon_hash_many(data)
+
+
+@register_hint
+def emit_event_and_track(ids: VmConsts, memory: MemoryDict):
+    # ruff: noqa: F821
+    from starkware.cairo.common.poseidon_hash import poseidon_hash_many
+
+    keys = [memory[ids.keys_ptr + i] for i in range(ids.keys_len)]
+    data = [memory[ids.data_ptr + i] for i in range(ids.data_len)]
+    ids.keys_hash = poseidon_hash_many(keys)
+    ids.data_hash = poseidon_hash_many(data)
+    try:
+        emitted_events
+    except NameError:
+        emitted_events = []
+    emitted_events.append((keys, data))
@@ -0,0 +1,41 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def compute_create_address(ids: VmConsts):
+    from ethereum.cancun.fork_types import Address
+    from ethereum.cancun.utils.address import compute_contract_address
+    from ethereum_types.numeric import Uint
+
+    from cairo_addons.hints.evm import read_address
+
+    sender = read_address(ids.sender, "sender")
+    # Nonces are bounded by 2^64 - 1 since EIP-2681.
+    if ids.nonce >= 2**64:
+        raise ValueError("nonce exceeds 64 bits")
+    address = compute_contract_address(Address(sender), Uint(ids.nonce))
+    ids.address = int.from_bytes(address, "little")
+
+
+@register_hint
+def compute_create2_address(ids: VmConsts):
+    from ethereum.crypto.hash import keccak256
+
+    from cairo_addons.hints.evm import read_address
+
+    preimage = b"\xff" + read_address(ids.sender, "sender")
+    for name in ("salt_low", "salt_high", "init_code_hash_low", "init_code_hash_high"):
+        limb = getattr(ids, name)
+        if limb >= 2**128:
+            raise ValueError(f"{name} exceeds 128 bits")
+        preimage += limb.to_bytes(16, "little")
+    ids.address = int.from_bytes(keccak256(preimage)[12:], "little")
+
+
+def read_address(value: int, name: str) -> bytes:
+    """Returns the 20 bytes of an address encoded as a little-endian felt."""
+    if value >= 2**160:
+        raise ValueError(f"{name} is not a 20-byte address")
+    return value.to_bytes(20, "little")
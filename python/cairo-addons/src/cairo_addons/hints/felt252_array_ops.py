@@ -0,0 +1,37 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def felt_array_add(ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager):
+    from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+
+    result = [
+        (memory[ids.a_ptr + i] + memory[ids.b_ptr + i]) % DEFAULT_PRIME
+        for i in range(ids.len)
+    ]
+    ids.result_ptr = segments.gen_arg(result)
+
+
+@register_hint
+def felt_array_mul(ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager):
+    from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+
+    result = [
+        memory[ids.a_ptr + i] * memory[ids.b_ptr + i] % DEFAULT_PRIME
+        for i in range(ids.len)
+    ]
+    ids.result_ptr = segments.gen_arg(result)
+
+
+@register_hint
+def felt_array_dot(ids: VmConsts, memory: MemoryDict):
+    from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+
+    ids.result = (
+        sum(memory[ids.a_ptr + i] * memory[ids.b_ptr + i] for i in range(ids.len))
+        % DEFAULT_PRIME
+    )
@@ -0,0 +1,37 @@
+from starkware.cairo.common.dict import DictManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def felt252_dict_entry_init(dict_manager: DictManager, ids: VmConsts):
+    # ruff: noqa: F821
+    try:
+        open_entry_key
+    except NameError:
+        open_entry_key = None
+    if open_entry_key is not None:
+        raise ValueError(f"Dict entry {open_entry_key} is already open")
+    dict_tracker = dict_manager.get_tracker(ids.dict_ptr)
+    ids.current_value = dict_tracker.data[ids.key]
+    open_entry_key = ids.key
+
+
+@register_hint
+def felt252_dict_entry_finalize(dict_manager: DictManager, ids: VmConsts):
+    # ruff: noqa: F821
+    try:
+        open_entry_key
+    except NameError:
+        open_entry_key = None
+    if open_entry_key is None:
+        raise ValueError("Dict entry finalized without being initialized")
+    if ids.key != open_entry_key:
+        raise ValueError(
+            f"Dict entry {ids.key} finalized while entry {open_entry_key} is open"
+        )
+    dict_tracker = dict_manager.get_tracker(ids.dict_ptr)
+    dict_tracker.data[ids.key] = ids.new_value
+    dict_tracker.current_ptr += ids.DictAccess.SIZE
+    open_entry_key = None
@@ -0,0 +1,15 @@
+from starkware.cairo.common.dict import DictManager
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def felt252_dict_init_from_scope(
+    dict_manager: DictManager,
+    ids: VmConsts,
+    segments: MemorySegmentManager,
+):
+    # ruff: noqa: F821
+    ids.dict_ptr = dict_manager.new_dict(segments, dict(init_key))
@@ -0,0 +1,49 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def is_felt_negative(ids: VmConsts):
+    from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+
+    ids.is_neg = int(ids.value > DEFAULT_PRIME // 2)
+
+
+@register_hint
+def felt_abs(ids: VmConsts):
+    from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+
+    if ids.value > DEFAULT_PRIME // 2:
+        ids.abs = DEFAULT_PRIME - ids.value
+        ids.sign = 1
+    else:
+        ids.abs = ids.value
+        ids.sign = 0
+
+
+@register_hint
+def felt_min(ids: VmConsts):
+    from cairo_addons.hints.felt_comparison import signed_value
+
+    ids.min = ids.a if signed_value(ids.a) <= signed_value(ids.b) else ids.b
+
+
+@register_hint
+def is_felt_le(ids: VmConsts):
+    from cairo_addons.hints.felt_comparison import signed_value
+
+    ids.is_le = int(signed_value(ids.a) <= signed_value(ids.b))
+
+
+@register_hint
+def is_felt_ge(ids: VmConsts):
+    from cairo_addons.hints.felt_comparison import signed_value
+
+    ids.is_ge = int(signed_value(ids.a) >= signed_value(ids.b))
+
+
+def signed_value(value: int) -> int:
+    from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+
+    return value - DEFAULT_PRIME if value > DEFAULT_PRIME // 2 else value
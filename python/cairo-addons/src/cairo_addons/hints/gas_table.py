@@ -0,0 +1,151 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def evm_opcode_gas_cost(ids: VmConsts):
+    from cairo_addons.hints.gas_table import GAS_TABLE, read_opcode
+
+    ids.base_gas = GAS_TABLE[read_opcode(ids)]
+
+
+@register_hint
+def evm_opcode_is_static_gas(ids: VmConsts):
+    from cairo_addons.hints.gas_table import is_static_gas, read_opcode
+
+    ids.is_static_gas = int(is_static_gas(read_opcode(ids)))
+
+
+GAS_JUMPDEST = 1
+GAS_BASE = 2
+GAS_VERY_LOW = 3
+GAS_LOW = 5
+GAS_MID = 8
+GAS_HIGH = 10
+GAS_EXPONENTIATION = 10
+GAS_BLOCK_HASH = 20
+GAS_KECCAK256 = 30
+GAS_WARM_ACCESS = 100
+GAS_LOG = 375
+GAS_LOG_TOPIC = 375
+GAS_SELF_DESTRUCT = 5000
+GAS_CREATE = 32000
+
+
+def build_gas_table():
+    table = [0] * 256
+
+    # 0x00 - 0x0B: stop and arithmetic operations
+    table[0x01] = GAS_VERY_LOW  # ADD
+    table[0x02] = GAS_LOW  # MUL
+    table[0x03] = GAS_VERY_LOW  # SUB
+    table[0x04] = GAS_LOW  # DIV
+    table[0x05] = GAS_LOW  # SDIV
+    table[0x06] = GAS_LOW  # MOD
+    table[0x07] = GAS_LOW  # SMOD
+    table[0x08] = GAS_MID  # ADDMOD
+    table[0x09] = GAS_MID  # MULMOD
+    table[0x0A] = GAS_EXPONENTIATION  # EXP
+    table[0x0B] = GAS_LOW  # SIGNEXTEND
+
+    # 0x10 - 0x1D: comparison and bitwise operations
+    for opcode in range(0x10, 0x1E):
+        table[opcode] = GAS_VERY_LOW
+
+    table[0x20] = GAS_KECCAK256  # KECCAK256
+
+    # 0x30 - 0x3F: environmental information
+    table[0x30] = GAS_BASE  # ADDRESS
+    table[0x31] = GAS_WARM_ACCESS  # BALANCE
+    table[0x32] = GAS_BASE  # ORIGIN
+    table[0x33] = GAS_BASE  # CALLER
+    table[0x34] = GAS_BASE  # CALLVALUE
+    table[0x35] = GAS_VERY_LOW  # CALLDATALOAD
+    table[0x36] = GAS_BASE  # CALLDATASIZE
+    table[0x37] = GAS_VERY_LOW  # CALLDATACOPY
+    table[0x38] = GAS_BASE  # CODESIZE
+    table[0x39] = GAS_VERY_LOW  # CODECOPY
+    table[0x3A] = GAS_BASE  # GASPRICE
+    table[0x3B] = GAS_WARM_ACCESS  # EXTCODESIZE
+    table[0x3C] = GAS_WARM_ACCESS  # EXTCODECOPY
+    table[0x3D] = GAS_BASE  # RETURNDATASIZE
+    table[0x3E] = GAS_VERY_LOW  # RETURNDATACOPY
+    table[0x3F] = GAS_WARM_ACCESS  # EXTCODEHASH
+
+    # 0x40 - 0x4A: block information
+    table[0x40] = GAS_BLOCK_HASH  # BLOCKHASH
+    table[0x41] = GAS_BASE  # COINBASE
+    table[0x42] = GAS_BASE  # TIMESTAMP
+    table[0x43] = GAS_BASE  # NUMBER
+    table[0x44] = GAS_BASE  # PREVRANDAO
+    table[0x45] = GAS_BASE  # GASLIMIT
+    table[0x46] = GAS_BASE  # CHAINID
+    table[0x47] = GAS_LOW  # SELFBALANCE
+    table[0x48] = GAS_BASE  # BASEFEE
+    table[0x49] = GAS_VERY_LOW  # BLOBHASH
+    table[0x4A] = GAS_BASE  # BLOBBASEFEE
+
+    # 0x50 - 0x5F: stack, memory, storage and flow operations
+    table[0x50] = GAS_BASE  # POP
+    table[0x51] = GAS_VERY_LOW  # MLOAD
+    table[0x52] = GAS_VERY_LOW  # MSTORE
+    table[0x53] = GAS_VERY_LOW  # MSTORE8
+    table[0x54] = GAS_WARM_ACCESS  # SLOAD
+    table[0x55] = GAS_WARM_ACCESS  # SSTORE
+    table[0x56] = GAS_MID  # JUMP
+    table[0x57] = GAS_HIGH  # JUMPI
+    table[0x58] = GAS_BASE  # PC
+    table[0x59] = GAS_BASE  # MSIZE
+    table[0x5A] = GAS_BASE  # GAS
+    table[0x5B] = GAS_JUMPDEST  # JUMPDEST
+    table[0x5C] = GAS_WARM_ACCESS  # TLOAD
+    table[0x5D] = GAS_WARM_ACCESS  # TSTORE
+    table[0x5E] = GAS_VERY_LOW  # MCOPY
+    table[0x5F] = GAS_BASE  # PUSH0
+
+    # 0x60 - 0x9F: PUSH1 - PUSH32, DUP1 - DUP16 and SWAP1 - SWAP16
+    for opcode in range(0x60, 0xA0):
+        table[opcode] = GAS_VERY_LOW
+
+    # 0xA0 - 0xA4: LOG0 - LOG4
+    for topics in range(5):
+        table[0xA0 + topics] = GAS_LOG + GAS_LOG_TOPIC * topics
+
+    # 0xF0 - 0xFF: system operations
+    table[0xF0] = GAS_CREATE  # CREATE
+    table[0xF1] = GAS_WARM_ACCESS  # CALL
+    table[0xF2] = GAS_WARM_ACCESS  # CALLCODE
+    table[0xF4] = GAS_WARM_ACCESS  # DELEGATECALL
+    table[0xF5] = GAS_CREATE  # CREATE2
+    table[0xFA] = GAS_WARM_ACCESS  # STATICCALL
+    table[0xFF] = GAS_SELF_DESTRUCT  # SELFDESTRUCT
+
+    return table
+
+
+GAS_TABLE = build_gas_table()
+
+DYNAMIC_GAS_OPCODES = {
+    *(0x0A, 0x20),
+    *(0x31, 0x37, 0x39, 0x3B, 0x3C, 0x3E, 0x3F),
+    *range(0x51, 0x56),
+    0x5E,
+    *range(0xA0, 0xA5),
+    *range(0xF0, 0xF6),
+    *(0xFA, 0xFD, 0xFF),
+}
+
+STATIC_GAS_RANGES = [(0x00, 0x0B), (0x10, 0x1D), (0x30, 0x4A), (0x50, 0x9F)]
+
+
+def read_opcode(ids: VmConsts) -> int:
+    if not 0 <= ids.opcode <= 255:
+        raise ValueError(f"Invalid opcode: {ids.opcode}")
+    return ids.opcode
+
+
+def is_static_gas(opcode: int) -> bool:
+    if opcode in DYNAMIC_GAS_OPCODES:
+        return False
+    return any(start <= opcode <= end for start, end in STATIC_GAS_RANGES)
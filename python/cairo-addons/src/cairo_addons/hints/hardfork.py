@@ -0,0 +1,28 @@
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def get_precompile_addresses(ids: VmConsts, segments: MemorySegmentManager):
+    from importlib import import_module
+
+    from cairo_addons.hints.hardfork import HARDFORKS
+
+    if ids.hardfork not in HARDFORKS:
+        raise ValueError(f"Unknown hardfork: {ids.hardfork}")
+    mapping = import_module(
+        f"ethereum.{HARDFORKS[ids.hardfork]}.vm.precompiled_contracts.mapping"
+    )
+    addresses = [
+        int.from_bytes(address, "little")
+        for address in sorted(mapping.PRE_COMPILED_CONTRACTS)
+    ]
+
+    ids.precompiles = segments.gen_arg(addresses)
+    ids.precompiles_len = len(addresses)
+
+
+# The hardforks by their integer value, matching the `Hardfork` enum of the Rust VM.
+HARDFORKS = {0: "shanghai", 1: "cancun", 2: "prague"}
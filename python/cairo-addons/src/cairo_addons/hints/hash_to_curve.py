@@ -0,0 +1,31 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def bls12_381_hash_to_g2(
+    ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager
+):
+    from hashlib import sha256
+
+    from py_ecc.bls.hash_to_curve import hash_to_G2
+    from py_ecc.optimized_bls12_381 import normalize
+
+    from cairo_addons.utils.uint384 import int_to_uint384
+
+    message = bytes(
+        [memory[ids.message_ptr + i] for i in range(ids.message_len)]
+    )
+    dst = bytes([memory[ids.dst_ptr + i] for i in range(ids.dst_len)])
+
+    x, y = normalize(hash_to_G2(message, dst, sha256))
+    # G2Point coordinates are stored as x = a0 * i + b0, y = a1 * i + b1
+    limbs = [
+        limb
+        for coeff in (x.coeffs[1], x.coeffs[0], y.coeffs[1], y.coeffs[0])
+        for limb in int_to_uint384(int(coeff))
+    ]
+    segments.write_arg(ids.result.address_, limbs)
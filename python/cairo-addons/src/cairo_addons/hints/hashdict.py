@@ -58,6 +58,47 @@ def hashdict_write(dict_manager: DictManager, ids: VmConsts, memory: MemoryDict)
     dict_manager.preimages[hashed_key] = preimage
 
 
+@register_hint
+def hashdict_delete(dict_manager: DictManager, ids: VmConsts, memory: MemoryDict):
+    from starkware.cairo.lang.vm.crypto import poseidon_hash_many
+
+    dict_tracker = dict_manager.get_tracker(ids.dict_ptr)
+    preimage = tuple([memory[ids.key + i] for i in range(ids.key_len)])
+    hashed_key = poseidon_hash_many(preimage) if len(preimage) != 1 else preimage[0]
+    dict_manager.preimages[hashed_key] = preimage
+
+    if preimage not in dict_tracker.data:
+        raise ValueError(f"No value found for preimage {preimage}")
+    del dict_tracker.data[preimage]
+    dict_tracker.current_ptr += ids.DictAccess.SIZE
+
+
+@register_hint
+def hashdict_contains(dict_manager: DictManager, ids: VmConsts, memory: MemoryDict):
+    from starkware.cairo.lang.vm.crypto import poseidon_hash_many
+
+    dict_tracker = dict_manager.get_tracker(ids.dict_ptr)
+    preimage = tuple([memory[ids.key + i] for i in range(ids.key_len)])
+    hashed_key = poseidon_hash_many(preimage) if len(preimage) != 1 else preimage[0]
+    dict_manager.preimages[hashed_key] = preimage
+
+    ids.result = int(preimage in dict_tracker.data)
+
+
+@register_hint
+def hashdict_merge(dict_manager: DictManager, ids: VmConsts):
+    src_tracker = dict_manager.get_tracker(ids.src_dict_ptr)
+    dst_tracker = dict_manager.get_tracker(ids.dst_dict_ptr)
+    entries = dict(src_tracker.data)
+    dst_tracker.current_ptr += len(entries) * ids.DictAccess.SIZE
+    dst_tracker.data.update(entries)
+
+
+@register_hint
+def hashdict_len(dict_manager: DictManager, ids: VmConsts):
+    ids.len = len(dict_manager.get_tracker(ids.dict_ptr).data)
+
+
 @register_hint
 def get_keys_for_address_prefix(
     dict_manager: DictManager,
@@ -65,11 +106,15 @@ def get_keys_for_address_prefix(
     segments: MemorySegmentManager,
     memory: MemoryDict,
 ):
+    from cairo_addons.hints.hashdict import _is_sorted
+
     dict_tracker = dict_manager.get_tracker(ids.dict_ptr)
     prefix = tuple([memory[ids.prefix + i] for i in range(ids.prefix_len)])
     matching_preimages = [
         key for key in dict_tracker.data.keys() if key[: len(prefix)] == prefix
     ]
+    if _is_sorted(ids):
+        matching_preimages.sort()
     base = segments.add()
     for i, preimage in enumerate(matching_preimages):
         ptr = segments.add()
@@ -125,6 +170,23 @@ def copy_hashdict_tracker_entry(dict_manager: DictManager, ids: VmConsts):
     dict_tracker.data[preimage] = obj_tracker.data[preimage]
 
 
+@register_hint
+def copy_hashdict_tracker_range(
+    dict_manager: DictManager, ids: VmConsts, memory: MemoryDict
+):
+    source_tracker = dict_manager.get_tracker(ids.source_ptr_stop)
+    prefix = tuple([memory[ids.prefix_ptr + i] for i in range(ids.prefix_len)])
+    entries = {
+        key: value
+        for key, value in source_tracker.data.items()
+        if isinstance(key, tuple) and key[: len(prefix)] == prefix
+    }
+    dest_tracker = dict_manager.get_tracker(ids.dest_ptr)
+    dest_tracker.current_ptr += len(entries) * ids.DictAccess.SIZE
+    dest_tracker.data.update(entries)
+    ids.count = len(entries)
+
+
 def _get_preimage_for_hashed_key(
     hashed_key: int,
     dict_manager: DictManager,
@@ -133,3 +195,13 @@ def _get_preimage_for_hashed_key(
         raise Exception("No preimage found for hashed key")
     preimage = dict_manager.preimages[hashed_key]
     return preimage
+
+
+def _is_sorted(ids: VmConsts) -> bool:
+    """Whether the optional `ids.sorted` flag is set."""
+    from starkware.cairo.lang.compiler.identifier_manager import IdentifierError
+
+    try:
+        return ids.sorted == 1
+    except (AttributeError, IdentifierError):
+        return False
@@ -0,0 +1,16 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def compute_keccak256(ids: VmConsts, memory: MemoryDict):
+    from ethereum.crypto.hash import keccak256
+
+    data = [memory[ids.data_ptr + i] for i in range(ids.data_len)]
+    if any(not 0 <= byte <= 255 for byte in data):
+        raise ValueError("Value is not a byte")
+    hash_value = int.from_bytes(keccak256(bytes(data)), "big")
+    ids.low = hash_value % 2**128
+    ids.high = hash_value >> 128
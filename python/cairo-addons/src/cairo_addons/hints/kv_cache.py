@@ -0,0 +1,59 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def kv_cache_put(ids: VmConsts, memory: MemoryDict):
+    # ruff: noqa: F821
+    from collections import OrderedDict
+
+    try:
+        kv_cache
+    except NameError:
+        kv_cache = {"entries": OrderedDict(), "hits": 0, "misses": 0}
+    value = [memory[ids.value_ptr + i] for i in range(ids.value_len)]
+    if any(not 0 <= byte <= 255 for byte in value):
+        raise ValueError("Value is not a byte")
+    kv_cache["entries"][ids.key] = bytes(value)
+    kv_cache["entries"].move_to_end(ids.key)
+    # Matches KV_CACHE_SIZE of the Rust hint.
+    if len(kv_cache["entries"]) > 1024:
+        kv_cache["entries"].popitem(last=False)
+
+
+@register_hint
+def kv_cache_get(ids: VmConsts, segments: MemorySegmentManager):
+    # ruff: noqa: F821
+    from collections import OrderedDict
+
+    try:
+        kv_cache
+    except NameError:
+        kv_cache = {"entries": OrderedDict(), "hits": 0, "misses": 0}
+    value = kv_cache["entries"].get(ids.key)
+    if value is None:
+        kv_cache["misses"] += 1
+        ids.found = 0
+        value = b""
+    else:
+        kv_cache["hits"] += 1
+        kv_cache["entries"].move_to_end(ids.key)
+        ids.found = 1
+    ids.value_ptr = segments.gen_arg(list(value))
+    ids.value_len = len(value)
+
+
+@register_hint
+def kv_cache_stats(ids: VmConsts):
+    # ruff: noqa: F821
+    from collections import OrderedDict
+
+    try:
+        kv_cache
+    except NameError:
+        kv_cache = {"entries": OrderedDict(), "hits": 0, "misses": 0}
+    ids.hits = kv_cache["hits"]
+    ids.misses = kv_cache["misses"]
@@ -0,0 +1,31 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def compute_l1_handler_hash(ids: VmConsts, memory: MemoryDict):
+    from starkware.cairo.common.hash_state import compute_hash_on_elements
+
+    payload = [memory[ids.payload_ptr + i] for i in range(ids.payload_len)]
+    ids.message_hash = compute_hash_on_elements(
+        [
+            int.from_bytes(b"l1_handler", "big"),
+            0,
+            ids.to_address,
+            ids.selector,
+            compute_hash_on_elements([ids.from_address, *payload]),
+            0,
+            ids.chain_id,
+            ids.nonce,
+        ]
+    )
+
+
+@register_hint
+def verify_l1_handler_nonce(ids: VmConsts):
+    if ids.nonce != ids.expected_nonce:
+        raise ValueError(
+            f"Invalid L1 handler nonce: expected {ids.expected_nonce}, got {ids.nonce}"
+        )
@@ -0,0 +1,18 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def compare_segments(ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager):
+    diff = []
+    for offset in range(ids.seg_size):
+        a_value = memory.get(ids.seg_a_base + offset)
+        b_value = memory.get(ids.seg_b_base + offset)
+        if a_value == b_value:
+            continue
+        diff.append((offset, a_value or 0, b_value or 0))
+    ids.diff_ptr = segments.gen_arg([item for triple in diff for item in triple])
+    ids.diff_len = len(diff)
@@ -0,0 +1,207 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def compute_mpt_node_hash(ids: VmConsts, memory: MemoryDict):
+    from ethereum.crypto.hash import keccak256
+
+    from cairo_addons.hints.mpt import validate_node
+
+    node = [memory[ids.node_ptr + i] for i in range(ids.node_len)]
+    if any(not 0 <= byte <= 255 for byte in node):
+        raise ValueError("Value is not a byte")
+    validate_node(bytes(node))
+    hash_value = int.from_bytes(keccak256(bytes(node)), "big")
+    ids.hash_low = hash_value % 2**128
+    ids.hash_high = hash_value >> 128
+
+
+@register_hint
+def verify_mpt_inclusion(ids: VmConsts, memory: MemoryDict):
+    from cairo_addons.hints.mpt import verify_inclusion
+
+    root = ids.root_high.to_bytes(16, "big") + ids.root_low.to_bytes(16, "big")
+    key = [memory[ids.key_ptr + i] for i in range(ids.key_len)]
+    value = [memory[ids.value_ptr + i] for i in range(ids.value_len)]
+    proof = []
+    node_ptr = ids.proof_ptr
+    for _ in range(ids.proof_len):
+        node_len = memory[node_ptr]
+        proof.append([memory[node_ptr + 1 + i] for i in range(node_len)])
+        node_ptr += node_len + 1
+    if any(not 0 <= byte <= 255 for data in (key, value, *proof) for byte in data):
+        raise ValueError("Value is not a byte")
+
+    proof = [bytes(node) for node in proof]
+    error_code = verify_inclusion(root, bytes(key), bytes(value), proof)
+    ids.valid = int(error_code == 0)
+    ids.error_code = error_code
+
+
+# Error codes of `verify_mpt_inclusion`, as the `MptProofError` of the Rust hint.
+INVALID_NODE = 1
+NODE_HASH_MISMATCH = 2
+KEY_NOT_FOUND = 3
+VALUE_MISMATCH = 4
+INCOMPLETE_PROOF = 5
+UNUSED_PROOF_NODES = 6
+
+
+class MptProofError(Exception):
+    def __init__(self, code: int):
+        super().__init__(code)
+        self.code = code
+
+
+def verify_inclusion(root: bytes, key: bytes, value: bytes, proof: list) -> int:
+    """
+    Returns 0 if `proof` proves that the trie of root `root` maps `key` to `value`,
+    and the error code of the first failed check otherwise.
+    """
+    try:
+        walk_proof(root, key, value, proof)
+    except MptProofError as e:
+        return e.code
+    return 0
+
+
+def walk_proof(root: bytes, key: bytes, value: bytes, proof: list):
+    """
+    Walks the proof from `root` along the nibbles of `key`, checking each node against
+    the reference held by its parent, until reaching the value of the key.
+    """
+    from ethereum.crypto.hash import keccak256
+
+    key = bytes_to_nibbles(key)
+    position = 0
+    nodes = iter(proof)
+    is_hash, reference = True, root
+    while True:
+        if is_hash:
+            node = next(nodes, None)
+            if node is None:
+                raise MptProofError(INCOMPLETE_PROOF)
+            if keccak256(node) != reference:
+                raise MptProofError(NODE_HASH_MISMATCH)
+        else:
+            node = reference
+        try:
+            items = decode_node_items(node)
+        except ValueError:
+            raise MptProofError(INVALID_NODE) from None
+
+        if len(items) == 2:
+            (path_is_list, path, _), child = items
+            decoded = None if path_is_list else compact_to_nibble_list(path)
+            if decoded is None:
+                raise MptProofError(INVALID_NODE)
+            path, is_leaf = decoded
+            if key[position : position + len(path)] != path:
+                raise MptProofError(KEY_NOT_FOUND)
+            position += len(path)
+            if not is_leaf:
+                child = child_reference(child)
+                if child is None:
+                    raise MptProofError(INVALID_NODE)
+                is_hash, reference = child
+                continue
+            if position != len(key):
+                raise MptProofError(KEY_NOT_FOUND)
+            leaf_value = child
+        elif len(items) == 17:
+            if position != len(key):
+                child = child_reference(items[key[position]])
+                if child is None:
+                    raise MptProofError(KEY_NOT_FOUND)
+                is_hash, reference = child
+                position += 1
+                continue
+            leaf_value = items[16]
+        else:
+            raise MptProofError(INVALID_NODE)
+
+        is_list, payload, _ = leaf_value
+        if is_list:
+            raise MptProofError(INVALID_NODE)
+        if payload != value:
+            raise MptProofError(VALUE_MISMATCH)
+        if next(nodes, None) is not None:
+            raise MptProofError(UNUSED_PROOF_NODES)
+        return
+
+
+def child_reference(item):
+    """
+    Returns the reference to the child node held by `item` as `(is_hash, reference)`,
+    or None for an empty child.
+    """
+    is_list, payload, encoding = item
+    if is_list:
+        return False, encoding
+    if len(payload) == 0:
+        return None
+    if len(payload) == 32:
+        return True, payload
+    raise MptProofError(INVALID_NODE)
+
+
+def bytes_to_nibbles(data: bytes):
+    return [nibble for byte in data for nibble in (byte >> 4, byte & 0x0F)]
+
+
+def compact_to_nibble_list(compact: bytes):
+    """
+    Decodes a compact (hex-prefix) encoded path into its nibbles and whether it is a
+    leaf path, or returns None if the prefix is invalid.
+    """
+    if len(compact) == 0:
+        return None
+    flag, first_nibble = compact[0] >> 4, compact[0] & 0x0F
+    if flag in (0, 2) and first_nibble == 0:
+        nibbles = []
+    elif flag in (1, 3):
+        nibbles = [first_nibble]
+    else:
+        return None
+    nibbles += bytes_to_nibbles(compact[1:])
+    return nibbles, flag >= 2
+
+
+def validate_node(node: bytes):
+    """
+    Checks that `node` is the RLP encoding of a leaf, extension or branch node, like
+    the Rust hint.
+    """
+    items = decode_node_items(node)
+    if len(items) == 2:
+        # The high nibble of the first byte of a compact path is 0 or 1 for extension
+        # nodes, 2 or 3 for leaf nodes.
+        is_list, path, _ = items[0]
+        if is_list or len(path) == 0 or path[0] >> 4 > 3:
+            raise ValueError("Invalid MPT node: invalid path")
+    elif len(items) != 17:
+        raise ValueError(f"Invalid MPT node: expected 2 or 17 items, got {len(items)}")
+
+
+def decode_node_items(node: bytes):
+    """
+    Returns the `(is_list, payload, encoding)` items of `node`, which must be a single
+    RLP list.
+    """
+    from cairo_addons.hints.rlp import decode_header
+
+    is_list, payload_start, payload_length = decode_header(node)
+    if not is_list or payload_start + payload_length != len(node):
+        raise ValueError("Invalid MPT node: not a single RLP list")
+
+    items = []
+    payload = node[payload_start:]
+    while payload:
+        item_is_list, item_start, item_length = decode_header(payload)
+        end = item_start + item_length
+        items.append((item_is_list, payload[item_start:end], payload[:end]))
+        payload = payload[end:]
+    return items
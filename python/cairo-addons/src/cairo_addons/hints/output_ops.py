@@ -0,0 +1,49 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def output_write_felt(ids: VmConsts, segments: MemorySegmentManager):
+    # ruff: noqa: F821
+    try:
+        output_ptr
+    except NameError:
+        output_ptr = ids.output_ptr
+    output_ptr = segments.write_arg(output_ptr, [ids.value])
+
+
+@register_hint
+def output_write_u256(ids: VmConsts, segments: MemorySegmentManager):
+    # ruff: noqa: F821
+    try:
+        output_ptr
+    except NameError:
+        output_ptr = ids.output_ptr
+    output_ptr = segments.write_arg(output_ptr, [ids.low, ids.high])
+
+
+@register_hint
+def output_write_array(
+    ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager
+):
+    # ruff: noqa: F821
+    try:
+        output_ptr
+    except NameError:
+        output_ptr = ids.output_ptr
+    output_ptr = segments.write_arg(
+        output_ptr, [memory[ids.ptr + i] for i in range(ids.len)]
+    )
+
+
+@register_hint
+def output_write_bytes32(ids: VmConsts, segments: MemorySegmentManager):
+    # ruff: noqa: F821
+    try:
+        output_ptr
+    except NameError:
+        output_ptr = ids.output_ptr
+    output_ptr = segments.write_arg(output_ptr, [ids.lo, ids.hi])
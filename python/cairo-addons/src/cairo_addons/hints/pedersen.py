@@ -0,0 +1,10 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def pedersen_hash_pair(ids: VmConsts):
+    from starkware.crypto.signature.fast_pedersen_hash import pedersen_hash
+
+    ids.hash = pedersen_hash(ids.x, ids.y)
@@ -0,0 +1,77 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def is_point_on_stark_curve(ids: VmConsts):
+    from cairo_addons.hints.point_on_curve import is_on_stark_curve
+
+    ids.on_curve = int(is_on_stark_curve(ids.x, ids.y))
+
+
+@register_hint
+def assert_point_on_stark_curve(ids: VmConsts):
+    from cairo_addons.hints.point_on_curve import is_on_stark_curve
+
+    if not is_on_stark_curve(ids.x, ids.y):
+        raise ValueError("Point not on curve")
+    ids.on_curve = 1
+
+
+@register_hint
+def is_point_on_bn254(ids: VmConsts):
+    from cairo_addons.hints.point_on_curve import is_on_bn254
+
+    ids.on_curve = int(is_on_bn254(ids.x, ids.y))
+
+
+@register_hint
+def assert_point_on_bn254(ids: VmConsts):
+    from cairo_addons.hints.point_on_curve import is_on_bn254
+
+    if not is_on_bn254(ids.x, ids.y):
+        raise ValueError("Point not on curve")
+    ids.on_curve = 1
+
+
+@register_hint
+def is_point_on_bls12_381_g1(ids: VmConsts):
+    from cairo_addons.hints.point_on_curve import is_on_bls12_381_g1
+
+    ids.on_curve = int(is_on_bls12_381_g1(ids.x, ids.y))
+
+
+@register_hint
+def assert_point_on_bls12_381_g1(ids: VmConsts):
+    from cairo_addons.hints.point_on_curve import is_on_bls12_381_g1
+
+    if not is_on_bls12_381_g1(ids.x, ids.y):
+        raise ValueError("Point not on curve")
+    ids.on_curve = 1
+
+
+def is_on_stark_curve(x, y) -> bool:
+    from starkware.crypto.signature.signature import ALPHA, BETA, FIELD_PRIME
+
+    return (y * y - x * x * x - ALPHA * x - BETA) % FIELD_PRIME == 0
+
+
+def is_on_bn254(x, y) -> bool:
+    from ethereum.crypto.alt_bn128 import ALT_BN128_PRIME as p
+
+    from cairo_addons.utils.uint256 import uint256_to_int
+
+    x = uint256_to_int(x.low, x.high)
+    y = uint256_to_int(y.low, y.high)
+    return x < p and y < p and (y * y - x * x * x - 3) % p == 0
+
+
+def is_on_bls12_381_g1(x, y) -> bool:
+    from py_ecc.optimized_bls12_381 import field_modulus as p
+
+    from cairo_addons.utils.uint384 import uint384_to_int
+
+    x = uint384_to_int(x.d0, x.d1, x.d2, x.d3)
+    y = uint384_to_int(y.d0, y.d1, y.d2, y.d3)
+    return x < p and y < p and (y * y - x * x * x - 4) % p == 0
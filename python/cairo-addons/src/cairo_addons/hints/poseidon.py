@@ -0,0 +1,12 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def poseidon_hash_range(ids: VmConsts, memory: MemoryDict):
+    from starkware.cairo.common.poseidon_hash import poseidon_hash_many
+
+    data = [memory[ids.data_ptr + i] for i in range(ids.data_len)]
+    ids.result = poseidon_hash_many(data)
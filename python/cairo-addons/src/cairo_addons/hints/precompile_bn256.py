@@ -0,0 +1,50 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def bn256_scalar_mul_precompile(
+    ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager
+):
+    from ethereum.cancun.vm.exceptions import OutOfGasError
+    from ethereum.crypto.alt_bn128 import ALT_BN128_PRIME, BNF, BNP
+
+    def inner():
+        data = bytes(
+            [memory[ids.data.value.data + i] for i in range(ids.data.value.len)]
+        )
+        data = data[:96].ljust(96, b"\x00")
+        x = int.from_bytes(data[:32], "big")
+        y = int.from_bytes(data[32:64], "big")
+        n = int.from_bytes(data[64:96], "big")
+
+        def write_error():
+            error_int = int.from_bytes(OutOfGasError.__name__.encode("ascii"), "big")
+            error_ptr = segments.add()
+            segments.write_arg(error_ptr, [error_int])
+            ids.error = error_ptr
+
+        if x >= ALT_BN128_PRIME or y >= ALT_BN128_PRIME:
+            write_error()
+            return
+
+        try:
+            point = BNP(BNF(x), BNF(y))
+        except ValueError:
+            write_error()
+            return
+
+        result = point.mul_by(n)
+        output = result.x.to_be_bytes32() + result.y.to_be_bytes32()
+
+        ids.error = 0
+        data_ptr = segments.add()
+        segments.write_arg(data_ptr, output)
+        bytes_ptr = segments.add()
+        segments.write_arg(bytes_ptr, [data_ptr, len(output)])
+        ids.output.value = bytes_ptr
+
+    inner()
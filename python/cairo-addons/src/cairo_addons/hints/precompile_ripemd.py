@@ -0,0 +1,17 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def ripemd160_precompile(
+    ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager
+):
+    import hashlib
+
+    data = bytes([memory[ids.input_ptr + i] for i in range(ids.input_len)])
+    output = hashlib.new("ripemd160", data).digest().rjust(32, b"\x00")
+    ids.output_ptr = segments.gen_arg(list(output))
+    ids.gas_cost = 600 + 120 * ((ids.input_len + 31) // 32)
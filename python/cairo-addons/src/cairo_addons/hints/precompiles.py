@@ -304,3 +304,109 @@ def point_evaluation_hint(
 @register_hint
 def bit_length_hint(ids: VmConsts, memory: MemoryDict, ap: RelocatableValue):
     memory[ap - 1] = ids.value.bit_length()
+
+
+@register_hint
+def ecrecover(ids: VmConsts):
+    from ethereum.crypto.elliptic_curve import SECP256K1N, secp256k1_recover
+    from ethereum.crypto.hash import keccak256
+    from ethereum.exceptions import InvalidSignatureError
+    from ethereum_types.bytes import Bytes32
+    from ethereum_types.numeric import U256
+
+    from cairo_addons.utils.uint256 import uint256_to_int
+
+    message_hash = uint256_to_int(ids.hash.value.low, ids.hash.value.high)
+    v = uint256_to_int(ids.v.value.low, ids.v.value.high)
+    r = uint256_to_int(ids.r.value.low, ids.r.value.high)
+    s = uint256_to_int(ids.s.value.low, ids.s.value.high)
+
+    # Adapted execution specs, the precompile returns no output for invalid signatures.
+    ids.address = 0
+    ids.success = 0
+    if v not in (27, 28) or not 0 < r < SECP256K1N or not 0 < s < SECP256K1N:
+        return
+    try:
+        public_key = secp256k1_recover(
+            U256(r), U256(s), U256(v - 27), Bytes32(message_hash.to_bytes(32, "big"))
+        )
+    except InvalidSignatureError:
+        return
+    ids.address = int.from_bytes(keccak256(public_key)[12:32], "little")
+    ids.success = 1
+
+
+@register_hint
+def modexp(ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager):
+    from cairo_addons.hints.precompiles import read_bytes
+
+    base = int.from_bytes(read_bytes(memory, ids.base_ptr, ids.base_len), "big")
+    exp = int.from_bytes(read_bytes(memory, ids.exp_ptr, ids.exp_len), "big")
+    modulus = int.from_bytes(read_bytes(memory, ids.mod_ptr, ids.mod_len), "big")
+
+    result = pow(base, exp, modulus) if modulus != 0 else 0
+    ids.result_ptr = segments.gen_arg(list(result.to_bytes(ids.mod_len, "big")))
+    ids.result_len = ids.mod_len
+
+
+def read_bytes(memory: MemoryDict, ptr, length: int) -> bytes:
+    data = [memory[ptr + i] for i in range(length)]
+    if any(not 0 <= byte <= 255 for byte in data):
+        raise ValueError("Value is not a byte")
+    return bytes(data)
+
+
+@register_hint
+def bn254_ec_add(ids: VmConsts):
+    from cairo_addons.hints.precompiles import read_bn254_point, write_bn254_point
+
+    p1 = read_bn254_point(ids.x1, ids.y1, "x1", "y1")
+    p2 = read_bn254_point(ids.x2, ids.y2, "x2", "y2")
+    write_bn254_point(p1 + p2, ids.rx, ids.ry)
+
+
+@register_hint
+def bn254_ec_mul(ids: VmConsts):
+    from cairo_addons.hints.precompiles import read_bn254_point, write_bn254_point
+    from cairo_addons.utils.uint256 import uint256_to_int
+
+    point = read_bn254_point(ids.x, ids.y, "x", "y")
+    scalar = uint256_to_int(ids.s.low, ids.s.high)
+    write_bn254_point(point.mul_by(scalar), ids.rx, ids.ry)
+
+
+@register_hint
+def sha256_precompile(
+    ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager
+):
+    import hashlib
+
+    from cairo_addons.hints.precompiles import read_bytes
+
+    data = read_bytes(memory, ids.data_ptr, ids.data_len)
+    ids.output_ptr = segments.gen_arg(list(hashlib.sha256(data).digest()))
+
+
+def read_bn254_point(x, y, x_name: str, y_name: str):
+    from ethereum.crypto.alt_bn128 import ALT_BN128_PRIME, BNF, BNP
+
+    from cairo_addons.utils.uint256 import uint256_to_int
+
+    coordinates = []
+    for value, name in ((x, x_name), (y, y_name)):
+        coordinate = uint256_to_int(value.low, value.high)
+        if coordinate >= ALT_BN128_PRIME:
+            raise ValueError(f"{name} is not a valid field element")
+        coordinates.append(coordinate)
+    try:
+        return BNP(BNF(coordinates[0]), BNF(coordinates[1]))
+    except ValueError:
+        raise ValueError(f"({x_name}, {y_name}) is not on the curve")
+
+
+def write_bn254_point(point, x, y):
+    from cairo_addons.utils.uint256 import int_to_uint256
+
+    # The point at infinity is represented as (0, 0).
+    x.low, x.high = int_to_uint256(int(point.x))
+    y.low, y.high = int_to_uint256(int(point.y))
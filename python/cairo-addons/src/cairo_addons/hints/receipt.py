@@ -0,0 +1,88 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.relocatable import RelocatableValue
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def rlp_encode_receipt(
+    ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager
+):
+    from ethereum_rlp import rlp
+    from ethereum_types.numeric import Uint
+
+    from cairo_addons.hints.receipt import read_bytes, read_bytes32, write_bytes
+
+    bloom = bytes([memory[ids.bloom + i] for i in range(256)])
+    logs = []
+    for i in range(ids.logs_len):
+        log_ptr = memory[ids.logs + i]
+        address = memory[log_ptr].to_bytes(32, "little")[:20]
+        topics_ptr = memory[log_ptr + 1]
+        topics = tuple(
+            read_bytes32(memory, memory[memory[topics_ptr] + j])
+            for j in range(memory[topics_ptr + 1])
+        )
+        data = read_bytes(memory, memory[log_ptr + 2])
+        logs.append((address, topics, data))
+
+    encoded_receipt = rlp.encode(
+        (ids.status != 0, Uint(ids.cumulative_gas_used), bloom, tuple(logs))
+    )
+    ids.encoded_receipt.value = write_bytes(segments, encoded_receipt)
+
+
+@register_hint
+def compute_receipts_root(
+    ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager
+):
+    from ethereum.cancun.trie import (
+        bytes_to_nibble_list,
+        encode_internal_node,
+        patricialize,
+    )
+    from ethereum.crypto.hash import keccak256
+    from ethereum_rlp import rlp
+    from ethereum_types.numeric import Uint
+
+    from cairo_addons.hints.receipt import read_bytes
+
+    prepared = {
+        bytes_to_nibble_list(rlp.encode(Uint(i))): read_bytes(
+            memory, memory[ids.receipts + i]
+        )
+        for i in range(ids.receipts_len)
+    }
+    root_node = encode_internal_node(patricialize(prepared, Uint(0)))
+    if len(rlp.encode(root_node)) < 32:
+        root = keccak256(rlp.encode(root_node))
+    else:
+        root = root_node
+
+    base = segments.add()
+    segments.write_arg(
+        base, [int.from_bytes(root[:16], "little"), int.from_bytes(root[16:], "little")]
+    )
+    ids.root.value = base
+
+
+def read_bytes(memory: MemoryDict, bytes_ptr: RelocatableValue) -> bytes:
+    length = memory[bytes_ptr + 1]
+    if length == 0:
+        return b""
+    data = memory[bytes_ptr]
+    return bytes([memory[data + i] for i in range(length)])
+
+
+def read_bytes32(memory: MemoryDict, ptr: RelocatableValue) -> bytes:
+    return memory[ptr].to_bytes(16, "little") + memory[ptr + 1].to_bytes(16, "little")
+
+
+def write_bytes(segments: MemorySegmentManager, value: bytes) -> RelocatableValue:
+    data_ptr = segments.add()
+    segments.write_arg(data_ptr, list(value))
+    bytes_ptr = segments.add()
+    segments.write_arg(bytes_ptr, [data_ptr, len(value)])
+    return bytes_ptr
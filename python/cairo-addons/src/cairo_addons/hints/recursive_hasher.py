@@ -0,0 +1,29 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def poseidon_hasher_new(ids: VmConsts):
+    # ruff: noqa: F821
+    try:
+        poseidon_hashers
+    except NameError:
+        poseidon_hashers = {}
+    handle = max(poseidon_hashers, default=-1) + 1
+    poseidon_hashers[handle] = []
+    ids.handle = handle
+
+
+@register_hint
+def poseidon_hasher_update(ids: VmConsts):
+    # ruff: noqa: F821
+    poseidon_hashers[ids.handle].append(ids.element)
+
+
+@register_hint
+def poseidon_hasher_finalize(ids: VmConsts):
+    # ruff: noqa: F821
+    from starkware.cairo.common.poseidon_hash import poseidon_hash_many
+
+    ids.hash = poseidon_hash_many(poseidon_hashers.pop(ids.handle))
@@ -0,0 +1,53 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def rlp_decode_item(ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager):
+    from cairo_addons.hints.rlp import decode_header
+
+    data = [memory[ids.rlp_ptr + i] for i in range(ids.rlp_len)]
+    if any(not 0 <= byte <= 255 for byte in data):
+        raise ValueError("Value is not a byte")
+    is_list, payload_start, payload_length = decode_header(bytes(data))
+    payload = data[payload_start : payload_start + payload_length]
+    ids.output_ptr = segments.gen_arg(payload)
+    ids.output_len = len(payload)
+    ids.is_list = int(is_list)
+
+
+def decode_header(data: bytes):
+    """
+    Returns whether the first RLP item of `data` is a list, and the start and length
+    of its payload, rejecting non-canonical encodings like the Rust hint.
+    """
+    if len(data) == 0:
+        raise ValueError("Invalid RLP: input too short")
+    prefix = data[0]
+    if prefix < 0x80:
+        return False, 0, 1
+
+    is_list = prefix >= 0xC0
+    short_prefix, long_prefix = (0xC0, 0xF7) if is_list else (0x80, 0xB7)
+    if prefix <= long_prefix:
+        payload_start = 1
+        payload_length = prefix - short_prefix
+        if not is_list and payload_length == 1 and (len(data) < 2 or data[1] < 0x80):
+            raise ValueError("Invalid RLP: non-canonical single byte")
+    else:
+        length_of_length = prefix - long_prefix
+        payload_start = 1 + length_of_length
+        if len(data) < payload_start:
+            raise ValueError("Invalid RLP: input too short")
+        if data[1] == 0:
+            raise ValueError("Invalid RLP: leading zeros in length")
+        payload_length = int.from_bytes(data[1:payload_start], "big")
+        if payload_length < 56:
+            raise ValueError("Invalid RLP: non-canonical size")
+
+    if len(data) < payload_start + payload_length:
+        raise ValueError("Invalid RLP: input too short")
+    return is_list, payload_start, payload_length
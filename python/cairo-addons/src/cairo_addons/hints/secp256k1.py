@@ -0,0 +1,44 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def secp256k1_add(ids: VmConsts):
+    from py_ecc.secp256k1.secp256k1 import add
+
+    from cairo_addons.hints.secp256k1 import read_point
+    from cairo_addons.utils.uint256 import int_to_uint256
+
+    # py_ecc also encodes the point at infinity as (0, 0).
+    x3, y3 = add(read_point(ids, "x1", "y1"), read_point(ids, "x2", "y2"))
+    ids.x3.low, ids.x3.high = int_to_uint256(x3)
+    ids.y3.low, ids.y3.high = int_to_uint256(y3)
+
+
+@register_hint
+def secp256k1_mul(ids: VmConsts):
+    from py_ecc.secp256k1.secp256k1 import multiply
+
+    from cairo_addons.hints.secp256k1 import read_point
+    from cairo_addons.utils.uint256 import int_to_uint256, uint256_to_int
+
+    scalar = uint256_to_int(ids.scalar.low, ids.scalar.high)
+    x_res, y_res = multiply(read_point(ids, "x", "y"), scalar)
+    ids.x_res.low, ids.x_res.high = int_to_uint256(x_res)
+    ids.y_res.low, ids.y_res.high = int_to_uint256(y_res)
+
+
+def read_point(ids, x_name, y_name):
+    from py_ecc.secp256k1.secp256k1 import B, P
+
+    from cairo_addons.utils.uint256 import uint256_to_int
+
+    x, y = (
+        uint256_to_int(getattr(ids, name).low, getattr(ids, name).high)
+        for name in (x_name, y_name)
+    )
+    assert (x, y) == (0, 0) or (
+        x < P and y < P and (y * y - x * x * x - B) % P == 0
+    ), f"({x_name}, {y_name}) is not on the curve"
+    return x, y
@@ -0,0 +1,29 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def compute_mapping_slot(ids: VmConsts):
+    from ethereum.crypto.hash import keccak256
+
+    key = ids.key_hi * 2**128 + ids.key_lo
+    base_slot = ids.base_slot_hi * 2**128 + ids.base_slot_lo
+    slot = int.from_bytes(
+        keccak256(key.to_bytes(32, "big") + base_slot.to_bytes(32, "big")), "big"
+    )
+    ids.slot_lo = slot % 2**128
+    ids.slot_hi = slot >> 128
+
+
+@register_hint
+def compute_nested_mapping_slot(ids: VmConsts):
+    from ethereum.crypto.hash import keccak256
+
+    key = ids.key_hi * 2**128 + ids.key_lo
+    inner_key = ids.inner_key_hi * 2**128 + ids.inner_key_lo
+    base_slot = ids.base_slot_hi * 2**128 + ids.base_slot_lo
+    outer_slot = keccak256(key.to_bytes(32, "big") + base_slot.to_bytes(32, "big"))
+    slot = int.from_bytes(keccak256(inner_key.to_bytes(32, "big") + outer_slot), "big")
+    ids.slot_lo = slot % 2**128
+    ids.slot_hi = slot >> 128
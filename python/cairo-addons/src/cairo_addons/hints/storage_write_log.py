@@ -0,0 +1,38 @@
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def log_storage_write(ids: VmConsts):
+    # ruff: noqa: F821
+    try:
+        storage_write_log
+    except NameError:
+        storage_write_log = []
+    storage_write_log.append((ids.address, ids.key, ids.value))
+
+
+@register_hint
+def get_storage_write_log_len(ids: VmConsts):
+    # ruff: noqa: F821
+    try:
+        storage_write_log
+    except NameError:
+        storage_write_log = []
+    ids.len = len(storage_write_log)
+
+
+@register_hint
+def flush_storage_write_log(ids: VmConsts, segments: MemorySegmentManager):
+    # ruff: noqa: F821
+    try:
+        storage_write_log
+    except NameError:
+        storage_write_log = []
+    ids.log_ptr = segments.gen_arg(
+        [item for write in storage_write_log for item in write]
+    )
+    ids.log_len = len(storage_write_log)
+    storage_write_log = []
@@ -0,0 +1,30 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def init_syscall_gas(ids: VmConsts):
+    remaining_gas = ids.initial_gas  # noqa: F841
+
+
+@register_hint
+def charge_syscall_gas(ids: VmConsts):
+    # ruff: noqa: F821
+    # (base cost, cost per item, name of the item count variable)
+    syscall_gas_costs = {
+        "StorageRead": (50, 0, None),
+        "StorageWrite": (50, 0, None),
+        "EmitEvent": (0, 100, "topic_count"),
+        "GetBlockHash": (50, 0, None),
+        "SendMessageToL1": (50, 0, None),
+    }
+    syscall_type = ids.syscall_type.to_bytes(31, "big").lstrip(b"\x00").decode()
+    if syscall_type not in syscall_gas_costs:
+        raise ValueError(f"Unknown syscall: {syscall_type}")
+    base, per_item, item_count = syscall_gas_costs[syscall_type]
+    required = base + per_item * (getattr(ids, item_count) if item_count else 0)
+    if required > remaining_gas:
+        raise ValueError(f"Out of gas: required {required}, available {remaining_gas}")
+    remaining_gas -= required
+    ids.gas_charged = required
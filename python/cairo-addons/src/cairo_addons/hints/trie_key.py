@@ -0,0 +1,25 @@
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def compute_storage_trie_key(ids: VmConsts, segments: MemorySegmentManager):
+    from ethereum.cancun.trie import bytes_to_nibble_list
+    from ethereum.crypto.hash import keccak256
+
+    slot = (ids.slot_hi * 2**128 + ids.slot_lo).to_bytes(32, "big")
+    if ids.is_mapping == 1:
+        key = (ids.key_hi * 2**128 + ids.key_lo).to_bytes(32, "big")
+        preimage = key + slot
+    elif ids.is_mapping == 0:
+        preimage = slot
+    else:
+        raise ValueError("is_mapping must be 0 or 1")
+
+    trie_key = keccak256(preimage)
+    trie_key_value = int.from_bytes(trie_key, "big")
+    ids.trie_key_lo = trie_key_value % 2**128
+    ids.trie_key_hi = trie_key_value >> 128
+    ids.trie_path = segments.gen_arg(list(bytes_to_nibble_list(trie_key)))
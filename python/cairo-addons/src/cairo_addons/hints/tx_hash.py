@@ -0,0 +1,113 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.relocatable import RelocatableValue
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def compute_legacy_tx_hash(ids: VmConsts, memory: MemoryDict):
+    from ethereum.crypto.hash import keccak256
+    from ethereum_rlp import rlp
+    from ethereum_types.numeric import Uint
+
+    from cairo_addons.hints.receipt import read_bytes
+    from cairo_addons.hints.tx_hash import read_to, read_u256
+
+    tx = ids.tx.value.address_
+    fields = (
+        read_u256(memory, memory[tx]),
+        Uint(memory[tx + 1]),
+        Uint(memory[tx + 2]),
+        read_to(memory, memory[tx + 3]),
+        read_u256(memory, memory[tx + 4]),
+        read_bytes(memory, memory[tx + 5]),
+    )
+    if ids.chain_id != 0:
+        fields += (Uint(ids.chain_id), Uint(0), Uint(0))
+
+    tx_hash = keccak256(rlp.encode(fields))
+    ids.hash_lo = int.from_bytes(tx_hash[16:], "big")
+    ids.hash_hi = int.from_bytes(tx_hash[:16], "big")
+
+
+@register_hint
+def compute_eip2930_tx_hash(ids: VmConsts, memory: MemoryDict):
+    from ethereum.crypto.hash import keccak256
+    from ethereum_rlp import rlp
+    from ethereum_types.numeric import Uint
+
+    from cairo_addons.hints.receipt import read_bytes
+    from cairo_addons.hints.tx_hash import read_access_list, read_to, read_u256
+
+    tx = ids.tx.value.address_
+    fields = (
+        Uint(memory[tx]),
+        read_u256(memory, memory[tx + 1]),
+        Uint(memory[tx + 2]),
+        Uint(memory[tx + 3]),
+        read_to(memory, memory[tx + 4]),
+        read_u256(memory, memory[tx + 5]),
+        read_bytes(memory, memory[tx + 6]),
+        read_access_list(memory, memory[tx + 7]),
+    )
+
+    tx_hash = keccak256(b"\x01" + rlp.encode(fields))
+    ids.hash_lo = int.from_bytes(tx_hash[16:], "big")
+    ids.hash_hi = int.from_bytes(tx_hash[:16], "big")
+
+
+@register_hint
+def compute_eip1559_tx_hash(ids: VmConsts, memory: MemoryDict):
+    from ethereum.crypto.hash import keccak256
+    from ethereum_rlp import rlp
+    from ethereum_types.numeric import Uint
+
+    from cairo_addons.hints.receipt import read_bytes
+    from cairo_addons.hints.tx_hash import read_access_list, read_to, read_u256
+
+    tx = ids.tx.value.address_
+    fields = (
+        Uint(memory[tx]),
+        read_u256(memory, memory[tx + 1]),
+        Uint(memory[tx + 2]),
+        Uint(memory[tx + 3]),
+        Uint(memory[tx + 4]),
+        read_to(memory, memory[tx + 5]),
+        read_u256(memory, memory[tx + 6]),
+        read_bytes(memory, memory[tx + 7]),
+        read_access_list(memory, memory[tx + 8]),
+    )
+
+    tx_hash = keccak256(b"\x02" + rlp.encode(fields))
+    ids.hash_lo = int.from_bytes(tx_hash[16:], "big")
+    ids.hash_hi = int.from_bytes(tx_hash[:16], "big")
+
+
+def read_u256(memory: MemoryDict, u256_ptr: RelocatableValue):
+    from ethereum_types.numeric import Uint
+
+    return Uint(memory[u256_ptr] + memory[u256_ptr + 1] * 2**128)
+
+
+def read_to(memory: MemoryDict, to_ptr: RelocatableValue) -> bytes:
+    address_ptr = memory.get(to_ptr + 1)
+    if not isinstance(address_ptr, RelocatableValue):
+        return b""
+    return memory[address_ptr].to_bytes(32, "little")[:20]
+
+
+def read_access_list(memory: MemoryDict, tuple_ptr: RelocatableValue) -> tuple:
+    from cairo_addons.hints.receipt import read_bytes32
+
+    entries = []
+    for i in range(memory[tuple_ptr + 1]):
+        entry = memory[memory[tuple_ptr] + i]
+        address = memory[entry].to_bytes(32, "little")[:20]
+        keys_ptr = memory[entry + 1]
+        storage_keys = tuple(
+            read_bytes32(memory, memory[memory[keys_ptr] + j])
+            for j in range(memory[keys_ptr + 1])
+        )
+        entries.append((address, storage_keys))
+    return tuple(entries)
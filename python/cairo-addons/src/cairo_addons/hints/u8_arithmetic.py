@@ -0,0 +1,53 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def u8_add_with_carry(ids: VmConsts):
+    from cairo_addons.hints.u8_arithmetic import read_operands
+
+    a, b = read_operands(ids)
+    ids.result = (a + b) % 256
+    ids.carry = int(a + b >= 256)
+
+
+@register_hint
+def u8_xor(ids: VmConsts):
+    from cairo_addons.hints.u8_arithmetic import read_operands
+
+    a, b = read_operands(ids)
+    ids.result = a ^ b
+
+
+@register_hint
+def u8_and(ids: VmConsts):
+    from cairo_addons.hints.u8_arithmetic import read_operands
+
+    a, b = read_operands(ids)
+    ids.result = a & b
+
+
+@register_hint
+def u8_or(ids: VmConsts):
+    from cairo_addons.hints.u8_arithmetic import read_operands
+
+    a, b = read_operands(ids)
+    ids.result = a | b
+
+
+@register_hint
+def u8_rotate_right(ids: VmConsts):
+    from cairo_addons.hints.u8_arithmetic import read_operands
+
+    a, b = read_operands(ids)
+    b %= 8
+    ids.result = ((a >> b) | (a << (8 - b))) & 0xFF
+
+
+def read_operands(ids: VmConsts):
+    for name in ("a", "b"):
+        value = getattr(ids, name)
+        if not 0 <= value <= 255:
+            raise ValueError(f"{name} out of range: {value}")
+    return ids.a, ids.b
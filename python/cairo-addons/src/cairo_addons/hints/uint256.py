@@ -0,0 +1,50 @@
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def uint256_add(ids: VmConsts):
+    from cairo_addons.hints.uint256 import read_u128
+
+    a = read_u128(ids, "a_high") << 128 | read_u128(ids, "a_low")
+    b = read_u128(ids, "b_high") << 128 | read_u128(ids, "b_low")
+    res = a + b
+    ids.res_low = res % 2**128
+    ids.res_high = (res >> 128) % 2**128
+    ids.carry = res >> 256
+
+
+@register_hint
+def uint256_mul(ids: VmConsts):
+    from cairo_addons.hints.uint256 import read_u128
+
+    a = read_u128(ids, "a_high") << 128 | read_u128(ids, "a_low")
+    b = read_u128(ids, "b_high") << 128 | read_u128(ids, "b_low")
+    res = a * b
+    ids.res_0 = res % 2**128
+    ids.res_1 = (res >> 128) % 2**128
+    ids.res_2 = (res >> 256) % 2**128
+    ids.res_3 = res >> 384
+
+
+@register_hint
+def uint256_divmod(ids: VmConsts):
+    from cairo_addons.hints.uint256 import read_u128
+
+    dividend = read_u128(ids, "dividend_high") << 128 | read_u128(ids, "dividend_low")
+    divisor = read_u128(ids, "divisor_high") << 128 | read_u128(ids, "divisor_low")
+    if divisor == 0:
+        raise ZeroDivisionError(f"Division by zero: cannot divide {dividend} by 0")
+    quotient, remainder = divmod(dividend, divisor)
+    ids.quotient_low = quotient % 2**128
+    ids.quotient_high = quotient >> 128
+    ids.remainder_low = remainder % 2**128
+    ids.remainder_high = remainder >> 128
+
+
+def read_u128(ids: VmConsts, name: str) -> int:
+    value = getattr(ids, name)
+    if value >= 2**128:
+        raise ValueError(f"{name} exceeds 128 bits")
+    return value
@@ -0,0 +1,36 @@
+from starkware.cairo.lang.vm.memory_dict import MemoryDict
+from starkware.cairo.lang.vm.memory_segments import MemorySegmentManager
+from starkware.cairo.lang.vm.vm_consts import VmConsts
+
+from cairo_addons.hints.decorator import register_hint
+
+
+@register_hint
+def reverse_bytes32(ids: VmConsts):
+    if ids.input_lo >= 2**128 or ids.input_hi >= 2**128:
+        raise ValueError("input exceeds 128 bits")
+    word = (ids.input_hi * 2**128 + ids.input_lo).to_bytes(32, "big")
+    reversed_word = int.from_bytes(word, "little")
+    ids.output_lo = reversed_word % 2**128
+    ids.output_hi = reversed_word >> 128
+
+
+@register_hint
+def reverse_bytes16(ids: VmConsts):
+    if ids.input >= 2**128:
+        raise ValueError("input exceeds 128 bits")
+    ids.output = int.from_bytes(ids.input.to_bytes(16, "big"), "little")
+
+
+@register_hint
+def reverse_bytes8(ids: VmConsts):
+    if ids.input >= 2**64:
+        raise ValueError("input exceeds 64 bits")
+    ids.output = int.from_bytes(ids.input.to_bytes(8, "big"), "little")
+
+
+@register_hint
+def swap_bytes32_ptr(ids: VmConsts, memory: MemoryDict, segments: MemorySegmentManager):
+    ids.output_ptr = segments.gen_arg(
+        [memory[ids.input_ptr + i] for i in reversed(range(32))]
+    )
@@ -43,17 +43,25 @@ func os() -> (felt, felt, felt) {
 
 
 @pytest.fixture(scope="session")
-def sw_program(cairo_content):
-    pass_manager = default_pass_manager(
-        prime=DEFAULT_PRIME, read_module=get_module_reader(cairo_path=[]).read
-    )
-
-    return compile_cairo(
-        cairo_content,
-        pass_manager=pass_manager,
-        debug_info=False,
-        add_start=False,
-    )
+def compile_program():
+    def _compile(cairo_content: str, add_start: bool = False) -> SWProgram:
+        pass_manager = default_pass_manager(
+            prime=DEFAULT_PRIME, read_module=get_module_reader(cairo_path=[]).read
+        )
+
+        return compile_cairo(
+            cairo_content,
+            pass_manager=pass_manager,
+            debug_info=False,
+            add_start=add_start,
+        )
+
+    return _compile
+
+
+@pytest.fixture(scope="session")
+def sw_program(compile_program, cairo_content):
+    return compile_program(cairo_content)
 
 
 @pytest.fixture(scope="module")
@@ -1,5 +1,6 @@
 import pytest
 from starkware.cairo.common.dict import DictManager, DictTracker
+from starkware.cairo.common.poseidon_hash import poseidon_hash_many
 
 from cairo_addons.vm import CairoRunner
 from cairo_addons.vm import DictManager as RustDictManager
@@ -50,6 +51,48 @@ class TestDictManager:
                 RustDictTracker(data={}, current_ptr=dict_ptr),
             )
 
+    def test_new_dict_from_python(self, runner):
+        value_ptr = runner.segments.add()
+        data = {1: 2, (3, 4): 5, 6: value_ptr}
+
+        dict_ptr = runner.dict_manager.new_dict_from_python(runner.segments, data)
+        assert dict_ptr.offset == 0
+        tracker = runner.dict_manager.get_tracker(dict_ptr)
+        assert tracker.current_ptr == dict_ptr
+        assert tracker.to_python_dict() == data
+
+        other_ptr = runner.dict_manager.new_dict_from_python(runner.segments, {})
+        assert other_ptr.segment_index == dict_ptr.segment_index + 1
+        assert runner.dict_manager.get_tracker(other_ptr).to_python_dict() == {}
+
+    def test_new_dict_from_python_invalid_key(self, runner):
+        with pytest.raises(TypeError):
+            runner.dict_manager.new_dict_from_python(runner.segments, {"key": 1})
+
+    def test_squash_all(self, runner):
+        dict_ptr = runner.dict_manager.new_dict_from_python(runner.segments, {})
+        accesses = [3, 0, 1, 1, 0, 2, 3, 1, 4, 1, 2, 5]
+        end_ptr = runner.segments.load_data(dict_ptr, accesses)
+        runner.dict_manager.trackers[dict_ptr.segment_index] = RustDictTracker(
+            data={1: 5, 3: 4}, current_ptr=end_ptr, default_value=None
+        )
+        empty_ptr = runner.dict_manager.new_dict_from_python(runner.segments, {})
+
+        assert runner.dict_manager.squash_all(runner.segments) == {
+            dict_ptr: [(1, 0, 5), (3, 0, 4)],
+            empty_ptr: [],
+        }
+
+    def test_squash_all_inconsistent_accesses(self, runner):
+        dict_ptr = runner.dict_manager.new_dict_from_python(runner.segments, {})
+        end_ptr = runner.segments.load_data(dict_ptr, [1, 0, 2, 1, 3, 4])
+        runner.dict_manager.trackers[dict_ptr.segment_index] = RustDictTracker(
+            data={1: 4}, current_ptr=end_ptr, default_value=None
+        )
+
+        with pytest.raises(ValueError, match="Inconsistent accesses to key 1"):
+            runner.dict_manager.squash_all(runner.segments)
+
     def test_api_compatibility(self):
         rust_manager = RustDictManager()
         python_manager = DictManager()
@@ -69,3 +112,128 @@ class TestDictManager:
         assert str(rust_manager.trackers[dict_ptr.segment_index]) == str(
             python_manager.trackers[dict_ptr.segment_index]
         )
+
+    @pytest.mark.parametrize("values", [[1, 2, 3], [0, 2**128], []])
+    def test_build_compound_key(self, values):
+        manager = RustDictManager()
+
+        key = manager.build_compound_key(values)
+        assert key == tuple(values)
+        assert manager.preimages[poseidon_hash_many(values)] == key
+
+    def test_build_compound_key_single_value(self):
+        manager = RustDictManager()
+
+        # Single-element keys are not hashed, as in the hashdict hints.
+        assert manager.build_compound_key([7]) == (7,)
+        assert manager.preimages[7] == (7,)
+
+    def test_build_compound_key_invalid_value(self):
+        manager = RustDictManager()
+
+        with pytest.raises(TypeError, match="Invalid value at index 1"):
+            manager.build_compound_key([1, "key"])
+        with pytest.raises(ValueError, match="must be integers"):
+            manager.build_compound_key([1, RustRelocatable(segment_index=0, offset=0)])
+        assert repr(manager.preimages) == "PreimagesMapping({})"
+
+    def test_build_compound_key_registers_preimage(self):
+        manager = RustDictManager()
+        hashed_key = poseidon_hash_many([1, 2])
+        with pytest.raises(KeyError):
+            manager.preimages[hashed_key]
+
+        key = manager.build_compound_key([1, 2])
+
+        assert manager.preimages[hashed_key] == key
+        # Building the same key again overwrites the preimage with the same key.
+        assert manager.build_compound_key([1, 2]) == key
+        assert manager.preimages[hashed_key] == key
+
+    def test_apply_updates(self):
+        dict_ptr = RustRelocatable(segment_index=0, offset=0)
+        tracker = RustDictTracker(data={1: 2}, current_ptr=dict_ptr)
+        value_ptr = RustRelocatable(segment_index=1, offset=2)
+
+        assert tracker.apply_updates([(1, 3), (4, value_ptr), ([5, 6], 7)]) == 3
+        assert tracker.data == {1: 3, 4: value_ptr, (5, 6): 7}
+
+    def test_apply_updates_through_dict_manager(self):
+        manager = RustDictManager()
+        dict_ptr = RustRelocatable(segment_index=0, offset=0)
+        manager.trackers[0] = RustDictTracker(data={1: 2}, current_ptr=dict_ptr)
+
+        tracker = manager.get_tracker(dict_ptr)
+        tracker.apply_updates([(1, 3), ([4, 5], 6)])
+        # The tracker is a copy, the manager is only updated once it is written back.
+        assert manager.get_tracker(dict_ptr).data == {1: 2}
+        manager.trackers[0] = tracker
+        assert manager.get_tracker(dict_ptr).data == {1: 3, (4, 5): 6}
+
+    def test_apply_updates_invalid_key(self):
+        dict_ptr = RustRelocatable(segment_index=0, offset=0)
+        tracker = RustDictTracker(data={1: 2}, current_ptr=dict_ptr)
+
+        with pytest.raises(TypeError, match="Invalid key at index 1"):
+            tracker.apply_updates([(3, 4), ("key", 5)])
+        assert tracker.data == {1: 2}
+
+    def test_to_python_dict(self):
+        dict_ptr = RustRelocatable(segment_index=0, offset=0)
+        value_ptr = RustRelocatable(segment_index=1, offset=2)
+        data = {1: 2, 3: value_ptr, (4, 5): 6}
+        tracker = RustDictTracker(data=data, current_ptr=dict_ptr)
+
+        python_dict = tracker.to_python_dict()
+        assert isinstance(python_dict, dict)
+        assert python_dict == data
+
+    def test_from_python_dict(self):
+        dict_ptr = RustRelocatable(segment_index=0, offset=0)
+        data = {1: 2, (3, 4): 5}
+        tracker = RustDictTracker.from_python_dict(data, dict_ptr, default_value=7)
+
+        assert tracker.current_ptr == dict_ptr
+        assert tracker.to_python_dict() == data
+
+    def test_snapshot_restore(self):
+        dict_ptr = RustRelocatable(segment_index=0, offset=0)
+        tracker = RustDictTracker(data={1: 2}, current_ptr=dict_ptr)
+        snapshot = tracker.snapshot()
+
+        tracker.apply_updates([(1, 3), ([4, 5], 6)])
+        assert tracker.data == {1: 3, (4, 5): 6}
+        tracker.restore(snapshot)
+        assert tracker.data == {1: 2}
+        assert tracker.current_ptr == dict_ptr
+
+        moved_ptr = RustRelocatable(segment_index=0, offset=6)
+        moved_tracker = RustDictTracker(data={}, current_ptr=moved_ptr)
+        moved_tracker.restore(snapshot)
+        assert moved_tracker.data == {1: 2}
+        assert moved_tracker.current_ptr == dict_ptr
+
+    def test_snapshot_restore_through_dict_manager(self):
+        manager = RustDictManager()
+        dict_ptr = RustRelocatable(segment_index=0, offset=0)
+        manager.trackers[0] = RustDictTracker(data={1: 2}, current_ptr=dict_ptr)
+        snapshot = manager.snapshot(dict_ptr)
+
+        tracker = manager.get_tracker(dict_ptr)
+        tracker.apply_updates([(1, 3), ([4, 5], 6)])
+        manager.trackers[0] = tracker
+        assert manager.get_tracker(dict_ptr).data == {1: 3, (4, 5): 6}
+
+        manager.restore(dict_ptr, snapshot)
+        assert manager.get_tracker(dict_ptr).data == {1: 2}
+        assert manager.get_tracker(dict_ptr).current_ptr == dict_ptr
+
+    def test_restore_other_segment(self):
+        dict_ptr = RustRelocatable(segment_index=0, offset=0)
+        snapshot = RustDictTracker(data={1: 2}, current_ptr=dict_ptr).snapshot()
+        other_ptr = RustRelocatable(segment_index=1, offset=0)
+        tracker = RustDictTracker(data={}, current_ptr=other_ptr)
+
+        with pytest.raises(ValueError, match="cannot be restored to segment 1"):
+            tracker.restore(snapshot)
+        assert tracker.data == {}
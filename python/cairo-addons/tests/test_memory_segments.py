@@ -51,3 +51,50 @@ class TestMemorySegmentManager:
         ptr = runner.segments.add()
         runner.segments.load_data(ptr, [1, 2, 3, 4])
         assert runner.segments.memory.get(ptr) == 1
+
+    def test_bulk_write(self, runner):
+        ptr = runner.segments.add()
+        value_ptr = runner.segments.add()
+        runner.segments.bulk_write([(ptr, 1), (ptr + 1, value_ptr), (ptr + 3, 2**128)])
+        assert runner.segments.memory.get(ptr) == 1
+        assert runner.segments.memory.get(ptr + 1) == value_ptr
+        assert runner.segments.memory.get(ptr + 2) is None
+        assert runner.segments.memory.get(ptr + 3) == 2**128
+
+    def test_bulk_write_invalid_value(self, runner):
+        ptr = runner.segments.add()
+        with pytest.raises(TypeError, match="Invalid value at index 1"):
+            runner.segments.bulk_write([(ptr, 1), (ptr + 1, "value")])
+        assert runner.segments.memory.get(ptr) is None
+
+    def test_bulk_write_should_stop_at_first_failed_write(self, runner):
+        ptr = runner.segments.add()
+        runner.segments.load_data(ptr + 1, [1])
+        with pytest.raises(RuntimeError, match="Failed to write at address"):
+            runner.segments.bulk_write([(ptr, 1), (ptr + 1, 2), (ptr + 2, 3)])
+        assert runner.segments.memory.get(ptr) == 1
+        assert runner.segments.memory.get(ptr + 2) is None
+
+    def test_read_segment(self, runner):
+        ptr = runner.segments.add()
+        value_ptr = runner.segments.add()
+        runner.segments.load_data(ptr, [1, value_ptr])
+        runner.segments.load_data(ptr + 3, [DEFAULT_PRIME - 1])
+        assert runner.segments.read_segment(ptr.segment_index) == [
+            1,
+            value_ptr,
+            None,
+            DEFAULT_PRIME - 1,
+        ]
+        assert runner.segments.read_segment(value_ptr.segment_index) == []
+
+    def test_read_segment_does_not_cache_sizes(self, runner):
+        ptr = runner.segments.add()
+        runner.segments.load_data(ptr, [1])
+        assert runner.segments.read_segment(ptr.segment_index) == [1]
+        runner.segments.load_data(ptr + 1, [2])
+        assert runner.segments.read_segment(ptr.segment_index) == [1, 2]
+
+    def test_read_segment_invalid_index(self, runner):
+        with pytest.raises(IndexError, match="Segment index out of range: 0"):
+            runner.segments.read_segment(0)
@@ -1,4 +1,8 @@
+import json
+
 import pytest
+from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+from starkware.cairo.lang.compiler.cairo_compile import compile_cairo
 
 from cairo_addons.vm import Program as RustProgram
 
@@ -16,3 +20,52 @@ class TestProgram:
         builtins = program.builtins
         program.builtins = builtins[:-1]
         assert program.builtins == builtins[:-1]
+
+    def test_constants(self):
+        program = compile_cairo(
+            """
+const ONE = 1;
+
+namespace Math {
+    const TWO_POW_128 = 2 ** 128;
+}
+
+func main() {
+    return ();
+}
+""",
+            prime=DEFAULT_PRIME,
+            debug_info=False,
+            add_start=False,
+        )
+        program_bytes = json.dumps(program.Schema().dump(program)).encode()
+
+        constants = RustProgram.from_bytes(program_bytes).constants
+        assert constants == {
+            "__main__.ONE": 1,
+            "__main__.Math.TWO_POW_128": 2**128,
+        }
+
+    def test_constants_empty(self, program_bytes):
+        assert RustProgram.from_bytes(program_bytes).constants == {}
+
+    def test_entry_points(self, program_bytes, sw_program):
+        entry_points = RustProgram.from_bytes(program_bytes).entry_points()
+        assert entry_points == {
+            "__main__.main": sw_program.get_label("main"),
+            "__main__.os": sw_program.get_label("os"),
+        }
+
+    def test_json_round_trip(self, program_bytes):
+        program = RustProgram.from_bytes(program_bytes)
+        program_json = program.to_json()
+
+        loaded = RustProgram.from_json(program_json)
+        assert loaded.builtins == program.builtins
+        assert loaded.constants == program.constants
+        assert loaded.entry_points() == program.entry_points()
+        assert json.loads(loaded.to_json()) == json.loads(program_json)
+
+    def test_from_json_invalid(self):
+        with pytest.raises(RuntimeError):
+            RustProgram.from_json("not a program")
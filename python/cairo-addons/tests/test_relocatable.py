@@ -1,3 +1,4 @@
+import pytest
 from hypothesis import assume, given
 from hypothesis import strategies as st
 from starkware.cairo.lang.vm.relocatable import RelocatableValue as SWRelocatable
@@ -22,6 +23,10 @@ class TestRelocatable:
         assert rust_relocatable.segment_index == relocatable.segment_index
         assert rust_relocatable.offset == relocatable.offset
 
+    def test_as_tuple(self):
+        segment_index, offset = RustRelocatable(2, 5).as_tuple()
+        assert (segment_index, offset) == (2, 5)
+
     @given(rel1=..., rel2=...)
     def test_eq(self, rel1: SWRelocatable, rel2: SWRelocatable):
         rust_rel1 = RustRelocatable(rel1.segment_index, rel1.offset)
@@ -69,6 +74,10 @@ class TestRelocatable:
         assert str(rust_rel) == str(rel)
         assert format(rust_rel) == format(rel)
 
+    def test_repr(self):
+        assert repr(RustRelocatable(1, 5)) == "1:5"
+        assert str([RustRelocatable(0, 2)]) == "[0:2]"
+
     @given(rel=...)
     def test_hash(self, rel: SWRelocatable):
         rust_rel = RustRelocatable(rel.segment_index, rel.offset)
@@ -79,3 +88,42 @@ class TestRelocatable:
         assert (RustRelocatable(rel.segment_index, rel.offset) in rust_dict) == (
             SWRelocatable(rel.segment_index, rel.offset) in sw_dict
         )
+
+    def test_ordering_builtins(self):
+        rels = [RustRelocatable(1, 10), RustRelocatable(0, 7), RustRelocatable(1, 5)]
+        assert RustRelocatable(1, 5) < RustRelocatable(1, 10)
+        assert sorted(rels) == [
+            RustRelocatable(0, 7),
+            RustRelocatable(1, 5),
+            RustRelocatable(1, 10),
+        ]
+        assert min(rels) == RustRelocatable(0, 7)
+        assert max(rels) == RustRelocatable(1, 10)
+
+    def test_set_and_dict_keys(self):
+        rels = {RustRelocatable(0, 1), RustRelocatable(1, 0), RustRelocatable(0, 1)}
+        assert len(rels) == 2
+        assert RustRelocatable(1, 0) in rels
+        assert {RustRelocatable(2, 3): "value"}[RustRelocatable(2, 3)] == "value"
+
+    def test_radd(self):
+        assert 5 + RustRelocatable(1, 2) == RustRelocatable(1, 7)
+
+    def test_add_negative_offset(self):
+        assert RustRelocatable(1, 7) + (-5) == RustRelocatable(1, 2)
+
+    def test_sub_negative_offset(self):
+        assert RustRelocatable(1, 2) - (-5) == RustRelocatable(1, 7)
+
+    def test_sub_relocatable_distance(self):
+        assert RustRelocatable(1, 7) - RustRelocatable(1, 2) == 5
+
+    def test_arithmetic_should_raise_overflow_error(self):
+        with pytest.raises(OverflowError):
+            RustRelocatable(1, 2) + (-3)
+        with pytest.raises(OverflowError):
+            RustRelocatable(1, 2) - 3
+        with pytest.raises(OverflowError):
+            RustRelocatable(1, 2) - RustRelocatable(1, 3)
+        with pytest.raises(OverflowError):
+            RustRelocatable(1, 2) - RustRelocatable(2, 1)
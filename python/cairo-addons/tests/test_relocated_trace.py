@@ -0,0 +1,30 @@
+from cairo_addons.vm import RelocatedTraceEntry
+
+
+class TestRelocatedTraceEntry:
+    def test_eq(self):
+        entry = RelocatedTraceEntry(1, 2, 3)
+        assert entry == RelocatedTraceEntry(1, 2, 3)
+        assert entry != RelocatedTraceEntry(1, 2, 4)
+        assert entry != RelocatedTraceEntry(2, 2, 3)
+
+    def test_hash(self):
+        entries = {RelocatedTraceEntry(1, 2, 3), RelocatedTraceEntry(1, 2, 3)}
+        assert len(entries) == 1
+        assert {RelocatedTraceEntry(4, 5, 6): 1}[RelocatedTraceEntry(4, 5, 6)] == 1
+
+    def test_ordering(self):
+        entries = [
+            RelocatedTraceEntry(2, 1, 1),
+            RelocatedTraceEntry(1, 3, 1),
+            RelocatedTraceEntry(1, 2, 9),
+        ]
+        assert sorted(entries) == [
+            RelocatedTraceEntry(1, 2, 9),
+            RelocatedTraceEntry(1, 3, 1),
+            RelocatedTraceEntry(2, 1, 1),
+        ]
+
+    def test_repr(self):
+        entry = RelocatedTraceEntry(1, 2, 3)
+        assert repr(entry) == "RelocatedTraceEntry(pc=1, ap=2, fp=3)"
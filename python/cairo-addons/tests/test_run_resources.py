@@ -9,3 +9,21 @@ class TestRunResources:
     def test_init_with_n_steps(self):
         run_resources = RunResources(n_steps=100)
         assert run_resources.n_steps == 100
+
+    def test_steps_without_limit(self):
+        run_resources = RunResources()
+        assert run_resources.steps_used is None
+        assert run_resources.steps_remaining is None
+        assert not run_resources.is_exhausted
+
+    def test_steps_with_limit(self):
+        run_resources = RunResources(n_steps=100)
+        assert run_resources.steps_used == 0
+        assert run_resources.steps_remaining == 100
+        assert not run_resources.is_exhausted
+
+    def test_is_exhausted(self):
+        run_resources = RunResources(n_steps=0)
+        assert run_resources.steps_used == 0
+        assert run_resources.steps_remaining == 0
+        assert run_resources.is_exhausted
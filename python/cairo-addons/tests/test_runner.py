@@ -1,10 +1,187 @@
-from cairo_addons.vm import CairoRunner
+import json
+
+import pytest
+from starkware.cairo.common.poseidon_hash import poseidon_hash_many
+from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+
+from cairo_addons.vm import (
+    CairoRunner,
+    DictManager,
+    DictTracker,
+    Layout,
+    Program,
+    Relocatable,
+    RunResources,
+    StrippedProgram,
+)
+
+
+def to_rust_program(sw_program):
+    program_json = json.dumps(sw_program.Schema().dump(sw_program))
+    return Program.from_bytes(program_json.encode())
+
+
+def initialize_entrypoint(runner, sw_program, entrypoint, args=()):
+    """
+    Sets up the initialized `runner` to call `entrypoint` with the program builtins
+    followed by `args`, and returns the address of the final `jmp rel 0` the call
+    returns to.
+    """
+    stack = [
+        value
+        for builtin in sw_program.builtins
+        for value in runner.builtin_runners[f"{builtin}_builtin"]["initial_stack"]
+    ]
+    return_fp = runner.execution_base + 2
+    end = runner.program_base + runner.program_len - 2
+    stack = [return_fp, end] + stack + list(args) + [return_fp, end]
+    runner.initial_pc = runner.program_base + sw_program.get_label(entrypoint)
+    runner.load_program_data(runner.program_base)
+    runner.load_data(runner.execution_base, stack)
+    runner.initial_fp = runner.initial_ap = runner.execution_base + len(stack)
+    runner.initialize_vm()
+    return end
+
+
+INNER_PROGRAM = """
+%builtins output
+
+func main{output_ptr: felt*}(x: felt) {
+    assert [output_ptr] = x * 2;
+    let output_ptr = output_ptr + 1;
+    return ();
+}
+"""
+
+RANGE_CHECK_PROGRAM = """
+%builtins range_check
+
+func main{range_check_ptr}() {
+    assert [range_check_ptr] = 42;
+    let range_check_ptr = range_check_ptr + 1;
+    return ();
+}
+"""
+
+BUILTINS_PROGRAM = """
+%builtins range_check poseidon
+
+func main{range_check_ptr, poseidon_ptr: felt*}() {
+    assert [range_check_ptr] = 42;
+    let range_check_ptr = range_check_ptr + 1;
+    assert poseidon_ptr[0] = 1;
+    assert poseidon_ptr[1] = 2;
+    assert poseidon_ptr[2] = 3;
+    tempvar hash = poseidon_ptr[3];
+    let poseidon_ptr = poseidon_ptr + 6;
+    return ();
+}
+"""
+
+TRACE_PROGRAM = """
+func main() {
+    [ap] = 42, ap++;
+    ret;
+}
+"""
+
+PROFILED_PROGRAM = """
+func is_negative(value: felt) -> felt {
+    alloc_locals;
+    local is_neg: felt;
+    %{ is_felt_negative %}
+    return is_neg;
+}
+
+func main() {
+    is_negative(1);
+    is_negative(-1);
+    return ();
+}
+"""
+
+HASHDICT_COPY_PROGRAM = """
+func write(dict_ptr: felt*, key_len: felt, key: felt*, new_value: felt) {
+    %{ hashdict_write %}
+    return ();
+}
+
+func copy(source_ptr_stop: felt*, dest_ptr: felt*, source_key: felt) {
+    %{ copy_hashdict_tracker_entry %}
+    return ();
+}
+
+func main(src: felt*, dst: felt*, key: felt*, hashed_key: felt) {
+    write(src, 2, key, 42);
+    copy(src + 3, dst, hashed_key);
+    return ();
+}
+"""
+
+HASHDICT_DELETE_PROGRAM = """
+func main(dict_ptr: felt*, key: felt*) {
+    tempvar key_len = 2;
+    %{ hashdict_delete %}
+    return ();
+}
+"""
+
+OUTER_PROGRAM = """
+func main(program_id: felt, args_ptr: felt*, args_len: felt) -> felt* {
+    tempvar output_ptr: felt*;
+    %{ cairo_run_inner %}
+    return output_ptr;
+}
+"""
 
 
 class TestRunner:
     def test_runner_creation(self, rust_program):
         CairoRunner(rust_program)
 
+    @pytest.mark.parametrize("proof_mode", [False, True])
+    def test_proof_mode(self, rust_program, proof_mode):
+        runner = CairoRunner(rust_program, layout="all_cairo", proof_mode=proof_mode)
+        assert runner.proof_mode == proof_mode
+
+    def test_initialize_main_entrypoint_proof_mode(
+        self, compile_program, cairo_content
+    ):
+        sw_program = compile_program(cairo_content, add_start=True)
+        runner = CairoRunner(
+            to_rust_program(sw_program), layout="all_cairo", proof_mode=True
+        )
+        runner.initialize_segments()
+        end = runner.initialize_main_entrypoint()
+        runner.initialize_vm()
+
+        execution_base = runner.execution_base
+        assert runner.initial_pc == runner.program_base + sw_program.get_label(
+            "__start__"
+        )
+        assert runner.initial_fp == runner.initial_ap == execution_base + 2
+        assert runner.ap == runner.fp == execution_base + 2
+        # The fake return fp and pc cells, so the verifier can enforce [fp - 2] = fp.
+        assert runner.segments.memory.get(execution_base) == execution_base + 2
+        assert runner.segments.memory.get(execution_base + 1) == 0
+        assert runner.execution_public_memory == [0, 1]
+
+        assert end == runner.program_base + sw_program.get_label("__end__")
+        runner.run_until_pc(end, RunResources())
+        assert runner.pc == end
+
+    def test_layout(self, rust_program):
+        assert Layout.from_str("all_cairo") == Layout.all_cairo
+        assert str(Layout.all_cairo) == "all_cairo"
+        runner = CairoRunner(rust_program, layout=Layout.all_cairo)
+        runner.initialize_segments()
+
+    def test_invalid_layout(self, rust_program):
+        with pytest.raises(ValueError, match="Invalid layout name: unknown"):
+            Layout.from_str("unknown")
+        with pytest.raises(ValueError, match="Invalid layout name: unknown"):
+            CairoRunner(rust_program, layout="unknown")
+
     def test_initialize_segments(self, rust_program):
         runner = CairoRunner(rust_program, layout="all_cairo")
         runner.initialize_segments()
@@ -46,3 +223,494 @@ class TestRunner:
         expected = 0xABDE1
         runner.segments.load_data(base, [expected])
         assert runner.segments.memory.get(base) == expected
+
+    def test_validate_existing_memory(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        runner.load_program_data(runner.program_base)
+        base = runner.segments.add()
+        runner.segments.load_data(base, [1, 2**128, runner.program_base])
+        runner.validate_existing_memory()
+
+    def test_generate_proof_data_requires_output_dir(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        with pytest.raises(KeyError):
+            runner.generate_proof_data({})
+
+    def test_generate_proof_data_rejects_unknown_format(self, rust_program, tmp_path):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        with pytest.raises(ValueError):
+            runner.generate_proof_data({"output_dir": tmp_path, "format": "yaml"})
+
+    def test_generate_proof_data(self, rust_program, sw_program, tmp_path):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        # The Cairo PIE requires main to return to an empty segment, as when
+        # initialized by cairo-vm.
+        end = runner.program_base + runner.program_len - 2
+        stack = [runner.segments.add(), end]
+        runner.initial_pc = runner.program_base + sw_program.get_label("main")
+        runner.load_program_data(runner.program_base)
+        runner.load_data(runner.execution_base, stack)
+        runner.initial_fp = runner.initial_ap = runner.execution_base + len(stack)
+        runner.initialize_vm()
+        runner.run_until_pc(end, RunResources())
+
+        result = runner.generate_proof_data({"output_dir": tmp_path / "proof"})
+
+        output_dir = tmp_path / "proof"
+        for name in (
+            "trace.bin",
+            "memory.bin",
+            "air_public_input.json",
+            "air_private_input.json",
+            "cairo_pie.zip",
+        ):
+            assert (output_dir / name).stat().st_size > 0
+        assert result["public_input"]["layout"] == "all_cairo"
+        assert str(result["private_input_path"]) == str(
+            output_dir / "air_private_input.json"
+        )
+        assert str(result["pie_path"]) == str(output_dir / "cairo_pie.zip")
+
+    def test_get_air_private_input(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        private_input = runner.get_air_private_input()
+        assert isinstance(private_input, dict)
+        assert "trace_path" not in private_input
+        assert "memory_path" not in private_input
+        assert all(instances == [] for instances in private_input.values())
+
+    def test_get_execution_scopes(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        scopes = runner.get_execution_scopes()
+        assert isinstance(scopes["dict_manager"], DictManager)
+        assert not any(name.startswith("__") for name in scopes)
+
+    @pytest.mark.parametrize(
+        "value",
+        [
+            42,
+            2**200,
+            DEFAULT_PRIME - 1,
+            Relocatable(1, 2),
+            [1, Relocatable(3, 4)],
+            [(1, 2), (3, Relocatable(5, 6))],
+            {1: 2, Relocatable(7, 8): 9},
+        ],
+    )
+    def test_set_get_execution_scope_variable(self, rust_program, value):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.set_execution_scope_variable("x", value)
+        assert runner.get_execution_scope_variable("x") == value
+
+    @pytest.mark.parametrize(
+        "value, match",
+        [
+            (-1, "Negative int -1 cannot be converted to a felt"),
+            (DEFAULT_PRIME, "is not below the field prime"),
+            ([(-1, 2)], "Negative int -1 cannot be converted to a felt"),
+            ([(DEFAULT_PRIME, 2)], "is not below the field prime"),
+        ],
+    )
+    def test_set_execution_scope_variable_invalid_int(
+        self, rust_program, value, match
+    ):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        with pytest.raises(ValueError, match=match):
+            runner.set_execution_scope_variable("x", value)
+
+    def test_set_execution_scope_variable_dict_manager(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.set_execution_scope_variable("other", runner.dict_manager)
+        assert isinstance(runner.get_execution_scope_variable("other"), DictManager)
+
+    def test_set_execution_scope_variable_unsupported_type(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        with pytest.raises(TypeError):
+            runner.set_execution_scope_variable("x", "not supported")
+
+    def test_get_execution_scope_variable_missing(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        with pytest.raises(KeyError):
+            runner.get_execution_scope_variable("missing")
+
+    def test_enter_exit_scope(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.set_execution_scope_variable("x", 1)
+        runner.enter_scope({"x": 2, "y": Relocatable(1, 2)})
+        assert runner.get_execution_scope_variable("x") == 2
+        assert runner.get_execution_scope_variable("y") == Relocatable(1, 2)
+        runner.exit_scope()
+        assert runner.get_execution_scope_variable("x") == 1
+        with pytest.raises(KeyError):
+            runner.get_execution_scope_variable("y")
+
+    def test_exit_main_scope(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        with pytest.raises(RuntimeError):
+            runner.exit_scope()
+
+    def test_add_relocation_rule(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        src = runner.segments.add_temporary_segment()
+        dst = runner.segments.add()
+        runner.add_relocation_rule(src, dst)
+        assert runner.get_relocation_table() == {src: dst}
+
+    def test_add_relocation_rule_rejects_non_temporary_segment(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        src = runner.segments.add()
+        dst = runner.segments.add()
+        with pytest.raises(ValueError):
+            runner.add_relocation_rule(src, dst)
+        assert runner.get_relocation_table() == {}
+
+    def test_check_safe_access(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        base = runner.segments.add()
+        assert not runner.check_safe_access(base)
+        runner.mark_as_safe(base)
+        assert runner.check_safe_access(base)
+        assert runner.segments.memory.get(base) is None
+
+    def test_check_safe_access_written_cell(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        base = runner.segments.add()
+        runner.segments.load_data(base, [42])
+        assert runner.check_safe_access(base)
+        assert not runner.check_safe_access(base + 1)
+
+    def test_builtin_usage_before_run(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        usage = runner.builtin_usage()
+        assert usage.keys() == runner.builtin_runners.keys()
+        assert all(used_cells == 0 for used_cells in usage.values())
+
+    def test_builtin_usage_after_run(self, compile_program):
+        program = compile_program(BUILTINS_PROGRAM)
+        runner = CairoRunner(to_rust_program(program), layout="all_cairo")
+        runner.initialize_segments()
+        end = initialize_entrypoint(runner, program, "main")
+        runner.run_until_pc(end, RunResources())
+
+        usage = runner.builtin_usage()
+        assert usage["range_check_builtin"] == 1
+        # The three inputs and the deduced first output of the permutation.
+        assert usage["poseidon_builtin"] == 4
+
+    def test_memory_trace_disabled_by_default(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        with pytest.raises(RuntimeError, match="Memory trace is not enabled"):
+            runner.get_memory_trace()
+
+    def test_enable_memory_trace(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.enable_memory_trace(True)
+        assert runner.get_memory_trace() == []
+        runner.enable_memory_trace(False)
+        with pytest.raises(RuntimeError, match="Memory trace is not enabled"):
+            runner.get_memory_trace()
+
+    def test_memory_trace(self, compile_program):
+        program = compile_program(TRACE_PROGRAM)
+        runner = CairoRunner(to_rust_program(program), layout="all_cairo")
+        runner.initialize_segments()
+        end = initialize_entrypoint(runner, program, "main")
+        pc, ap, fp = runner.pc, runner.ap, runner.fp
+        runner.enable_memory_trace(True)
+
+        runner.run_until_pc(end, RunResources())
+
+        trace = runner.get_memory_trace()
+        # `[ap] = 42, ap++` reads its encoding, writes dst, reads op0 and reads the
+        # immediate op1.
+        assert trace[:4] == [
+            (pc, False, runner.segments.memory.get(pc)),
+            (ap, True, 42),
+            (fp - 1, False, end),
+            (pc + 1, False, 42),
+        ]
+        # `ret` and the final `jmp rel 0` only read known cells.
+        assert trace[4][0] == pc + 2
+        assert not any(is_write for _, is_write, _ in trace[4:])
+
+    def test_run_until_pc_with_resources(self, rust_program, sw_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        end = initialize_entrypoint(runner, sw_program, "main")
+        # main calls os, which pushes three values and returns, then returns: 6 steps.
+        resources = RunResources(10)
+
+        runner.run_until_pc(end, resources)
+
+        assert runner.pc == end
+        assert resources.steps_used == 6
+        assert resources.steps_remaining == 4
+        assert not resources.is_exhausted
+
+    def test_run_until_pc_exhausts_resources(self, rust_program, sw_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        end = initialize_entrypoint(runner, sw_program, "main")
+        resources = RunResources(3)
+
+        with pytest.raises(RuntimeError):
+            runner.run_until_pc(end, resources)
+
+        # The call to os and its first two instructions of two words each were run.
+        assert runner.pc == runner.program_base + sw_program.get_label("os") + 4
+        assert resources.steps_used == 3
+        assert resources.steps_remaining == 0
+        assert resources.is_exhausted
+
+    def test_step_n_before_initialization(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        with pytest.raises(RuntimeError, match="Runner is not initialized"):
+            runner.step_n(1)
+
+    def test_step_n(self, rust_program, sw_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        end = initialize_entrypoint(runner, sw_program, "main")
+        os = runner.program_base + sw_program.get_label("os")
+        ap = runner.ap
+
+        # main calls os, pushing the call frame, and os pushes its three return values
+        # with `[ap] = x, ap++` instructions of two words each.
+        assert runner.step_n(1) == 1
+        assert runner.pc == os
+        assert runner.step_n(2) == 2
+        assert runner.pc == os + 4
+        assert runner.ap == ap + 4
+
+        # os then returns to main, which returns to the final `jmp rel 0`.
+        assert runner.step_n(10) == 3
+        assert runner.pc == end
+        assert runner.step_n(1) == 0
+
+    def test_get_return_values(self, rust_program, sw_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        end = initialize_entrypoint(runner, sw_program, "os")
+        runner.run_until_pc(end, RunResources())
+
+        assert runner.get_return_values(3) == [1, 2, 3]
+        assert runner.get_return_values(1) == [3]
+
+    def test_get_return_values_below_segment_start(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        assert runner.get_return_values(0) == []
+        with pytest.raises(ValueError, match="Cannot read 1 return values below ap"):
+            runner.get_return_values(1)
+
+    def test_export_memory_as_bytes(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        base = runner.segments.add()
+        runner.segments.load_data(base + 1, [1, 2**128])
+
+        memory = runner.export_memory_as_bytes()
+
+        # All segments but `base` are empty, so `base` is relocated to address 1.
+        assert memory == b"".join(
+            value.to_bytes(32, "little") for value in [0, 0, 1, 2**128]
+        )
+        assert runner.export_memory_as_bytes() == memory
+
+    def test_load_program_from_string(self, rust_program, program_bytes):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.load_program_from_string(program_bytes.decode())
+        runner.initialize_segments()
+        assert isinstance(runner.dict_manager, DictManager)
+
+    def test_load_stripped_program_from_string(self, rust_program, sw_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        stripped = sw_program.stripped()
+        runner.load_stripped_program_from_string(
+            json.dumps(
+                {
+                    "data": [hex(x) for x in stripped.data],
+                    "builtins": stripped.builtins,
+                    "main": stripped.main,
+                }
+            )
+        )
+        runner.initialize_segments()
+        assert runner.program_len == len(stripped.data) + 2
+
+        end = initialize_entrypoint(runner, sw_program, "main")
+        assert end == runner.program_base + len(stripped.data)
+        runner.run_until_pc(end, RunResources())
+        assert runner.pc == end
+
+    def test_load_program_from_string_and_run(self, rust_program, compile_program):
+        program = compile_program(RANGE_CHECK_PROGRAM)
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.load_program_from_string(json.dumps(program.Schema().dump(program)))
+        runner.initialize_segments()
+        assert runner.program_len == len(program.data) + 2
+
+        end = initialize_entrypoint(runner, program, "main")
+        runner.run_until_pc(end, RunResources())
+
+        assert runner.pc == end
+        # The range check pointer returned by main is read with the builtins of the
+        # loaded program.
+        assert runner.read_return_values(0) + 1 == runner.ap
+
+    def test_load_program_from_string_after_initialization(
+        self, rust_program, program_bytes
+    ):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        with pytest.raises(RuntimeError):
+            runner.load_program_from_string(program_bytes.decode())
+
+    @pytest.mark.parametrize("include_dict_managers", [True, False])
+    def test_serialize_state_round_trip(
+        self, rust_program, sw_program, include_dict_managers
+    ):
+        def start():
+            runner = CairoRunner(rust_program, layout="all_cairo")
+            runner.initialize_segments()
+            end = initialize_entrypoint(runner, sw_program, "main")
+            base = runner.segments.add()
+            runner.segments.load_data(base, [42, Relocatable(0, 2)])
+            runner.dict_manager.insert(
+                base.segment_index, DictTracker({1: 2}, base, default_value=0)
+            )
+            return runner, end, base
+
+        expected, end, _ = start()
+        expected.run_until_pc(end, RunResources(10))
+        expected.relocate()
+
+        runner, end, base = start()
+        with pytest.raises(RuntimeError):
+            runner.run_until_pc(end, RunResources(3))
+        restored = CairoRunner.deserialize_state(
+            runner.serialize_state(include_dict_managers)
+        )
+
+        assert restored.program_base == runner.program_base
+        assert restored.execution_base == runner.execution_base
+        assert restored.program_len == runner.program_len
+        assert restored.pc == runner.pc
+        assert restored.ap == runner.ap
+        assert restored.fp == runner.fp
+        assert restored.current_step == 3
+        assert restored.run_resources.steps_used == 3
+        assert restored.run_resources.is_exhausted
+        pointer = Relocatable(base.segment_index, 1)
+        assert restored.segments.memory.get(base) == 42
+        assert restored.segments.memory.get(pointer) == Relocatable(0, 2)
+        if include_dict_managers:
+            assert restored.dict_manager.get_tracker(base).data == {1: 2}
+        else:
+            with pytest.raises(KeyError):
+                restored.dict_manager.get_tracker(base)
+
+        # The restored run continues from the same point as the uninterrupted run.
+        restored.run_until_pc(end, RunResources(7))
+        restored.relocate()
+        assert restored.run_resources.steps_remaining == 4
+        assert restored.pc == expected.pc
+        assert restored.ap == expected.ap
+        assert restored.fp == expected.fp
+        assert restored.current_step == expected.current_step == 6
+        assert restored.trace_df.equals(expected.trace_df)
+
+    def test_serialize_state_rejects_temporary_segments(self, rust_program):
+        runner = CairoRunner(rust_program, layout="all_cairo")
+        runner.initialize_segments()
+        runner.segments.add_temporary_segment()
+        with pytest.raises(RuntimeError):
+            runner.serialize_state()
+
+    def test_hint_profiler(self, compile_program):
+        program = compile_program(PROFILED_PROGRAM)
+        runner = CairoRunner(to_rust_program(program), layout="all_cairo")
+        profiler = runner.hint_profiler
+        assert profiler.report() == []
+        runner.initialize_segments()
+        end = initialize_entrypoint(runner, program, "main")
+
+        runner.run_until_pc(end, RunResources())
+
+        [(hint_name, call_count, total_ns)] = profiler.report()
+        assert hint_name == "is_felt_negative"
+        assert call_count == 2
+        assert total_ns > 0
+        profiler.reset()
+        assert runner.hint_profiler.report() == []
+
+    def test_hashdict_copy_registers_preimage_once(self, compile_program):
+        program = compile_program(HASHDICT_COPY_PROGRAM)
+        runner = CairoRunner(to_rust_program(program), layout="all_cairo")
+        runner.initialize_segments()
+        src, dst, key = (runner.segments.add() for _ in range(3))
+        runner.segments.load_data(key, [1, 2])
+        for dict_ptr in (src, dst):
+            runner.dict_manager.insert(
+                dict_ptr.segment_index, DictTracker({}, dict_ptr, default_value=0)
+            )
+        hashed_key = poseidon_hash_many([1, 2])
+        args = [src, dst, key, hashed_key]
+        end = initialize_entrypoint(runner, program, "main", args)
+
+        runner.run_until_pc(end, RunResources())
+
+        assert runner.dict_manager.get_tracker(dst).data == {(1, 2): 42}
+        # The preimage is only held by the registry, not by the dict manager.
+        registry = runner.get_execution_scopes()["preimage_registry"]
+        assert registry == {hashed_key: (1, 2)}
+        with pytest.raises(KeyError):
+            runner.dict_manager.preimages[hashed_key]
+
+    def test_hashdict_delete_missing_key_leaves_tracker_unchanged(
+        self, compile_program
+    ):
+        program = compile_program(HASHDICT_DELETE_PROGRAM)
+        runner = CairoRunner(to_rust_program(program), layout="all_cairo")
+        runner.initialize_segments()
+        dict_ptr, key = runner.segments.add(), runner.segments.add()
+        runner.segments.load_data(key, [1, 2])
+        runner.dict_manager.insert(
+            dict_ptr.segment_index, DictTracker({}, dict_ptr, default_value=0)
+        )
+        end = initialize_entrypoint(runner, program, "main", [dict_ptr, key])
+
+        with pytest.raises(RuntimeError, match="No value found for preimage"):
+            runner.run_until_pc(end, RunResources())
+
+        tracker = runner.dict_manager.get_tracker(dict_ptr)
+        assert tracker.current_ptr == dict_ptr
+        assert tracker.data == {}
+
+    def test_cairo_run_inner(self, compile_program):
+        inner = compile_program(INNER_PROGRAM).stripped()
+        outer = compile_program(OUTER_PROGRAM)
+        runner = CairoRunner(to_rust_program(outer), layout="all_cairo")
+        runner.set_execution_scope_variable(
+            "double",
+            StrippedProgram(data=inner.data, builtins=inner.builtins, main=inner.main),
+        )
+        runner.initialize_segments()
+        args_ptr = runner.segments.add()
+        runner.segments.load_data(args_ptr, [21])
+        program_id = int.from_bytes(b"double", "big")
+        end = initialize_entrypoint(runner, outer, "main", [program_id, args_ptr, 1])
+
+        runner.run_until_pc(end, RunResources())
+
+        [output_ptr] = runner.get_return_values(1)
+        assert runner.segments.memory.get(output_ptr) == 42
+        assert runner.segments.memory.get(output_ptr + 1) is None
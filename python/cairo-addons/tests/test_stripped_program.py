@@ -1,3 +1,5 @@
+import pytest
+
 from cairo_addons.vm import StrippedProgram
 
 
@@ -17,3 +19,37 @@ class TestStrippedProgram:
         program = StrippedProgram(data=sw_program.data, builtins=[], main=0)
         program.main = sw_program.get_label("os")
         assert program.main == sw_program.get_label("os")
+
+    def test_to_felt_list(self, sw_program):
+        builtins = ["output", "range_check"]
+        program = StrippedProgram(data=sw_program.data, builtins=builtins, main=2)
+
+        assert program.to_felt_list() == [
+            0,
+            2,
+            2,
+            int.from_bytes(b"output", "big"),
+            int.from_bytes(b"range_check", "big"),
+            *sw_program.data,
+        ]
+
+    def test_from_felt_list(self, sw_program):
+        program = StrippedProgram(data=sw_program.data, builtins=["output"], main=2)
+
+        decoded = StrippedProgram.from_felt_list(program.to_felt_list())
+        assert decoded.data == program.data
+        assert decoded.builtins == program.builtins
+        assert decoded.main == program.main
+
+    @pytest.mark.parametrize(
+        "felts, match",
+        [
+            ([0, 0], "missing header"),
+            ([1, 0, 0], "unsupported bootloader version 1"),
+            ([0, 0, 1], "missing builtins"),
+            ([0, 0, 1, int.from_bytes(b"unknown", "big")], "Invalid builtin name"),
+        ],
+    )
+    def test_from_felt_list_invalid(self, felts, match):
+        with pytest.raises(ValueError, match=match):
+            StrippedProgram.from_felt_list(felts)